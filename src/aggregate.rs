@@ -0,0 +1,285 @@
+//! Health of a parent made out of the health of its parts, eg: limbs, turret arms, vehicle parts,...
+use bevy::ecs::event::ManualEventReader;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::{DamageAppliedEvent, DeathEvent, HealthSystem};
+
+/// Sum the [`HealthSystem`] of the children of an entity, weighted by their [`HealthPart`].
+/// A [`PartDestroyed`] event is sent when a child dies, and the [`HealthSystem`] of the parent is killed once the
+/// [`AggregateDeathRule`] is met, crediting the entity that destroyed the last part, or that last damaged a part
+/// when the aggregate health falls below [`AggregateDeathRule::HealthBelow`] without a part dying.
+/// # Fields
+/// * `rule`: When the parent dies.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     AggregateDeathRule, AggregateHealth, DamageEvent, HealthPart, HealthSystem,
+///     HealthSystemPlugin, PartDestroyed,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let arm = app.world.spawn(HealthSystem::new(50.0)).id();
+/// let core = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), HealthPart::new(2.0).vital()))
+///     .id();
+/// let turret = app
+///     .world
+///     .spawn((
+///         HealthSystem::new(1.0),
+///         AggregateHealth::new(AggregateDeathRule::AnyVitalDestroyed),
+///     ))
+///     .push_children(&[arm, core])
+///     .id();
+///
+/// app.world.send_event(DamageEvent::new(arm, 50.0));
+/// app.update();
+/// let events = app.world.resource::<Events<PartDestroyed>>();
+/// assert_eq!(arm, events.iter_current_update_events().next().unwrap().part);
+/// assert_eq!(200.0, app.world.get::<AggregateHealth>(turret).unwrap().get_health());
+/// assert!(app.world.get::<HealthSystem>(turret).unwrap().is_alive());
+///
+/// app.world.send_event(DamageEvent::new(core, 100.0));
+/// app.update();
+/// assert!(app.world.get::<HealthSystem>(turret).unwrap().is_dead());
+/// ```
+/// Damage spread over parts kills the parent once it crosses [`AggregateDeathRule::HealthBelow`].
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     AggregateDeathRule, AggregateHealth, DamageEvent, DeathEvent, HealthSystem,
+///     HealthSystemPlugin,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let left = app.world.spawn(HealthSystem::new(100.0)).id();
+/// let right = app.world.spawn(HealthSystem::new(100.0)).id();
+/// let ship = app
+///     .world
+///     .spawn((
+///         HealthSystem::new(1.0),
+///         AggregateHealth::new(AggregateDeathRule::HealthBelow(0.5)),
+///     ))
+///     .push_children(&[left, right])
+///     .id();
+/// let pirate = app.world.spawn_empty().id();
+///
+/// app.world.send_event(DamageEvent::new(left, 40.0).with_source(pirate));
+/// app.update();
+/// assert!(app.world.get::<HealthSystem>(ship).unwrap().is_alive());
+///
+/// app.world.send_event(DamageEvent::new(right, 60.0).with_source(pirate));
+/// app.update();
+/// assert!(app.world.get::<HealthSystem>(left).unwrap().is_alive());
+/// assert!(app.world.get::<HealthSystem>(ship).unwrap().is_dead());
+/// let events = app.world.resource::<Events<DeathEvent>>();
+/// let death = events.iter_current_update_events().find(|event| event.entity == ship).unwrap();
+/// assert_eq!(Some(pirate), death.source);
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct AggregateHealth {
+    pub rule: AggregateDeathRule,
+    health: f32,
+    max_health: f32,
+}
+
+impl AggregateHealth {
+    /// Sum the health of the children of an entity.
+    /// # Arguments
+    /// * `rule`: When the parent dies.
+    pub fn new(rule: AggregateDeathRule) -> Self {
+        Self {
+            rule,
+            health: 0.0,
+            max_health: 0.0,
+        }
+    }
+
+    /// The weighted sum of the health of the parts.
+    pub fn get_health(&self) -> f32 {
+        self.health
+    }
+
+    /// The weighted sum of the max health of the parts.
+    pub fn get_health_max(&self) -> f32 {
+        self.max_health
+    }
+
+    /// The health of the parts from `0.0` to `1.0`, `0.0` without parts.
+    pub fn get_health_normalized(&self) -> f32 {
+        if self.max_health > 0.0 {
+            self.health / self.max_health
+        } else {
+            0.0
+        }
+    }
+}
+
+/// When the parent of an [`AggregateHealth`] dies.
+#[derive(Debug, Default, PartialEq, Copy, Clone, Reflect)]
+pub enum AggregateDeathRule {
+    /// __Default__. Once all parts are dead.
+    #[default]
+    AllPartsDestroyed,
+    /// Once a [`HealthPart::vital`] part is dead.
+    AnyVitalDestroyed,
+    /// Once the aggregate health, from `0.0` to `1.0`, falls to or below this value.
+    HealthBelow(f32),
+    /// Never, only parts die.
+    Never,
+}
+
+/// How a child counts in the [`AggregateHealth`] of its parent. Children without it have a weight of `1.0` and are not vital.
+/// # Fields
+/// * `weight`: The multiplier of the health of this part in the aggregate.
+/// * `vital`: The parent dies with this part under [`AggregateDeathRule::AnyVitalDestroyed`].
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct HealthPart {
+    pub weight: f32,
+    pub vital: bool,
+}
+
+impl HealthPart {
+    /// Weight a part.
+    /// # Arguments
+    /// * `weight`: The multiplier of the health of this part in the aggregate.
+    pub fn new(weight: f32) -> Self {
+        Self {
+            weight,
+            vital: false,
+        }
+    }
+
+    /// Make this part vital.
+    pub fn vital(mut self) -> Self {
+        self.vital = true;
+        self
+    }
+}
+
+impl Default for HealthPart {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when a child of an [`AggregateHealth`] dies.
+/// # Fields
+/// * `parent`: The entity with the [`AggregateHealth`].
+/// * `part`: The child that died.
+/// * `source`: The entity that destroyed the part, if any.
+#[derive(Debug, Clone, Event)]
+pub struct PartDestroyed {
+    pub parent: Entity,
+    pub part: Entity,
+    pub source: Option<Entity>,
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_aggregate_health(
+    mut reader: Local<ManualEventReader<DeathEvent>>,
+    mut deaths: ResMut<Events<DeathEvent>>,
+    mut damaged: EventReader<DamageAppliedEvent>,
+    mut parents: Query<(
+        Entity,
+        &mut AggregateHealth,
+        &Children,
+        Option<&mut HealthSystem>,
+    )>,
+    parts: Query<(&HealthSystem, Option<&HealthPart>), Without<AggregateHealth>>,
+    parent_of: Query<&Parent>,
+    mut destroyed: EventWriter<PartDestroyed>,
+) {
+    let mut part_deaths = HashMap::new();
+    for event in reader.iter(&deaths) {
+        let Ok(parent) = parent_of.get(event.entity) else {
+            continue;
+        };
+        if !parents.contains(parent.get()) {
+            continue;
+        }
+
+        destroyed.send(PartDestroyed {
+            parent: parent.get(),
+            part: event.entity,
+            source: event.source,
+        });
+        part_deaths.insert(parent.get(), event.clone());
+    }
+
+    let mut last_damage = HashMap::new();
+    for event in damaged.iter() {
+        let Ok(parent) = parent_of.get(event.target) else {
+            continue;
+        };
+        if parents.contains(parent.get()) {
+            last_damage.insert(parent.get(), event);
+        }
+    }
+
+    let mut detected = Vec::new();
+    for (entity, mut aggregate, children, health_system) in &mut parents {
+        let mut health = 0.0;
+        let mut max_health = 0.0;
+        let mut all_dead = true;
+        let mut vital_dead = false;
+        for (part, health_part) in parts.iter_many(children.iter()) {
+            let (weight, vital) =
+                health_part.map_or((1.0, false), |part| (part.weight, part.vital));
+            health += part.get_health() * weight;
+            max_health += part.get_health_max() * weight;
+            all_dead &= part.is_dead();
+            vital_dead |= vital && part.is_dead();
+        }
+        aggregate.health = health;
+        aggregate.max_health = max_health;
+
+        // Only the health rule can be met without a part dying, eg: by damage spread over many parts.
+        let part_death = part_deaths.remove(&entity);
+        let rule_met = match aggregate.rule {
+            AggregateDeathRule::AllPartsDestroyed => part_death.is_some() && all_dead,
+            AggregateDeathRule::AnyVitalDestroyed => part_death.is_some() && vital_dead,
+            AggregateDeathRule::HealthBelow(fraction) => {
+                aggregate.max_health > 0.0 && aggregate.get_health_normalized() <= fraction
+            }
+            AggregateDeathRule::Never => false,
+        };
+
+        if let (true, Some(mut health_system)) = (rule_met, health_system) {
+            if health_system.is_dead() {
+                continue;
+            }
+            health_system.kill_system(true);
+            detected.push(match part_death {
+                Some(part_death) => DeathEvent {
+                    entity,
+                    ..part_death
+                },
+                None => {
+                    let last_damage = last_damage.get(&entity);
+                    DeathEvent {
+                        entity,
+                        source: last_damage.and_then(|event| event.source),
+                        cause: last_damage.and_then(|event| event.cause.clone()),
+                        weapon: None,
+                        contributors: Vec::new(),
+                    }
+                }
+            });
+        }
+    }
+
+    for event in detected {
+        deaths.send(event);
+    }
+    // Skip the events sent above.
+    reader.iter(&deaths).for_each(drop);
+}
@@ -0,0 +1,80 @@
+//! Armor mitigating damage before it reaches health.
+use bevy::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::DamageContext;
+
+/// Armor reducing any damage from a [`DamageEvent`](crate::DamageEvent) before it reaches the [`HealthSystem`](crate::HealthSystem).
+/// Damage absorbed by a [`Shield`](crate::Shield) or [`TemporaryHealth`](crate::TemporaryHealth) is not mitigated.
+/// The flat reduction is applied first, then the percentage one.
+/// # Fields
+/// * `flat_reduction`: Damage removed from every hit.
+/// * `percent_reduction`: Fraction of the remaining damage removed, from `0.0` to `1.0`.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{Armor, DamageAppliedEvent, DamageEvent, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app.world.spawn((HealthSystem::new(100.0), Armor::new(5.0, 0.5))).id();
+/// app.world.send_event(DamageEvent::new(entity, 25.0));
+/// app.update();
+///
+/// assert_eq!(90.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// let events = app.world.resource::<Events<DamageAppliedEvent>>();
+/// let applied = events.iter_current_update_events().next().unwrap();
+/// assert_eq!(15.0, applied.breakdown.armor_absorbed);
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[reflect(Component)]
+pub struct Armor {
+    pub flat_reduction: f32,
+    pub percent_reduction: f32,
+}
+
+impl Armor {
+    /// Create a new armor.
+    /// # Arguments
+    /// * `flat_reduction`: Damage removed from every hit.
+    /// * `percent_reduction`: Fraction of the remaining damage removed, from `0.0` to `1.0`.
+    pub fn new(flat_reduction: f32, percent_reduction: f32) -> Self {
+        Self {
+            flat_reduction,
+            percent_reduction,
+        }
+    }
+
+    /// Returns the damage left after this armor mitigated it.
+    /// # Arguments
+    /// * `amount`: The amount of damage to mitigate.
+    /// # Example
+    /// ```
+    /// use bevy_health_system::Armor;
+    ///
+    /// let armor = Armor::new(10.0, 0.25);
+    /// assert_eq!(30.0, armor.mitigate(50.0));
+    /// assert_eq!(0.0, armor.mitigate(5.0));
+    /// ```
+    pub fn mitigate(&self, amount: f32) -> f32 {
+        let after_flat = (amount - self.flat_reduction.max(0.0)).max(0.0);
+
+        after_flat * (1.0 - self.percent_reduction.clamp(0.0, 1.0))
+    }
+}
+
+pub(crate) fn armor_stage(context: &mut DamageContext, world: &mut World) {
+    if context.ignores_mitigation() {
+        return;
+    }
+    let Some(armor) = world.get::<Armor>(context.target) else {
+        return;
+    };
+
+    let remaining = armor.mitigate(context.amount);
+    context.armor_absorbed = context.amount - remaining;
+    context.amount = remaining;
+}
@@ -0,0 +1,162 @@
+//! Recent attackers of an entity, for kill and assist credit.
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use bevy::ecs::entity::{EntityMapper, MapEntities};
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::prelude::*;
+
+/// A hit recorded by [`RecentAttackers`].
+/// # Fields
+/// * `source`: The entity that dealt the damage.
+/// * `damage`: The amount of health lost.
+/// * `time`: When the damage was taken, as [`Time::elapsed`].
+#[derive(Debug, PartialEq, Copy, Clone, Reflect)]
+pub struct RecentHit {
+    pub source: Entity,
+    pub damage: f32,
+    pub time: Duration,
+}
+
+/// The share of an attacker in the damage recently taken by an entity.
+/// # Fields
+/// * `source`: The attacker.
+/// * `damage`: The damage it dealt within the window.
+/// * `share`: Its fraction of the damage dealt within the window, from `0.0` to `1.0`.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct DamageContribution {
+    pub source: Entity,
+    pub damage: f32,
+    pub share: f32,
+}
+
+/// Record the last hits an entity took from a [`DamageEvent`](crate::DamageEvent) with a source.
+/// The [`DeathEvent`](crate::DeathEvent) of the entity lists the [`DamageContribution`] of every attacker within the window,
+/// to compute kill and assist credit, shared experience,...
+/// # Fields
+/// * `window`: How long a hit counts, older hits are ignored.
+/// * `capacity`: The most hits recorded, the oldest hits are dropped first.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, DeathEvent, HealthSystem, HealthSystemPlugin, RecentAttackers};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let killer = app.world.spawn_empty().id();
+/// let assist = app.world.spawn_empty().id();
+/// let victim = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), RecentAttackers::new(Duration::from_secs(10))))
+///     .id();
+///
+/// app.world.send_event(DamageEvent::new(victim, 25.0).with_source(assist));
+/// app.world.send_event(DamageEvent::new(victim, 100.0).with_source(killer));
+/// app.update();
+///
+/// let events = app.world.resource::<Events<DeathEvent>>();
+/// let death = events.iter_current_update_events().next().unwrap();
+/// assert_eq!(killer, death.contributors[0].source);
+/// assert_eq!(0.75, death.contributors[0].share);
+/// assert_eq!(assist, death.contributors[1].source);
+/// assert_eq!(0.25, death.contributors[1].share);
+/// ```
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct RecentAttackers {
+    pub window: Duration,
+    pub capacity: usize,
+    hits: VecDeque<RecentHit>,
+}
+
+/// Keep the attackers pointing to the same entities when spawned from a scene.
+impl MapEntities for RecentAttackers {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        for hit in &mut self.hits {
+            hit.source = entity_mapper.get_or_reserve(hit.source);
+        }
+    }
+}
+
+/// Record the hits of the last 10 seconds.
+impl Default for RecentAttackers {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(10))
+    }
+}
+
+impl RecentAttackers {
+    /// Record up to 16 hits.
+    /// # Arguments
+    /// * `window`: How long a hit counts.
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            capacity: 16,
+            hits: VecDeque::new(),
+        }
+    }
+
+    /// Set the most hits recorded.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Record a hit, dropping the oldest hit if full.
+    pub fn record(&mut self, hit: RecentHit) {
+        if self.capacity == 0 {
+            return;
+        }
+        while self.hits.len() >= self.capacity {
+            self.hits.pop_front();
+        }
+        self.hits.push_back(hit);
+    }
+
+    /// Returns the recorded hits, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &RecentHit> {
+        self.hits.iter()
+    }
+
+    /// Remove every recorded hit.
+    pub fn clear(&mut self) {
+        self.hits.clear();
+    }
+
+    /// The contribution of every attacker within the window before `now`, largest damage first.
+    /// # Arguments
+    /// * `now`: The current time, as [`Time::elapsed`].
+    pub fn contributions(&self, now: Duration) -> Vec<DamageContribution> {
+        let mut contributions: Vec<DamageContribution> = Vec::new();
+        for hit in self.hits.iter().filter(|hit| hit.time + self.window >= now) {
+            match contributions
+                .iter_mut()
+                .find(|contribution| contribution.source == hit.source)
+            {
+                Some(contribution) => contribution.damage += hit.damage,
+                None => contributions.push(DamageContribution {
+                    source: hit.source,
+                    damage: hit.damage,
+                    share: 0.0,
+                }),
+            }
+        }
+
+        let total: f32 = contributions
+            .iter()
+            .map(|contribution| contribution.damage)
+            .sum();
+        for contribution in contributions.iter_mut() {
+            contribution.share = if total > 0.0 {
+                contribution.damage / total
+            } else {
+                0.0
+            };
+        }
+        contributions.sort_by(|a, b| b.damage.total_cmp(&a.damage));
+        contributions
+    }
+}
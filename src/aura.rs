@@ -0,0 +1,104 @@
+//! Auras healing nearby entities, eg: medics, healing totems,...
+use bevy::prelude::*;
+
+use crate::{Falloff, HealEvent, HealthSystem, Team, TeamFilter};
+
+/// Heal the entities around this one every frame, attenuated by their distance with the `falloff`.
+/// The entity with the aura is not healed by it.
+/// # Fields
+/// * `radius`: The radius of the aura around the [`GlobalTransform`] of the entity.
+/// * `amount_per_second`: The amount of health healed per second at the center.
+/// * `team_filter`: Which entities are healed, allies of the entity by default.
+/// * `falloff`: How the heal weakens with the distance, not reflected, scenes use the default.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{HealingAura, HealthSystem, HealthSystemPlugin, Team};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+///
+/// app.world
+///     .spawn((HealingAura::new(5.0, 10.0), Team(1), GlobalTransform::IDENTITY));
+///
+/// let mut ally = HealthSystem::new(100.0);
+/// ally.set_health(50.0);
+/// let ally = app.world.spawn((ally, Team(1), GlobalTransform::from_xyz(1.0, 0.0, 0.0))).id();
+/// let mut enemy = HealthSystem::new(100.0);
+/// enemy.set_health(50.0);
+/// let enemy = app.world.spawn((enemy, Team(2), GlobalTransform::from_xyz(1.0, 0.0, 0.0))).id();
+///
+/// app.update();
+/// app.update();
+/// assert_eq!(60.0, app.world.get::<HealthSystem>(ally).unwrap().get_health());
+/// assert_eq!(50.0, app.world.get::<HealthSystem>(enemy).unwrap().get_health());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct HealingAura {
+    pub radius: f32,
+    pub amount_per_second: f32,
+    pub team_filter: TeamFilter,
+    #[reflect(ignore)]
+    pub falloff: Falloff,
+}
+
+impl HealingAura {
+    /// Heal allies around this entity.
+    /// # Arguments
+    /// * `radius`: The radius of the aura.
+    /// * `amount_per_second`: The amount of health healed per second at the center.
+    pub fn new(radius: f32, amount_per_second: f32) -> Self {
+        Self {
+            radius,
+            amount_per_second,
+            team_filter: TeamFilter::default(),
+            falloff: Falloff::default(),
+        }
+    }
+
+    /// Set which entities are healed.
+    pub fn with_team_filter(mut self, team_filter: TeamFilter) -> Self {
+        self.team_filter = team_filter;
+        self
+    }
+
+    /// Set how the heal weakens with the distance.
+    pub fn with_falloff(mut self, falloff: Falloff) -> Self {
+        self.falloff = falloff;
+        self
+    }
+}
+
+pub(crate) fn apply_healing_auras(
+    time: Res<Time>,
+    auras: Query<(Entity, &HealingAura, &GlobalTransform, Option<&Team>)>,
+    targets: Query<(Entity, &GlobalTransform, Option<&Team>), With<HealthSystem>>,
+    mut heal: EventWriter<HealEvent>,
+) {
+    let delta = time.delta_seconds();
+    if delta <= 0.0 {
+        return;
+    }
+
+    for (aura_entity, aura, aura_transform, aura_team) in &auras {
+        let center = aura_transform.translation();
+        for (entity, transform, team) in &targets {
+            if entity == aura_entity || !aura.team_filter.matches(aura_team, team) {
+                continue;
+            }
+
+            let multiplier = aura
+                .falloff
+                .multiplier(transform.translation().distance(center), aura.radius);
+            if multiplier > 0.0 {
+                heal.send(HealEvent::new(
+                    entity,
+                    aura.amount_per_second * multiplier * delta,
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Automatic revives of dead entities, eg: a phoenix down.
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{DeathEvent, HealthSystem, HealthSystemReviveHealType, ReviveEvent};
+
+/// Revive an entity some time after it died, as long as it has uses left.
+/// The entity is revived with a [`ReviveEvent`], and [`AutoRevived`] is sent.
+/// Reviving it manually before that cancels the pending revive, without using it.
+/// # Fields
+/// * `heal_type`: How the entity is healed when revived.
+/// * `delay`: How long to wait after death before reviving.
+/// * `uses`: How many times the entity can still be revived.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     AutoRevive, AutoRevived, DamageEvent, HealthSystem, HealthSystemPlugin,
+///     HealthSystemReviveHealType,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app
+///     .world
+///     .spawn((
+///         HealthSystem::new(100.0),
+///         AutoRevive::new(HealthSystemReviveHealType::HealFull, Duration::ZERO, 1),
+///     ))
+///     .id();
+///
+/// app.world.send_event(DamageEvent::new(entity, 100.0));
+/// app.update();
+/// app.update();
+///
+/// assert_eq!(100.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// assert_eq!(0, app.world.get::<AutoRevive>(entity).unwrap().uses);
+/// let events = app.world.resource::<Events<AutoRevived>>();
+/// assert_eq!(entity, events.iter_current_update_events().next().unwrap().entity);
+///
+/// app.world.send_event(DamageEvent::new(entity, 100.0));
+/// app.update();
+/// app.update();
+/// assert!(app.world.get::<HealthSystem>(entity).unwrap().is_dead());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct AutoRevive {
+    pub heal_type: HealthSystemReviveHealType,
+    pub delay: Duration,
+    pub uses: u32,
+    pending: Option<Timer>,
+}
+
+impl AutoRevive {
+    /// Revive an entity some time after it died.
+    /// # Arguments
+    /// * `heal_type`: How the entity is healed when revived.
+    /// * `delay`: How long to wait after death before reviving.
+    /// * `uses`: How many times the entity can be revived.
+    pub fn new(heal_type: HealthSystemReviveHealType, delay: Duration, uses: u32) -> Self {
+        Self {
+            heal_type,
+            delay,
+            uses,
+            pending: None,
+        }
+    }
+
+    /// Whether the entity is waiting to be revived.
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// The time left before the entity is revived, if it is waiting to.
+    pub fn remaining(&self) -> Option<Duration> {
+        self.pending.as_ref().map(Timer::remaining)
+    }
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when [`AutoRevive`] revives an entity.
+/// # Fields
+/// * `entity`: The entity revived.
+/// * `uses_left`: How many times the entity can still be revived.
+#[derive(Debug, Clone, Event)]
+pub struct AutoRevived {
+    pub entity: Entity,
+    pub uses_left: u32,
+}
+
+pub(crate) fn start_auto_revives(
+    mut deaths: EventReader<DeathEvent>,
+    mut query: Query<(&HealthSystem, &mut AutoRevive)>,
+) {
+    for event in deaths.iter() {
+        let Ok((health_system, mut auto_revive)) = query.get_mut(event.entity) else {
+            continue;
+        };
+        if health_system.is_dead() && auto_revive.uses > 0 {
+            auto_revive.pending = Some(Timer::new(auto_revive.delay, TimerMode::Once));
+        }
+    }
+}
+
+pub(crate) fn tick_auto_revives(
+    time: Res<Time>,
+    mut query: Query<(Entity, &HealthSystem, &mut AutoRevive)>,
+    mut revives: EventWriter<ReviveEvent>,
+    mut revived: EventWriter<AutoRevived>,
+) {
+    for (entity, health_system, mut auto_revive) in &mut query {
+        if !health_system.is_dead() {
+            auto_revive.pending = None;
+            continue;
+        }
+        let Some(timer) = auto_revive.pending.as_mut() else {
+            continue;
+        };
+        if !timer.tick(time.delta()).finished() {
+            continue;
+        }
+        auto_revive.pending = None;
+        auto_revive.uses = auto_revive.uses.saturating_sub(1);
+
+        revives.send(ReviveEvent::new(entity, auto_revive.heal_type));
+        revived.send(AutoRevived {
+            entity,
+            uses_left: auto_revive.uses,
+        });
+    }
+}
@@ -0,0 +1,154 @@
+//! Blocking and parrying incoming damage.
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::DamageContext;
+
+/// Let gameplay code block or parry a [`DamageEvent`](crate::DamageEvent), eg: while a shield is raised.
+/// A parried hit deals no damage and sends [`Parried`], a blocked hit is reduced and sends [`BlockedHit`].
+/// Forced damage can't be blocked nor parried.
+/// # Fields
+/// * `blocking`: The entity is blocking.
+/// * `reduction`: The fraction of the damage removed when blocking, from `0.0` to `1.0`.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{BlockState, BlockedHit, DamageEvent, HealthSystem, HealthSystemPlugin, Parried};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let mut block = BlockState::new(0.75);
+/// block.blocking = true;
+/// let entity = app.world.spawn((HealthSystem::new(100.0), block)).id();
+///
+/// app.world.send_event(DamageEvent::new(entity, 20.0));
+/// app.update();
+/// assert_eq!(95.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// let events = app.world.resource::<Events<BlockedHit>>();
+/// assert_eq!(15.0, events.iter_current_update_events().next().unwrap().blocked);
+///
+/// app.world
+///     .get_mut::<BlockState>(entity)
+///     .unwrap()
+///     .open_parry_window(Duration::from_millis(200));
+/// app.world.send_event(DamageEvent::new(entity, 20.0));
+/// app.update();
+/// assert_eq!(95.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// assert_eq!(1, app.world.resource::<Events<Parried>>().len());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct BlockState {
+    pub blocking: bool,
+    pub reduction: f32,
+    parry_window: Option<Timer>,
+}
+
+impl BlockState {
+    /// Let an entity block, not blocking yet.
+    /// # Arguments
+    /// * `reduction`: The fraction of the damage removed when blocking, from `0.0` to `1.0`.
+    pub fn new(reduction: f32) -> Self {
+        Self {
+            blocking: false,
+            reduction,
+            parry_window: None,
+        }
+    }
+
+    /// Parry every hit taken during `duration`, replacing any window already open.
+    pub fn open_parry_window(&mut self, duration: Duration) {
+        self.parry_window = Some(Timer::new(duration, TimerMode::Once));
+    }
+
+    /// Close the parry window, if open.
+    pub fn close_parry_window(&mut self) {
+        self.parry_window = None;
+    }
+
+    /// Returns true while the parry window is open.
+    pub fn is_parrying(&self) -> bool {
+        self.parry_window.is_some()
+    }
+
+    /// Returns the part of `amount` removed by blocking, `0.0` when not blocking.
+    pub fn blocked(&self, amount: f32) -> f32 {
+        if self.blocking {
+            amount * self.reduction.clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when an entity blocked a [`DamageEvent`](crate::DamageEvent).
+/// # Fields
+/// * `target`: The entity that blocked.
+/// * `source`: The entity that dealt the damage, if any.
+/// * `blocked`: The amount of damage removed by blocking.
+#[derive(Debug, Clone, Event)]
+pub struct BlockedHit {
+    pub target: Entity,
+    pub source: Option<Entity>,
+    pub blocked: f32,
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when an entity parried a [`DamageEvent`](crate::DamageEvent).
+/// # Fields
+/// * `target`: The entity that parried.
+/// * `source`: The entity whose hit was parried, if any.
+/// * `amount`: The amount of damage parried.
+#[derive(Debug, Clone, Event)]
+pub struct Parried {
+    pub target: Entity,
+    pub source: Option<Entity>,
+    pub amount: f32,
+}
+
+pub(crate) fn tick_parry_windows(time: Res<Time>, mut query: Query<&mut BlockState>) {
+    for mut block in &mut query {
+        let closed = block
+            .parry_window
+            .as_mut()
+            .is_some_and(|window| window.tick(time.delta()).finished());
+        if closed {
+            block.parry_window = None;
+        }
+    }
+}
+
+pub(crate) fn block_stage(context: &mut DamageContext, world: &mut World) {
+    if context.event.force {
+        return;
+    }
+    let Some(block) = world.get::<BlockState>(context.target) else {
+        return;
+    };
+
+    if block.is_parrying() {
+        context.cancel();
+        if !context.is_preview() {
+            world.send_event(Parried {
+                target: context.hit,
+                source: context.event.source,
+                amount: context.amount,
+            });
+        }
+        return;
+    }
+
+    let blocking = block.blocking;
+    let blocked = block.blocked(context.amount);
+    context.blocked = blocked;
+    context.amount -= blocked;
+    if blocking && !context.is_preview() {
+        world.send_event(BlockedHit {
+            target: context.hit,
+            source: context.event.source,
+            blocked,
+        });
+    }
+}
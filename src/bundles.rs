@@ -0,0 +1,148 @@
+//! Ready-made bundles of the health components of common kinds of entities.
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    DeathBehavior, HealthRegen, HealthSystem, HealthThresholds, HitInvulnerability, Loot, Player,
+};
+
+/// The health of a player: a [`HealthSystem`] regenerating out of damage, with invincibility frames after each hit
+/// and [`HealthThresholds`], eg: to play a low health effect.
+/// # Fields
+/// * `player`: The [`Player`] marker.
+/// * `health_system`: The health of the player.
+/// * `regen`: The [`HealthRegen`], 1 health per second after 5 seconds without damage by default.
+/// * `hit_invulnerability`: The [`HitInvulnerability`], half a second by default.
+/// * `thresholds`: The [`HealthThresholds`], a quarter of max health by default.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageEvent, HealthSystem, HealthSystemPlugin, Invulnerability, PlayerHealthBundle,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let player = app
+///     .world
+///     .spawn(
+///         PlayerHealthBundle::new(100.0)
+///             .with_regen(2.0, Duration::from_secs(3))
+///             .with_hit_invulnerability(Duration::from_secs(1))
+///             .with_thresholds(vec![0.25, 0.5]),
+///     )
+///     .id();
+/// app.world.send_event(DamageEvent::new(player, 10.0));
+/// app.update();
+/// assert_eq!(90.0, app.world.get::<HealthSystem>(player).unwrap().get_health());
+/// assert!(app.world.get::<Invulnerability>(player).is_some());
+/// ```
+#[derive(Debug, Clone, Bundle)]
+pub struct PlayerHealthBundle {
+    pub player: Player,
+    pub health_system: HealthSystem,
+    pub regen: HealthRegen,
+    pub hit_invulnerability: HitInvulnerability,
+    pub thresholds: HealthThresholds,
+}
+
+impl PlayerHealthBundle {
+    /// Create the health of a player, with the default regen, invincibility frames and thresholds.
+    /// # Arguments
+    /// * `max_health`: The max health of the player, who spawns with full health.
+    pub fn new(max_health: f32) -> Self {
+        Self {
+            player: Player,
+            health_system: HealthSystem::new(max_health),
+            regen: HealthRegen::new(1.0, Duration::from_secs(5)),
+            hit_invulnerability: HitInvulnerability::new(Duration::from_millis(500)),
+            thresholds: HealthThresholds(vec![0.25]),
+        }
+    }
+
+    /// Set the regeneration of the player.
+    /// # Arguments
+    /// * `rate_per_second`: Health regenerated every second.
+    /// * `delay_after_damage`: How long regeneration waits after the player was damaged.
+    pub fn with_regen(mut self, rate_per_second: f32, delay_after_damage: Duration) -> Self {
+        self.regen = HealthRegen::new(rate_per_second, delay_after_damage);
+        self
+    }
+
+    /// Set how long the player stays invulnerable after a hit.
+    pub fn with_hit_invulnerability(mut self, duration: Duration) -> Self {
+        self.hit_invulnerability = HitInvulnerability::new(duration);
+        self
+    }
+
+    /// Set the fractions of max health sending a [`ThresholdCrossed`](crate::ThresholdCrossed) event.
+    pub fn with_thresholds(mut self, thresholds: Vec<f32>) -> Self {
+        self.thresholds = HealthThresholds(thresholds);
+        self
+    }
+}
+
+/// The health of an enemy: a [`HealthSystem`] despawned on death, with a [`Loot`] hook.
+/// # Fields
+/// * `health_system`: The health of the enemy.
+/// * `death_behavior`: The [`DeathBehavior`], [`DeathBehavior::Despawn`] by default.
+/// * `loot`: The [`Loot`] dropped on death, none by default.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageEvent, DeathBehavior, EnemyHealthBundle, HealthSystemPlugin, Loot, LootDropped,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let enemy = app
+///     .world
+///     .spawn(
+///         EnemyHealthBundle::new(30.0)
+///             .with_death_behavior(DeathBehavior::Despawn)
+///             .with_loot(Loot::new("goblin").with_chance(1.0)),
+///     )
+///     .id();
+/// app.world.send_event(DamageEvent::new(enemy, 30.0));
+/// app.update();
+///
+/// assert!(app.world.get_entity(enemy).is_none());
+/// let events = app.world.resource::<Events<LootDropped>>();
+/// assert_eq!("goblin", events.iter_current_update_events().next().unwrap().table);
+/// ```
+#[derive(Debug, Clone, Bundle)]
+pub struct EnemyHealthBundle {
+    pub health_system: HealthSystem,
+    pub death_behavior: DeathBehavior,
+    pub loot: Loot,
+}
+
+impl EnemyHealthBundle {
+    /// Create the health of an enemy, despawned on death without loot.
+    /// # Arguments
+    /// * `max_health`: The max health of the enemy, which spawns with full health.
+    pub fn new(max_health: f32) -> Self {
+        Self {
+            health_system: HealthSystem::new(max_health),
+            death_behavior: DeathBehavior::Despawn,
+            loot: Loot::default(),
+        }
+    }
+
+    /// Set what happens to the enemy when it dies.
+    pub fn with_death_behavior(mut self, death_behavior: DeathBehavior) -> Self {
+        self.death_behavior = death_behavior;
+        self
+    }
+
+    /// Set the loot dropped by the enemy when it dies.
+    pub fn with_loot(mut self, loot: Loot) -> Self {
+        self.loot = loot;
+        self
+    }
+}
@@ -0,0 +1,122 @@
+//! Limits on the damage an entity takes per hit and per second.
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::DamageContext;
+
+/// Limit the damage dealt to the health of an entity, eg: to protect a boss from being one-shot.
+/// Caps apply once the damage is mitigated, forced damage is capped too.
+/// Executions are capped as well: an [`ExecuteThreshold`](crate::ExecuteThreshold) hit over the cap deals the capped damage
+/// and is no longer an execution.
+/// # Fields
+/// * `per_hit`: The most damage a single hit deals, if limited.
+/// * `per_second`: The most damage dealt within any second, if limited.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageCap, DamageEvent, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_millis(500)));
+///
+/// let boss = app
+///     .world
+///     .spawn((
+///         HealthSystem::new(100.0),
+///         DamageCap::default().with_per_hit(25.0).with_per_second(40.0),
+///     ))
+///     .id();
+///
+/// app.world.send_event(DamageEvent::new(boss, 100.0));
+/// app.world.send_event(DamageEvent::new(boss, 100.0));
+/// app.update();
+/// assert_eq!(60.0, app.world.get::<HealthSystem>(boss).unwrap().get_health());
+///
+/// app.world.send_event(DamageEvent::new(boss, 100.0));
+/// app.update();
+/// assert_eq!(60.0, app.world.get::<HealthSystem>(boss).unwrap().get_health());
+///
+/// app.world.send_event(DamageEvent::new(boss, 100.0));
+/// app.update();
+/// assert_eq!(35.0, app.world.get::<HealthSystem>(boss).unwrap().get_health());
+/// ```
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageAppliedEvent, DamageCap, DamageEvent, Fraction, HealthSystem, HealthSystemPlugin,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let boss = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), DamageCap::default().with_per_second(40.0)))
+///     .id();
+/// app.world.send_event(DamageEvent::new(boss, 30.0));
+/// app.world.send_event(DamageEvent::new(boss, 5.0).with_execute(Fraction::new(0.9)));
+/// app.update();
+///
+/// assert_eq!(60.0, app.world.get::<HealthSystem>(boss).unwrap().get_health());
+/// let events = app.world.resource::<Events<DamageAppliedEvent>>();
+/// assert!(events.iter_current_update_events().all(|event| !event.was_execute));
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct DamageCap {
+    pub per_hit: Option<f32>,
+    pub per_second: Option<f32>,
+    window: VecDeque<(f64, f32)>,
+}
+
+impl DamageCap {
+    /// Limit the damage of a single hit.
+    pub fn with_per_hit(mut self, per_hit: f32) -> Self {
+        self.per_hit = Some(per_hit);
+        self
+    }
+
+    /// Limit the damage dealt within any second.
+    pub fn with_per_second(mut self, per_second: f32) -> Self {
+        self.per_second = Some(per_second);
+        self
+    }
+
+    /// The damage dealt within the second before `now`, in seconds since startup.
+    fn recent(&mut self, now: f64) -> f32 {
+        while self
+            .window
+            .front()
+            .is_some_and(|&(time, _)| time <= now - 1.0)
+        {
+            self.window.pop_front();
+        }
+
+        self.window.iter().map(|(_, amount)| amount).sum()
+    }
+}
+
+pub(crate) fn damage_cap_stage(context: &mut DamageContext, world: &mut World) {
+    let now = world.resource::<Time>().elapsed_seconds_f64();
+    let Some(mut cap) = world.get::<DamageCap>(context.target).cloned() else {
+        return;
+    };
+
+    let mut limit = cap.per_hit.unwrap_or(f32::INFINITY);
+    if let Some(per_second) = cap.per_second {
+        limit = limit.min((per_second - cap.recent(now)).max(0.0));
+    }
+    if context.amount > limit {
+        context.capped = context.amount - limit;
+        context.amount = limit;
+        context.was_execute = false;
+    }
+
+    if !context.is_preview() && cap.per_second.is_some() && context.amount > 0.0 {
+        cap.window.push_back((now, context.amount));
+        world.entity_mut(context.target).insert(cap);
+    }
+}
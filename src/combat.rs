@@ -0,0 +1,117 @@
+//! Combat state of entities, and rules for effects running only in or out of combat.
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+
+use crate::DamageAppliedEvent;
+
+/// How long an entity stays [`InCombat`] after it last dealt or took damage, 5 seconds by default.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Resource)]
+pub struct CombatTimeout(pub Duration);
+
+impl Default for CombatTimeout {
+    fn default() -> Self {
+        Self(Duration::from_secs(5))
+    }
+}
+
+/// Marker for an entity that recently dealt or took damage, inserted and removed by the [`HealthSystemPlugin`](crate::HealthSystemPlugin).
+/// The marker is removed once the entity was out of any fight for the [`CombatTimeout`].
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{CombatTimeout, DamageEvent, HealthSystem, HealthSystemPlugin, InCombat};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+/// app.insert_resource(CombatTimeout(Duration::from_secs(2)));
+///
+/// let attacker = app.world.spawn_empty().id();
+/// let entity = app.world.spawn(HealthSystem::new(100.0)).id();
+/// app.world.send_event(DamageEvent::new(entity, 10.0).with_source(attacker));
+/// app.update();
+/// assert!(app.world.get::<InCombat>(entity).is_some());
+/// assert!(app.world.get::<InCombat>(attacker).is_some());
+///
+/// app.update();
+/// app.update();
+/// assert!(app.world.get::<InCombat>(entity).is_none());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct InCombat {
+    timer: Timer,
+}
+
+impl InCombat {
+    /// The time left before the entity leaves combat.
+    pub fn remaining(&self) -> Duration {
+        self.timer.remaining()
+    }
+}
+
+/// When an effect, eg: a [`HealthRegen`](crate::HealthRegen), runs relative to the [`InCombat`] state of its entity.
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone, Reflect)]
+pub enum CombatRule {
+    /// __Default__. Run whether the entity is in combat or not.
+    #[default]
+    Always,
+    /// Run only while the entity is in combat.
+    InCombat,
+    /// Run only while the entity is out of combat.
+    OutOfCombat,
+}
+
+impl CombatRule {
+    /// Returns true if the effect runs for an entity in combat, or not.
+    pub fn allows(&self, in_combat: bool) -> bool {
+        match self {
+            CombatRule::Always => true,
+            CombatRule::InCombat => in_combat,
+            CombatRule::OutOfCombat => !in_combat,
+        }
+    }
+}
+
+pub(crate) fn update_combat_state(
+    mut commands: Commands,
+    time: Res<Time>,
+    timeout: Res<CombatTimeout>,
+    mut damaged: EventReader<DamageAppliedEvent>,
+    mut query: Query<(Entity, &mut InCombat)>,
+) {
+    for (_, mut in_combat) in &mut query {
+        in_combat.timer.tick(time.delta());
+    }
+
+    let mut entered = HashSet::new();
+    for event in damaged.iter() {
+        for entity in std::iter::once(event.target).chain(event.source) {
+            match query.get_mut(entity) {
+                Ok((_, mut in_combat)) => {
+                    in_combat.timer.set_duration(timeout.0);
+                    in_combat.timer.reset();
+                }
+                Err(_) => {
+                    entered.insert(entity);
+                }
+            }
+        }
+    }
+
+    for (entity, in_combat) in &query {
+        if in_combat.timer.finished() {
+            commands.entity(entity).remove::<InCombat>();
+        }
+    }
+    for entity in entered {
+        if let Some(mut entity) = commands.get_entity(entity) {
+            entity.insert(InCombat {
+                timer: Timer::new(timeout.0, TimerMode::Once),
+            });
+        }
+    }
+}
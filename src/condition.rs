@@ -0,0 +1,91 @@
+//! Run conditions on the health of entities, eg: a game over screen once the player died.
+use bevy::prelude::*;
+
+use crate::{Fraction, HealthSystem};
+
+/// A run condition true while an entity with a [`HealthSystem`] is dead.
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_health_system::any_entity_dead;
+///
+/// fn play_death_jingle() {}
+///
+/// App::new().add_systems(Update, play_death_jingle.run_if(any_entity_dead()));
+/// ```
+pub fn any_entity_dead() -> impl FnMut(Query<&HealthSystem>) -> bool + Clone {
+    |query: Query<&HealthSystem>| query.iter().any(HealthSystem::is_dead)
+}
+
+/// A run condition true while a living entity with the component `M` is below a fraction of its max health.
+/// # Arguments
+/// * `fraction`: The fraction of max health, eg: `0.25`.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{entity_below, Fraction, HealthSystem, Player};
+///
+/// #[derive(Resource, Default)]
+/// struct SlowMotion(bool);
+///
+/// let mut app = App::new();
+/// app.init_resource::<SlowMotion>().add_systems(
+///     Update,
+///     (|mut slow_motion: ResMut<SlowMotion>| slow_motion.0 = true)
+///         .run_if(entity_below::<Player>(Fraction::from_percent(25.0))),
+/// );
+///
+/// let player = app.world.spawn((HealthSystem::new(100.0), Player)).id();
+/// app.update();
+/// assert!(!app.world.resource::<SlowMotion>().0);
+///
+/// app.world.get_mut::<HealthSystem>(player).unwrap().deal_damage(80.0, false);
+/// app.update();
+/// assert!(app.world.resource::<SlowMotion>().0);
+/// ```
+pub fn entity_below<M: Component>(
+    fraction: Fraction,
+) -> impl FnMut(Query<&HealthSystem, With<M>>) -> bool + Clone {
+    move |query: Query<&HealthSystem, With<M>>| {
+        query.iter().any(|health_system| {
+            health_system.is_alive() && health_system.get_health_fraction() < fraction
+        })
+    }
+}
+
+/// A run condition true once every entity with the component `M` and a [`HealthSystem`] is dead,
+/// eg: `all_dead::<Boss>()` with a `Boss` marker of your own. False while there are none.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{all_dead, HealthSystem};
+///
+/// #[derive(Component)]
+/// struct Boss;
+///
+/// #[derive(Resource, Default)]
+/// struct Victory(bool);
+///
+/// let mut app = App::new();
+/// app.init_resource::<Victory>().add_systems(
+///     Update,
+///     (|mut victory: ResMut<Victory>| victory.0 = true).run_if(all_dead::<Boss>()),
+/// );
+/// app.update();
+/// assert!(!app.world.resource::<Victory>().0);
+///
+/// let first = app.world.spawn((HealthSystem::new(100.0), Boss)).id();
+/// let second = app.world.spawn((HealthSystem::new(100.0), Boss)).id();
+/// app.world.get_mut::<HealthSystem>(first).unwrap().kill_system(true);
+/// app.update();
+/// assert!(!app.world.resource::<Victory>().0);
+///
+/// app.world.get_mut::<HealthSystem>(second).unwrap().kill_system(true);
+/// app.update();
+/// assert!(app.world.resource::<Victory>().0);
+/// ```
+pub fn all_dead<M: Component>() -> impl FnMut(Query<&HealthSystem, With<M>>) -> bool + Clone {
+    |query: Query<&HealthSystem, With<M>>| {
+        !query.is_empty() && query.iter().all(HealthSystem::is_dead)
+    }
+}
@@ -0,0 +1,210 @@
+//! `bevy_console` commands manipulating health at runtime, eg: for QA.
+use bevy::ecs::entity::Entities;
+use bevy::prelude::*;
+use bevy_console::{reply, reply_failed, AddConsoleCommand, ConsoleCommand};
+use clap::Parser;
+
+use crate::{
+    DamageCause, DamageEvent, Fraction, GlobalDamageSettings, HealEvent, HealthSystem,
+    HealthSystemModifier, HealthSystemReviveHealType, ReviveEvent,
+};
+
+/// Add this plugin after the `ConsolePlugin` of `bevy_console` to register the console commands below.
+/// They send the events of the [`HealthSystemPlugin`](crate::HealthSystemPlugin), so damage goes through the whole pipeline.
+/// Entities are written as their index, eg: `12`, or as shown by their `Debug` format, eg: `12v0`.
+/// * `hp.damage <entity> <amount>`: Send a [`DamageEvent`].
+/// * `hp.heal <entity> <amount>`: Send a [`HealEvent`].
+/// * `hp.kill <entity>`: Send a forced [`DamageEvent`] executing the entity.
+/// * `hp.revive <entity>`: Send a [`ReviveEvent`] fully healing the entity.
+/// * `hp.god [entity]`: Toggle [`HealthSystemModifier::INVINCIBLE`] on the entity,
+///   or the god mode of the [`GlobalDamageSettings`] without entity.
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_console::ConsolePlugin;
+/// use bevy_health_system::{HealthConsolePlugin, HealthSystemPlugin};
+///
+/// App::new()
+///     .add_plugins((DefaultPlugins, ConsolePlugin, HealthSystemPlugin, HealthConsolePlugin))
+///     .run();
+/// ```
+#[derive(Debug, Default)]
+pub struct HealthConsolePlugin;
+
+impl Plugin for HealthConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_console_command::<DamageCommand, _>(damage_command)
+            .add_console_command::<HealCommand, _>(heal_command)
+            .add_console_command::<KillCommand, _>(kill_command)
+            .add_console_command::<ReviveCommand, _>(revive_command)
+            .add_console_command::<GodCommand, _>(god_command);
+    }
+}
+
+/// Damage an entity.
+#[derive(Parser, ConsoleCommand)]
+#[command(name = "hp.damage")]
+struct DamageCommand {
+    /// The entity to damage.
+    entity: String,
+    /// The amount of damage.
+    amount: f32,
+}
+
+/// Heal an entity.
+#[derive(Parser, ConsoleCommand)]
+#[command(name = "hp.heal")]
+struct HealCommand {
+    /// The entity to heal.
+    entity: String,
+    /// The amount of health healed.
+    amount: f32,
+}
+
+/// Kill an entity, even if invincible.
+#[derive(Parser, ConsoleCommand)]
+#[command(name = "hp.kill")]
+struct KillCommand {
+    /// The entity to kill.
+    entity: String,
+}
+
+/// Revive an entity with full health.
+#[derive(Parser, ConsoleCommand)]
+#[command(name = "hp.revive")]
+struct ReviveCommand {
+    /// The entity to revive.
+    entity: String,
+}
+
+/// Toggle invincibility of an entity, or god mode of the players without entity.
+#[derive(Parser, ConsoleCommand)]
+#[command(name = "hp.god")]
+struct GodCommand {
+    /// The entity to make invincible.
+    entity: Option<String>,
+}
+
+/// Find the entity written as `12` or `12v0`.
+fn parse_entity(text: &str, entities: &Entities) -> Option<Entity> {
+    match text.split_once('v') {
+        Some((index, generation)) => {
+            let bits = (generation.parse::<u64>().ok()? << 32) | index.parse::<u64>().ok()?;
+            Some(Entity::from_bits(bits))
+        }
+        None => entities.resolve_from_id(text.parse().ok()?),
+    }
+}
+
+fn damage_command(
+    mut command: ConsoleCommand<DamageCommand>,
+    entities: &Entities,
+    query: Query<&HealthSystem>,
+    mut events: EventWriter<DamageEvent>,
+) {
+    let Some(Ok(DamageCommand { entity, amount })) = command.take() else {
+        return;
+    };
+    let Some(target) = parse_entity(&entity, entities).filter(|&entity| query.contains(entity))
+    else {
+        reply_failed!(command, "No entity with health matching {entity}");
+        return;
+    };
+    events.send(DamageEvent::new(target, amount).with_cause("console"));
+    command.ok();
+}
+
+fn heal_command(
+    mut command: ConsoleCommand<HealCommand>,
+    entities: &Entities,
+    query: Query<&HealthSystem>,
+    mut events: EventWriter<HealEvent>,
+) {
+    let Some(Ok(HealCommand { entity, amount })) = command.take() else {
+        return;
+    };
+    let Some(target) = parse_entity(&entity, entities).filter(|&entity| query.contains(entity))
+    else {
+        reply_failed!(command, "No entity with health matching {entity}");
+        return;
+    };
+    events.send(HealEvent::new(target, amount));
+    command.ok();
+}
+
+fn kill_command(
+    mut command: ConsoleCommand<KillCommand>,
+    entities: &Entities,
+    query: Query<&HealthSystem>,
+    mut events: EventWriter<DamageEvent>,
+) {
+    let Some(Ok(KillCommand { entity })) = command.take() else {
+        return;
+    };
+    let Some(target) = parse_entity(&entity, entities).filter(|&entity| query.contains(entity))
+    else {
+        reply_failed!(command, "No entity with health matching {entity}");
+        return;
+    };
+    // Executing at full health kills through damage caps and mitigation.
+    let health = query.get(target).map_or(0.0, HealthSystem::get_health);
+    events.send(
+        DamageEvent::new(target, health.max(1.0))
+            .forced()
+            .with_execute(Fraction::ONE)
+            .with_cause(DamageCause::CONSOLE),
+    );
+    command.ok();
+}
+
+fn revive_command(
+    mut command: ConsoleCommand<ReviveCommand>,
+    entities: &Entities,
+    query: Query<&HealthSystem>,
+    mut events: EventWriter<ReviveEvent>,
+) {
+    let Some(Ok(ReviveCommand { entity })) = command.take() else {
+        return;
+    };
+    let Some(target) = parse_entity(&entity, entities).filter(|&entity| query.contains(entity))
+    else {
+        reply_failed!(command, "No entity with health matching {entity}");
+        return;
+    };
+    events.send(ReviveEvent::new(
+        target,
+        HealthSystemReviveHealType::HealFull,
+    ));
+    command.ok();
+}
+
+fn god_command(
+    mut command: ConsoleCommand<GodCommand>,
+    entities: &Entities,
+    mut query: Query<&mut HealthSystem>,
+    mut settings: ResMut<GlobalDamageSettings>,
+) {
+    let Some(Ok(GodCommand { entity })) = command.take() else {
+        return;
+    };
+    let Some(entity) = entity else {
+        settings.god_mode = !settings.god_mode;
+        reply!(command, "God mode: {}", settings.god_mode);
+        command.ok();
+        return;
+    };
+    let Some(Ok(mut health_system)) =
+        parse_entity(&entity, entities).map(|target| query.get_mut(target))
+    else {
+        reply_failed!(command, "No entity with health matching {entity}");
+        return;
+    };
+    let invincible = !health_system.has_modifier(HealthSystemModifier::INVINCIBLE);
+    if invincible {
+        health_system.add_modifier(HealthSystemModifier::INVINCIBLE);
+    } else {
+        health_system.remove_modifier(HealthSystemModifier::INVINCIBLE);
+    }
+    reply!(command, "Invincible: {invincible}");
+    command.ok();
+}
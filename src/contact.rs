@@ -0,0 +1,147 @@
+//! Damage dealt by touching an entity, eg: spikes, enemies,...
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+
+use crate::{DamageEvent, DamageType};
+
+/// Damage entities touching this one, through the damage pipeline.
+/// Contacts are read from the physics crate enabled with the `rapier` or `avian` feature,
+/// each touching entity is damaged at most once per `cooldown`.
+/// With `rapier`, colliders need [`ActiveEvents::COLLISION_EVENTS`](https://docs.rs/bevy_rapier3d/0.22.0/bevy_rapier3d/geometry/struct.ActiveEvents.html) to report contacts.
+/// # Fields
+/// * `amount`: The amount of damage dealt per hit.
+/// * `cooldown`: The time between two hits on the same entity.
+/// * `damage_type`: The type of the damage.
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{ContactDamage, DamageType};
+///
+/// fn spawn_lava(mut commands: Commands) {
+///     commands.spawn(ContactDamage::new(10.0, Duration::from_millis(500)).with_type(DamageType::FIRE));
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct ContactDamage {
+    pub amount: f32,
+    pub cooldown: Duration,
+    pub damage_type: DamageType,
+}
+
+impl ContactDamage {
+    /// Damage entities touching this one.
+    /// # Arguments
+    /// * `amount`: The amount of damage dealt per hit.
+    /// * `cooldown`: The time between two hits on the same entity.
+    pub fn new(amount: f32, cooldown: Duration) -> Self {
+        Self {
+            amount,
+            cooldown,
+            damage_type: DamageType::default(),
+        }
+    }
+
+    /// Set the type of the damage.
+    pub fn with_type(mut self, damage_type: DamageType) -> Self {
+        self.damage_type = damage_type;
+        self
+    }
+}
+
+/// Pairs of touching entities, filled by the physics integrations.
+#[derive(Debug, Default, Resource)]
+pub(crate) struct Contacts {
+    touching: HashSet<(Entity, Entity)>,
+    last_hit: HashMap<(Entity, Entity), Duration>,
+}
+
+impl Contacts {
+    #[cfg_attr(not(any(feature = "rapier", feature = "avian")), allow(dead_code))]
+    pub(crate) fn start(&mut self, a: Entity, b: Entity) {
+        self.touching.insert((a, b));
+        self.touching.insert((b, a));
+    }
+
+    #[cfg_attr(not(any(feature = "rapier", feature = "avian")), allow(dead_code))]
+    pub(crate) fn stop(&mut self, a: Entity, b: Entity) {
+        self.touching.remove(&(a, b));
+        self.touching.remove(&(b, a));
+    }
+
+    /// Returns the entities touching `entity`.
+    pub(crate) fn touching(&self, entity: Entity) -> impl Iterator<Item = Entity> + '_ {
+        self.touching
+            .iter()
+            .filter(move |(a, _)| *a == entity)
+            .map(|(_, b)| *b)
+    }
+}
+
+pub(crate) fn apply_contact_damage(
+    time: Res<Time>,
+    mut contacts: ResMut<Contacts>,
+    damagers: Query<&ContactDamage>,
+    mut damage: EventWriter<DamageEvent>,
+) {
+    let now = time.elapsed();
+    let Contacts { touching, last_hit } = &mut *contacts;
+
+    last_hit.retain(|pair, hit_at| {
+        touching.contains(pair)
+            || damagers
+                .get(pair.0)
+                .is_ok_and(|contact| now - *hit_at < contact.cooldown)
+    });
+
+    for &(damager, target) in touching.iter() {
+        let Ok(contact) = damagers.get(damager) else {
+            continue;
+        };
+        if last_hit
+            .get(&(damager, target))
+            .is_some_and(|hit_at| now - *hit_at < contact.cooldown)
+        {
+            continue;
+        }
+
+        last_hit.insert((damager, target), now);
+        damage.send(
+            DamageEvent::new(target, contact.amount)
+                .with_type(contact.damage_type)
+                .with_source(damager),
+        );
+    }
+}
+
+#[cfg(feature = "rapier")]
+pub(crate) fn track_rapier_contacts(
+    mut events: EventReader<bevy_rapier3d::prelude::CollisionEvent>,
+    mut contacts: ResMut<Contacts>,
+) {
+    use bevy_rapier3d::prelude::CollisionEvent;
+
+    for event in events.iter() {
+        match *event {
+            CollisionEvent::Started(a, b, _) => contacts.start(a, b),
+            CollisionEvent::Stopped(a, b, _) => contacts.stop(a, b),
+        }
+    }
+}
+
+#[cfg(feature = "avian")]
+pub(crate) fn track_avian_contacts(
+    mut started: EventReader<bevy_xpbd_3d::prelude::CollisionStarted>,
+    mut ended: EventReader<bevy_xpbd_3d::prelude::CollisionEnded>,
+    mut contacts: ResMut<Contacts>,
+) {
+    for event in started.iter() {
+        contacts.start(event.0, event.1);
+    }
+    for event in ended.iter() {
+        contacts.stop(event.0, event.1);
+    }
+}
@@ -0,0 +1,70 @@
+//! Critical hits.
+use bevy::prelude::*;
+
+use crate::{DamageContext, HealthRng};
+
+/// Let an entity deal critical hits, multiplying the damage it deals.
+/// Crits are rolled with the [`HealthRng`](crate::HealthRng), reflected damage never crits.
+/// The [`DamageAppliedEvent::was_crit`](crate::DamageAppliedEvent::was_crit) of critical hits is set.
+/// # Fields
+/// * `chance`: The chance to deal a critical hit, from `0.0` to `1.0`.
+/// * `multiplier`: The multiplier of the damage of critical hits.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{CritStats, DamageAppliedEvent, DamageEvent, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let attacker = app.world.spawn(CritStats::new(1.0, 2.0)).id();
+/// let entity = app.world.spawn(HealthSystem::new(100.0)).id();
+/// app.world.send_event(DamageEvent::new(entity, 10.0).with_source(attacker));
+/// app.update();
+///
+/// assert_eq!(80.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// let events = app.world.resource::<Events<DamageAppliedEvent>>();
+/// assert!(events.iter_current_update_events().next().unwrap().was_crit);
+/// ```
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct CritStats {
+    pub chance: f32,
+    pub multiplier: f32,
+}
+
+/// Never crit, double damage otherwise.
+impl Default for CritStats {
+    fn default() -> Self {
+        Self::new(0.0, 2.0)
+    }
+}
+
+impl CritStats {
+    /// Let an entity deal critical hits.
+    /// # Arguments
+    /// * `chance`: The chance to deal a critical hit, from `0.0` to `1.0`.
+    /// * `multiplier`: The multiplier of the damage of critical hits.
+    pub fn new(chance: f32, multiplier: f32) -> Self {
+        Self { chance, multiplier }
+    }
+}
+
+pub(crate) fn crit_stage(context: &mut DamageContext, world: &mut World) {
+    if context.event.reflected || context.is_preview() {
+        return;
+    }
+    let Some(crit) = context
+        .event
+        .source
+        .and_then(|source| world.get::<CritStats>(source))
+        .cloned()
+    else {
+        return;
+    };
+
+    if world.resource_mut::<HealthRng>().chance(crit.chance) {
+        context.was_crit = true;
+        context.amount *= crit.multiplier;
+    }
+}
@@ -0,0 +1,463 @@
+//! Damage requests and the systems applying them.
+use std::borrow::Cow;
+
+use bevy::ecs::event::ManualEventReader;
+use bevy::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::poise::apply_poise_damage;
+use crate::shield::apply_shield_absorbed;
+use crate::temporary_health::apply_temporary_absorbed;
+use crate::{
+    DamageBreakdown, DamagePipeline, DamageType, DeathEvent, Downable, Downed, DownedEvent, Endure,
+    EnduredLethalHit, ExecuteThreshold, Fraction, GlobalDamageSettings, HealthSystem,
+    RecentAttackers, RecentHit,
+};
+
+/// Request to deal damage to an entity's [`HealthSystem`], or to its pool if it is a [`SharedHealthMember`](crate::SharedHealthMember).
+/// Send it with an [`EventWriter`], the [`HealthSystemPlugin`](crate::HealthSystemPlugin) applies it.
+/// # Fields
+/// * `target`: The entity receiving the damage, or one of its [`HitZone`](crate::HitZone)s.
+/// * `amount`: The amount of damage dealt.
+/// * `force`: Ignore anything that prevents dealing damage, eg: invincibility, invulnerability frames,...
+/// * `damage_type`: The type of the damage, see [`Resistances`](crate::Resistances).
+/// * `source`: The entity dealing the damage, if any.
+/// * `reflected`: The damage was reflected by [`Thorns`](crate::Thorns), it can't be reflected again.
+/// * `cause`: What caused the damage, eg: a weapon, the environment, a fall,...
+/// * `weapon`: The tag of the weapon dealing the damage, passed along to the [`DeathEvent`] and [`Kill`](crate::Kill) events.
+/// * `poise_damage`: How much this damage lowers the target [`Poise`](crate::Poise), the amount of damage if `None`.
+/// * `variance`: The amount is randomly rolled up to this fraction above or below, eg: `0.1` for ±10%.
+/// * `hit`: Where and how the target was hit, passed along untouched to the [`DamageAppliedEvent`].
+/// * `health_floor`: The lowest health this damage can leave, the damage is non-lethal if set.
+/// * `execute`: Execute the target if left below this threshold, instead of the [`ExecuteThreshold`] of the source.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app.world.spawn(HealthSystem::new(100.0)).id();
+/// app.world.send_event(DamageEvent::new(entity, 10.0));
+/// app.update();
+///
+/// assert_eq!(90.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// ```
+#[derive(Debug, Clone, Event)]
+pub struct DamageEvent {
+    pub target: Entity,
+    pub amount: f32,
+    pub force: bool,
+    pub damage_type: DamageType,
+    pub source: Option<Entity>,
+    pub reflected: bool,
+    pub cause: Option<DamageCause>,
+    pub weapon: Option<Cow<'static, str>>,
+    pub poise_damage: Option<f32>,
+    pub variance: f32,
+    pub hit: Option<HitInfo>,
+    pub health_floor: Option<f32>,
+    pub execute: Option<ExecuteThreshold>,
+}
+
+impl DamageEvent {
+    /// Create a new, non forced, damage request.
+    /// # Arguments
+    /// * `target`: The entity receiving the damage.
+    /// * `amount`: The amount of damage dealt.
+    pub fn new(target: Entity, amount: f32) -> Self {
+        Self {
+            target,
+            amount,
+            force: false,
+            damage_type: DamageType::default(),
+            source: None,
+            reflected: false,
+            cause: None,
+            weapon: None,
+            poise_damage: None,
+            variance: 0.0,
+            hit: None,
+            health_floor: None,
+            execute: None,
+        }
+    }
+
+    /// Make this damage ignore anything that prevents dealing damage.
+    pub fn forced(mut self) -> Self {
+        self.force = true;
+        self
+    }
+
+    /// Set the type of this damage.
+    /// # Arguments
+    /// * `damage_type`: The type of the damage.
+    pub fn with_type(mut self, damage_type: DamageType) -> Self {
+        self.damage_type = damage_type;
+        self
+    }
+
+    /// Set the entity dealing this damage.
+    /// # Arguments
+    /// * `source`: The entity dealing the damage.
+    pub fn with_source(mut self, source: Entity) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    /// Set what caused this damage.
+    /// # Arguments
+    /// * `cause`: What caused the damage.
+    pub fn with_cause(mut self, cause: impl Into<DamageCause>) -> Self {
+        self.cause = Some(cause.into());
+        self
+    }
+
+    /// Set the tag of the weapon dealing this damage.
+    pub fn with_weapon(mut self, weapon: impl Into<Cow<'static, str>>) -> Self {
+        self.weapon = Some(weapon.into());
+        self
+    }
+
+    /// Set how much this damage lowers the target [`Poise`](crate::Poise).
+    /// # Arguments
+    /// * `poise_damage`: The amount of poise removed.
+    pub fn with_poise_damage(mut self, poise_damage: f32) -> Self {
+        self.poise_damage = Some(poise_damage);
+        self
+    }
+
+    /// Randomly roll the amount of this damage, using the [`HealthRng`](crate::HealthRng).
+    /// # Arguments
+    /// * `variance`: The fraction the amount can go above or below, eg: `0.1` for ±10%.
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_health_system::{DamageEvent, HealthRng, HealthSystem, HealthSystemPlugin};
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+    /// app.insert_resource(HealthRng::new(7));
+    ///
+    /// let entity = app.world.spawn(HealthSystem::new(100.0)).id();
+    /// app.world.send_event(DamageEvent::new(entity, 10.0).with_variance(0.1));
+    /// app.update();
+    ///
+    /// let health = app.world.get::<HealthSystem>(entity).unwrap().get_health();
+    /// assert!((89.0..=91.0).contains(&health));
+    /// ```
+    pub fn with_variance(mut self, variance: f32) -> Self {
+        self.variance = variance;
+        self
+    }
+
+    /// Set where and how the target was hit.
+    /// # Arguments
+    /// * `hit`: The spatial data of the hit.
+    pub fn with_hit(mut self, hit: HitInfo) -> Self {
+        self.hit = Some(hit);
+        self
+    }
+
+    /// Make this damage unable to kill, or down, the target, leaving it at `1.0` health at least.
+    /// # Example
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_health_system::{DamageEvent, HealthSystem, HealthSystemPlugin};
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+    ///
+    /// let dummy = app.world.spawn(HealthSystem::new(100.0)).id();
+    /// app.world.send_event(DamageEvent::new(dummy, 500.0).non_lethal());
+    /// app.update();
+    ///
+    /// assert_eq!(1.0, app.world.get::<HealthSystem>(dummy).unwrap().get_health());
+    /// ```
+    pub fn non_lethal(self) -> Self {
+        self.with_health_floor(1.0)
+    }
+
+    /// Make this damage unable to bring the health of the target below `floor`.
+    /// # Arguments
+    /// * `floor`: The lowest health the damage can leave.
+    pub fn with_health_floor(mut self, floor: f32) -> Self {
+        self.health_floor = Some(floor);
+        self
+    }
+
+    /// Make this damage execute the target if it leaves its health below a fraction of its max health.
+    /// # Arguments
+    /// * `fraction`: The fraction of max health below which the target is executed.
+    pub fn with_execute(mut self, fraction: Fraction) -> Self {
+        self.execute = Some(ExecuteThreshold::new(fraction));
+        self
+    }
+}
+
+/// Spatial data of a hit, eg: for physics or animation systems reacting to a [`DamageAppliedEvent`].
+/// The [`HealthSystemPlugin`](crate::HealthSystemPlugin) never reads it.
+/// # Fields
+/// * `direction`: The direction the hit came from.
+/// * `knockback`: The impulse to apply to the target.
+/// * `point`: Where the target was hit, in world space.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageAppliedEvent, DamageEvent, HealthSystem, HealthSystemPlugin, HitInfo};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app.world.spawn(HealthSystem::new(100.0)).id();
+/// let hit = HitInfo::default()
+///     .with_direction(Vec3::X)
+///     .with_knockback(Vec3::X * 5.0)
+///     .with_point(Vec3::new(0.0, 1.5, 0.0));
+/// app.world.send_event(DamageEvent::new(entity, 10.0).with_hit(hit));
+/// app.update();
+///
+/// let events = app.world.resource::<Events<DamageAppliedEvent>>();
+/// let applied = events.iter_current_update_events().next().unwrap();
+/// assert_eq!(Some(Vec3::X * 5.0), applied.hit.unwrap().knockback);
+/// ```
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub struct HitInfo {
+    pub direction: Option<Vec3>,
+    pub knockback: Option<Vec3>,
+    pub point: Option<Vec3>,
+}
+
+impl HitInfo {
+    /// Set the direction the hit came from.
+    pub fn with_direction(mut self, direction: Vec3) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Set the impulse to apply to the target.
+    pub fn with_knockback(mut self, knockback: Vec3) -> Self {
+        self.knockback = Some(knockback);
+        self
+    }
+
+    /// Set where the target was hit.
+    pub fn with_point(mut self, point: Vec3) -> Self {
+        self.point = Some(point);
+        self
+    }
+}
+
+/// A tag describing what caused some damage, eg: a weapon id, the environment, a fall,...
+/// # Examples
+/// ```
+/// use bevy_health_system::{DamageCause, DamageEvent};
+/// # let entity = bevy::prelude::Entity::PLACEHOLDER;
+///
+/// let sword = DamageEvent::new(entity, 10.0).with_cause("iron_sword");
+/// assert_eq!("iron_sword", sword.cause.unwrap().as_str());
+///
+/// let fall = DamageEvent::new(entity, 10.0).with_cause(DamageCause::FALL);
+/// assert_eq!(Some(DamageCause::FALL), fall.cause);
+/// ```
+/// Kills without damage carry a cause too, see [`HealthSystem::kill_system_with_cause`].
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DamageCause(Cow<'static, str>);
+
+impl DamageCause {
+    pub const ENVIRONMENT: Self = Self(Cow::Borrowed("environment"));
+    pub const FALL: Self = Self(Cow::Borrowed("fall"));
+    pub const DECAY: Self = Self(Cow::Borrowed("decay"));
+    /// The cause of [`HealthSystem::kill_system`], eg: a cutscene killing the player.
+    pub const SCRIPTED_KILL: Self = Self(Cow::Borrowed("scripted_kill"));
+    pub const OUT_OF_BOUNDS: Self = Self(Cow::Borrowed("out_of_bounds"));
+    pub const CONSOLE: Self = Self(Cow::Borrowed("console"));
+
+    /// Create a new damage cause.
+    pub fn new(cause: impl Into<Cow<'static, str>>) -> Self {
+        Self(cause.into())
+    }
+
+    /// Returns the tag of this cause.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&'static str> for DamageCause {
+    fn from(cause: &'static str) -> Self {
+        Self::new(cause)
+    }
+}
+
+impl From<String> for DamageCause {
+    fn from(cause: String) -> Self {
+        Self::new(cause)
+    }
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) after a [`DamageEvent`] was applied.
+/// # Fields
+/// * `target`: The entity that took the damage, the pool when the damage was dealt to a [`SharedHealthMember`](crate::SharedHealthMember).
+/// * `member`: The [`SharedHealthMember`](crate::SharedHealthMember) the damage was dealt to, if any.
+/// * `zone`: The tag of the [`HitZone`](crate::HitZone) the damage was dealt to, if any.
+/// * `source`: The entity that dealt the damage, if any.
+/// * `reflected`: The damage was reflected by [`Thorns`](crate::Thorns).
+/// * `cause`: What caused the damage, if known.
+/// * `killed`: The damage killed the target.
+/// * `friendly_fire`: The damage was dealt by a teammate, see [`FriendlyFirePolicy`](crate::FriendlyFirePolicy).
+/// * `was_crit`: The damage was a critical hit, see [`CritStats`](crate::CritStats).
+/// * `was_execute`: The damage executed the target, see [`ExecuteThreshold`].
+/// * `amount`: The amount of health the target actually lost.
+/// * `damage_type`: The type of the damage.
+/// * `breakdown`: How the damage was mitigated by the target, eg: its [`Resistances`](crate::Resistances),
+///   [`Shield`](crate::Shield), [`Armor`](crate::Armor),...
+/// * `hit`: Where and how the target was hit, from the [`DamageEvent`].
+#[derive(Debug, Clone, Event)]
+pub struct DamageAppliedEvent {
+    pub target: Entity,
+    pub member: Option<Entity>,
+    pub zone: Option<Cow<'static, str>>,
+    pub source: Option<Entity>,
+    pub reflected: bool,
+    pub cause: Option<DamageCause>,
+    pub killed: bool,
+    pub friendly_fire: bool,
+    pub was_crit: bool,
+    pub was_execute: bool,
+    pub amount: f32,
+    pub damage_type: DamageType,
+    pub breakdown: DamageBreakdown,
+    pub hit: Option<HitInfo>,
+}
+
+/// Resolve a damage request through the [`DamagePipeline`] and apply it, sending the events of its outcome.
+pub(crate) fn apply_damage(world: &mut World, pipeline: &DamagePipeline, event: &DamageEvent) {
+    let policy = world.resource::<GlobalDamageSettings>().invalid_amounts;
+    let clamped;
+    let event = match policy.check(event.amount) {
+        None => return,
+        Some(amount) if amount.to_bits() == event.amount.to_bits() => event,
+        Some(amount) => {
+            clamped = DamageEvent {
+                amount,
+                ..event.clone()
+            };
+            &clamped
+        }
+    };
+    let Some(mut context) = pipeline.run(world, event, false) else {
+        return;
+    };
+    let Some(amount) = policy.check(context.amount) else {
+        return;
+    };
+    context.amount = amount;
+    let entity = context.target;
+    let downable = world
+        .get::<Downable>(entity)
+        .map(|downable| downable.bleed_out);
+    let Some(mut health_system) = world.get_mut::<HealthSystem>(entity) else {
+        return;
+    };
+
+    let was_downed = health_system.is_downed();
+    let health_before = health_system.get_health();
+    let lethal = context.is_lethal(health_before);
+    if let (true, false, Some(bleed_out)) = (lethal, was_downed, downable) {
+        health_system.down_system();
+        world
+            .entity_mut(entity)
+            .insert(Downed::new(bleed_out, event.source));
+        world.send_event(DownedEvent {
+            entity,
+            source: event.source,
+        });
+    } else if let (true, Some(floor)) = (context.amount > 0.0, context.health_floor()) {
+        health_system.deal_damage_non_lethal(context.amount, floor, event.force);
+    } else if context.amount > 0.0 {
+        health_system.deal_damage(context.amount, event.force);
+    }
+
+    apply_poise_damage(&context, world);
+    apply_shield_absorbed(&context, world);
+    apply_temporary_absorbed(&context, world);
+
+    if context.endured() {
+        world.entity_mut(entity).remove::<Endure>();
+        world.send_event(EnduredLethalHit {
+            entity,
+            source: event.source,
+        });
+    }
+
+    let Some(health_system) = world.get::<HealthSystem>(entity) else {
+        return;
+    };
+    let killed = health_system.is_dead();
+    let amount = health_before - health_system.get_health();
+    let applied = amount.clamp(0.0, health_before.max(0.0));
+    let now = world.resource::<Time>().elapsed();
+    let mut contributors = Vec::new();
+    if let Some(mut attackers) = world.get_mut::<RecentAttackers>(entity) {
+        if let (Some(source), true) = (event.source, applied > 0.0) {
+            attackers.record(RecentHit {
+                source,
+                damage: applied,
+                time: now,
+            });
+        }
+        if killed {
+            contributors = attackers.contributions(now);
+        }
+    }
+    if killed {
+        world.send_event(DeathEvent {
+            entity,
+            source: event.source,
+            cause: event.cause.clone(),
+            weapon: event.weapon.clone(),
+            contributors,
+        });
+    }
+
+    let breakdown = context.breakdown(applied, lethal);
+    world.send_event(DamageAppliedEvent {
+        target: entity,
+        member: (entity != context.hit).then_some(context.hit),
+        zone: context.zone,
+        source: event.source,
+        reflected: event.reflected,
+        cause: event.cause.clone(),
+        killed,
+        friendly_fire: context.friendly_fire,
+        was_crit: context.was_crit,
+        was_execute: context.was_execute,
+        amount,
+        damage_type: event.damage_type,
+        breakdown,
+        hit: event.hit,
+    });
+}
+
+pub(crate) fn apply_damage_events(
+    world: &mut World,
+    mut reader: Local<ManualEventReader<DamageEvent>>,
+) {
+    let events: Vec<_> = reader
+        .iter(world.resource::<Events<DamageEvent>>())
+        .cloned()
+        .collect();
+    if events.is_empty() {
+        return;
+    }
+
+    world.resource_scope(|world, pipeline: Mut<DamagePipeline>| {
+        for event in &events {
+            apply_damage(world, &pipeline, event);
+        }
+    });
+}
@@ -0,0 +1,407 @@
+//! Floating combat text spawned where entities are hit or healed.
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{BlockedHit, DamageAppliedEvent, DamageImmune, HealEvent};
+
+/// Add this plugin to spawn a [`DamageNumber`] each time an entity is damaged, healed, blocks or is immune to a hit.
+/// It is not part of the [`HealthSystemPlugin`](crate::HealthSystemPlugin), add both.
+/// Numbers are styled by the [`DamageNumberStyle`] resource and recycled through the [`DamageNumberPool`].
+#[derive(Debug, Default)]
+pub struct DamageNumbersPlugin;
+
+impl Plugin for DamageNumbersPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DamageNumberStyle>()
+            .init_resource::<DamageNumberPool>()
+            .register_type::<DamageNumber>()
+            .register_type::<DamageNumberKind>()
+            .register_type::<DamageNumberSpace>()
+            .add_systems(
+                PostUpdate,
+                (spawn_damage_numbers, animate_damage_numbers).chain(),
+            );
+    }
+}
+
+/// What a [`DamageNumber`] shows.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Copy, Clone, Reflect)]
+pub enum DamageNumberKind {
+    /// __Default__. Damage applied from a [`DamageEvent`](crate::DamageEvent).
+    #[default]
+    Damage,
+    /// Damage applied from a critical hit, see [`CritStats`](crate::CritStats).
+    Crit,
+    /// Health requested by a [`HealEvent`].
+    Heal,
+    /// Damage removed by blocking, see [`BlockedHit`].
+    Blocked,
+    /// A hit rejected by [`DamageImmunities`](crate::DamageImmunities), see [`DamageImmune`].
+    Immune,
+}
+
+/// How a kind of [`DamageNumber`] looks.
+/// # Fields
+/// * `color`: The color of the text, faded out over the lifetime of the number.
+/// * `font_size`: The font size of the text.
+/// * `label`: Turns the amount into the text shown, eg: `|amount| format!("{amount:.0}!")`.
+#[derive(Debug, Clone)]
+pub struct DamageNumberLook {
+    pub color: Color,
+    pub font_size: f32,
+    pub label: fn(f32) -> String,
+}
+
+impl DamageNumberLook {
+    /// Create a look showing the amount, rounded.
+    /// # Arguments
+    /// * `color`: The color of the text.
+    /// * `font_size`: The font size of the text.
+    pub fn new(color: Color, font_size: f32) -> Self {
+        Self {
+            color,
+            font_size,
+            label: |amount| format!("{amount:.0}"),
+        }
+    }
+
+    /// Set how the amount is turned into the text shown.
+    pub fn with_label(mut self, label: fn(f32) -> String) -> Self {
+        self.label = label;
+        self
+    }
+}
+
+/// Where [`DamageNumber`]s are drawn.
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone, Reflect)]
+pub enum DamageNumberSpace {
+    /// __Default__. `Text2d` in the world, for 2D games.
+    #[default]
+    World2d,
+    /// `bevy_ui` text following the hit location as seen by the first 3D camera, for 3D games.
+    Screen,
+}
+
+/// The style of the [`DamageNumber`]s spawned by the [`DamageNumbersPlugin`].
+/// # Fields
+/// * `space`: Where the numbers are drawn.
+/// * `damage`, `crit`, `heal`, `blocked`, `immune`: How each [`DamageNumberKind`] looks.
+/// * `lifetime`: How long a number is shown.
+/// * `velocity`: How fast a number moves, in world units per second.
+/// * `offset`: Where a number spawns relative to the entity, unless the [`HitInfo`](crate::HitInfo) has a point.
+#[derive(Debug, Clone, Resource)]
+pub struct DamageNumberStyle {
+    pub space: DamageNumberSpace,
+    pub damage: DamageNumberLook,
+    pub crit: DamageNumberLook,
+    pub heal: DamageNumberLook,
+    pub blocked: DamageNumberLook,
+    pub immune: DamageNumberLook,
+    pub lifetime: Duration,
+    pub velocity: Vec3,
+    pub offset: Vec3,
+}
+
+impl Default for DamageNumberStyle {
+    fn default() -> Self {
+        Self {
+            space: DamageNumberSpace::World2d,
+            damage: DamageNumberLook::new(Color::WHITE, 24.0),
+            crit: DamageNumberLook::new(Color::ORANGE, 32.0)
+                .with_label(|amount| format!("{amount:.0}!")),
+            heal: DamageNumberLook::new(Color::GREEN, 24.0)
+                .with_label(|amount| format!("+{amount:.0}")),
+            blocked: DamageNumberLook::new(Color::GRAY, 20.0).with_label(|_| "Blocked".to_string()),
+            immune: DamageNumberLook::new(Color::GRAY, 20.0).with_label(|_| "Immune".to_string()),
+            lifetime: Duration::from_secs(1),
+            velocity: Vec3::Y * 40.0,
+            offset: Vec3::ZERO,
+        }
+    }
+}
+
+impl DamageNumberStyle {
+    /// Returns how this kind of number looks.
+    pub fn look(&self, kind: DamageNumberKind) -> &DamageNumberLook {
+        match kind {
+            DamageNumberKind::Damage => &self.damage,
+            DamageNumberKind::Crit => &self.crit,
+            DamageNumberKind::Heal => &self.heal,
+            DamageNumberKind::Blocked => &self.blocked,
+            DamageNumberKind::Immune => &self.immune,
+        }
+    }
+}
+
+/// The expired [`DamageNumber`]s, hidden and reused by the next hits instead of spawning new entities.
+/// # Fields
+/// * `capacity`: The most numbers kept for reuse, extra expired numbers are despawned.
+#[derive(Debug, Clone, Resource)]
+pub struct DamageNumberPool {
+    pub capacity: usize,
+    free: Vec<Entity>,
+}
+
+impl Default for DamageNumberPool {
+    fn default() -> Self {
+        Self {
+            capacity: 64,
+            free: Vec::new(),
+        }
+    }
+}
+
+impl DamageNumberPool {
+    /// Returns the number of hidden numbers ready for reuse.
+    pub fn len(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Returns true if no number is ready for reuse.
+    pub fn is_empty(&self) -> bool {
+        self.free.is_empty()
+    }
+}
+
+/// A floating text spawned by the [`DamageNumbersPlugin`], moving and fading out until it expires.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageEvent, DamageNumber, DamageNumberKind, DamageNumberPool, DamageNumbersPlugin,
+///     HealEvent, HealthSystem, HealthSystemPlugin,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin, DamageNumbersPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(2)));
+///
+/// let entity = app.world.spawn((HealthSystem::new(100.0), TransformBundle::default())).id();
+/// app.world.send_event(DamageEvent::new(entity, 25.0));
+/// app.update();
+///
+/// let mut numbers = app.world.query::<(&DamageNumber, &Text)>();
+/// let (number, text) = numbers.single(&app.world);
+/// assert_eq!((DamageNumberKind::Damage, "25"), (number.kind, text.sections[0].value.as_str()));
+///
+/// // The number expires and is reused by the next one.
+/// app.update();
+/// assert_eq!(1, app.world.resource::<DamageNumberPool>().len());
+/// app.world.send_event(HealEvent::new(entity, 10.0));
+/// app.update();
+/// let (number, text) = numbers.single(&app.world);
+/// assert_eq!((DamageNumberKind::Heal, "+10"), (number.kind, text.sections[0].value.as_str()));
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct DamageNumber {
+    pub kind: DamageNumberKind,
+    pub amount: f32,
+    pub position: Vec3,
+    space: DamageNumberSpace,
+    age: Duration,
+    fresh: bool,
+    expired: bool,
+}
+
+impl DamageNumber {
+    /// Returns how long the number has been shown.
+    pub fn age(&self) -> Duration {
+        self.age
+    }
+
+    /// Returns true once the number expired and waits for reuse.
+    pub fn is_expired(&self) -> bool {
+        self.expired
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn spawn_damage_numbers(
+    mut commands: Commands,
+    style: Res<DamageNumberStyle>,
+    mut pool: ResMut<DamageNumberPool>,
+    mut damaged: EventReader<DamageAppliedEvent>,
+    mut healed: EventReader<HealEvent>,
+    mut blocked: EventReader<BlockedHit>,
+    mut immune: EventReader<DamageImmune>,
+    transforms: Query<&GlobalTransform>,
+    mut numbers: Query<(&mut DamageNumber, &mut Text, &mut Visibility)>,
+) {
+    let mut hits = Vec::new();
+    for event in damaged.iter() {
+        if event.breakdown.applied <= 0.0 {
+            continue;
+        }
+        let kind = if event.was_crit {
+            DamageNumberKind::Crit
+        } else {
+            DamageNumberKind::Damage
+        };
+        let point = event.hit.as_ref().and_then(|hit| hit.point);
+        hits.push((event.target, point, kind, event.breakdown.applied));
+    }
+    for event in healed.iter().filter(|event| event.amount > 0.0) {
+        hits.push((event.target, None, DamageNumberKind::Heal, event.amount));
+    }
+    for event in blocked.iter() {
+        hits.push((event.target, None, DamageNumberKind::Blocked, event.blocked));
+    }
+    for event in immune.iter() {
+        hits.push((event.target, None, DamageNumberKind::Immune, 0.0));
+    }
+
+    for (target, point, kind, amount) in hits {
+        let Some(position) = point.or_else(|| {
+            let transform = transforms.get(target).ok()?;
+            Some(transform.translation() + style.offset)
+        }) else {
+            continue;
+        };
+
+        let look = style.look(kind);
+        let number = DamageNumber {
+            kind,
+            amount,
+            position,
+            space: style.space,
+            age: Duration::ZERO,
+            fresh: false,
+            expired: false,
+        };
+        let section = TextSection::new(
+            (look.label)(amount),
+            TextStyle {
+                font_size: look.font_size,
+                color: look.color,
+                ..default()
+            },
+        );
+
+        let mut reused = false;
+        while let Some(entity) = pool.free.pop() {
+            let Ok((mut free, mut text, mut visibility)) = numbers.get_mut(entity) else {
+                continue;
+            };
+            if free.space != style.space {
+                commands.entity(entity).despawn();
+                continue;
+            }
+            // Reused numbers are animated later this frame, do not age them yet.
+            *free = DamageNumber {
+                fresh: true,
+                ..number.clone()
+            };
+            text.sections = vec![section.clone()];
+            *visibility = Visibility::Inherited;
+            reused = true;
+            break;
+        }
+        if reused {
+            continue;
+        }
+
+        let text = Text::from_sections([section]);
+        match style.space {
+            DamageNumberSpace::World2d => commands.spawn((
+                number,
+                Text2dBundle {
+                    text,
+                    transform: Transform::from_translation(position),
+                    ..default()
+                },
+            )),
+            DamageNumberSpace::Screen => commands.spawn((
+                number,
+                TextBundle {
+                    text,
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        ..default()
+                    },
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+            )),
+        };
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn animate_damage_numbers(
+    mut commands: Commands,
+    time: Res<Time>,
+    style: Res<DamageNumberStyle>,
+    mut pool: ResMut<DamageNumberPool>,
+    cameras: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    mut numbers: Query<(
+        Entity,
+        &mut DamageNumber,
+        &mut Text,
+        &mut Visibility,
+        Option<&mut Transform>,
+        Option<&mut Style>,
+    )>,
+) {
+    let lifetime = style.lifetime.as_secs_f32();
+    let camera = cameras.iter().find(|(camera, _)| camera.is_active);
+
+    for (entity, mut number, mut text, mut visibility, transform, node_style) in &mut numbers {
+        if number.expired {
+            continue;
+        }
+        if number.fresh {
+            number.fresh = false;
+        } else {
+            number.age += time.delta();
+        }
+        if number.age >= style.lifetime {
+            number.expired = true;
+            *visibility = Visibility::Hidden;
+            if pool.free.len() < pool.capacity {
+                pool.free.push(entity);
+            } else {
+                commands.entity(entity).despawn();
+            }
+            continue;
+        }
+
+        let age = number.age.as_secs_f32();
+        let position = number.position + style.velocity * age;
+        let alpha = if lifetime > 0.0 {
+            1.0 - age / lifetime
+        } else {
+            1.0
+        };
+        let color = style.look(number.kind).color;
+        for section in &mut text.sections {
+            section.style.color = color.with_a(color.a() * alpha);
+        }
+
+        match number.space {
+            DamageNumberSpace::World2d => {
+                if let Some(mut transform) = transform {
+                    transform.translation = position;
+                }
+            }
+            DamageNumberSpace::Screen => {
+                let viewport = camera.and_then(|(camera, camera_transform)| {
+                    camera.world_to_viewport(camera_transform, position)
+                });
+                let shown = match (viewport, node_style) {
+                    (Some(viewport), Some(mut node_style)) => {
+                        node_style.left = Val::Px(viewport.x);
+                        node_style.top = Val::Px(viewport.y);
+                        Visibility::Inherited
+                    }
+                    _ => Visibility::Hidden,
+                };
+                if *visibility != shown {
+                    *visibility = shown;
+                }
+            }
+        }
+    }
+}
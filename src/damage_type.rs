@@ -0,0 +1,296 @@
+//! Damage types, and resistances and immunities against them.
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::DamageContext;
+
+/// The type of the damage carried by a [`DamageEvent`](crate::DamageEvent).
+/// A damage type is identified by its name, define your own with [`DamageType::new`]
+/// and register them in the [`DamageTypeRegistry`] to look their names back up.
+/// # Examples
+/// ```
+/// use bevy_health_system::DamageType;
+///
+/// const VOID: DamageType = DamageType::new("void");
+/// assert_eq!(VOID, DamageType::new("void"));
+/// assert_ne!(VOID, DamageType::FIRE);
+/// assert_eq!(DamageType::PHYSICAL, DamageType::default());
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DamageType(u64);
+
+impl DamageType {
+    /// __Default__.
+    pub const PHYSICAL: Self = Self::new("physical");
+    pub const FIRE: Self = Self::new("fire");
+    pub const POISON: Self = Self::new("poison");
+    /// True damage ignores [`Resistances`] and [`Armor`](crate::Armor).
+    pub const TRUE: Self = Self::new("true");
+
+    /// Create the damage type identified by `name`.
+    /// The same name always creates the same damage type.
+    pub const fn new(name: &str) -> Self {
+        // FNV-1a, stable across runs so damage types can be saved and sent over the network.
+        let bytes = name.as_bytes();
+        let mut hash = 0xcbf29ce484222325_u64;
+        let mut index = 0;
+        while index < bytes.len() {
+            hash ^= bytes[index] as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+            index += 1;
+        }
+
+        Self(hash)
+    }
+
+    /// Returns the id of this damage type.
+    pub fn id(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Default for DamageType {
+    fn default() -> Self {
+        Self::PHYSICAL
+    }
+}
+
+/// Every [`DamageType`] known by the game, with its name.
+/// The [`HealthSystemPlugin`](crate::HealthSystemPlugin) registers the built-in damage types.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageType, DamageTypeRegistry, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let mut registry = app.world.resource_mut::<DamageTypeRegistry>();
+/// let holy = registry.register("holy");
+///
+/// assert_eq!(Some(holy), registry.get("holy"));
+/// assert_eq!(Some("holy"), registry.name(holy));
+/// assert_eq!(Some("fire"), registry.name(DamageType::FIRE));
+/// ```
+#[derive(Debug, Clone, Resource)]
+pub struct DamageTypeRegistry {
+    names: HashMap<DamageType, String>,
+}
+
+impl DamageTypeRegistry {
+    /// - Register a damage type.
+    /// - Returns the registered damage type, the same as [`DamageType::new`].
+    /// # Arguments
+    /// * `name`: The name of the damage type.
+    pub fn register(&mut self, name: &str) -> DamageType {
+        let damage_type = DamageType::new(name);
+        if let Some(registered) = self.names.get(&damage_type) {
+            if registered != name {
+                warn!(
+                    "Damage type `{name}` has the same id as `{registered}`, rename one of them."
+                );
+            }
+            return damage_type;
+        }
+
+        self.names.insert(damage_type, name.to_string());
+        damage_type
+    }
+
+    /// Returns the damage type registered with this name.
+    pub fn get(&self, name: &str) -> Option<DamageType> {
+        let damage_type = DamageType::new(name);
+        self.names.contains_key(&damage_type).then_some(damage_type)
+    }
+
+    /// Returns the name of a registered damage type.
+    pub fn name(&self, damage_type: DamageType) -> Option<&str> {
+        self.names.get(&damage_type).map(String::as_str)
+    }
+
+    /// Returns true if the damage type was registered.
+    pub fn contains(&self, damage_type: DamageType) -> bool {
+        self.names.contains_key(&damage_type)
+    }
+
+    /// Returns every registered damage type and its name.
+    pub fn iter(&self) -> impl Iterator<Item = (DamageType, &str)> {
+        self.names
+            .iter()
+            .map(|(damage_type, name)| (*damage_type, name.as_str()))
+    }
+}
+
+impl Default for DamageTypeRegistry {
+    fn default() -> Self {
+        let mut registry = Self {
+            names: HashMap::default(),
+        };
+        for name in ["physical", "fire", "poison", "true"] {
+            registry.register(name);
+        }
+
+        registry
+    }
+}
+
+/// Multipliers applied to damage of each [`DamageType`] dealt to this entity.
+/// Damage types without a multiplier are not changed.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, DamageType, HealthSystem, HealthSystemPlugin, Resistances};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app
+///     .world
+///     .spawn((
+///         HealthSystem::new(100.0),
+///         Resistances::default().with(DamageType::FIRE, 0.5),
+///     ))
+///     .id();
+/// app.world.send_event(DamageEvent::new(entity, 20.0).with_type(DamageType::FIRE));
+/// app.update();
+///
+/// assert_eq!(90.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Resistances {
+    multipliers: HashMap<DamageType, f32>,
+}
+
+impl Resistances {
+    /// Set the multiplier for a damage type.
+    /// # Arguments
+    /// * `damage_type`: The damage type to resist.
+    /// * `multiplier`: The multiplier applied to damage of this type, eg: `0.5` halves it, `2.0` doubles it.
+    pub fn with(mut self, damage_type: DamageType, multiplier: f32) -> Self {
+        self.set(damage_type, multiplier);
+        self
+    }
+
+    /// Set the multiplier for a damage type.
+    /// # Arguments
+    /// * `damage_type`: The damage type to resist.
+    /// * `multiplier`: The multiplier applied to damage of this type, eg: `0.5` halves it, `2.0` doubles it.
+    pub fn set(&mut self, damage_type: DamageType, multiplier: f32) {
+        self.multipliers.insert(damage_type, multiplier);
+    }
+
+    /// Remove the multiplier for a damage type.
+    pub fn remove(&mut self, damage_type: DamageType) {
+        self.multipliers.remove(&damage_type);
+    }
+
+    /// Returns the multiplier for a damage type, `1.0` if there is none.
+    /// # Example
+    /// ```
+    /// use bevy_health_system::{DamageType, Resistances};
+    ///
+    /// let resistances = Resistances::default().with(DamageType::POISON, 0.0);
+    /// assert_eq!(0.0, resistances.get_multiplier(DamageType::POISON));
+    /// assert_eq!(1.0, resistances.get_multiplier(DamageType::FIRE));
+    /// ```
+    pub fn get_multiplier(&self, damage_type: DamageType) -> f32 {
+        self.multipliers.get(&damage_type).copied().unwrap_or(1.0)
+    }
+}
+
+pub(crate) fn resistances_stage(context: &mut DamageContext, world: &mut World) {
+    let multiplier = match world.get::<Resistances>(context.target) {
+        Some(resistances) if !context.ignores_mitigation() => {
+            resistances.get_multiplier(context.event.damage_type)
+        }
+        _ => 1.0,
+    };
+
+    let resisted_amount = context.amount * multiplier;
+    context.resisted = context.amount - resisted_amount;
+    context.amount = resisted_amount;
+    context.after_resistances = resisted_amount;
+}
+
+/// The [`DamageType`]s this entity takes no damage from, eg: a fire elemental immune to fire.
+/// Immune hits send [`DamageImmune`] instead of dealing damage, forced damage ignores immunities.
+/// Saving immunities in serialized scenes requires the `serde` feature.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageEvent, DamageImmune, DamageImmunities, DamageType, HealthSystem, HealthSystemPlugin,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app
+///     .world
+///     .spawn((
+///         HealthSystem::new(100.0),
+///         DamageImmunities::default().with(DamageType::FIRE),
+///     ))
+///     .id();
+/// app.world.send_event(DamageEvent::new(entity, 20.0).with_type(DamageType::FIRE));
+/// app.world.send_event(DamageEvent::new(entity, 20.0));
+/// app.update();
+///
+/// assert_eq!(80.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// let events = app.world.resource::<Events<DamageImmune>>();
+/// assert_eq!(1, events.iter_current_update_events().count());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct DamageImmunities(pub HashSet<DamageType>);
+
+impl DamageImmunities {
+    /// Add an immunity to a damage type.
+    pub fn with(mut self, damage_type: DamageType) -> Self {
+        self.0.insert(damage_type);
+        self
+    }
+
+    /// Returns true if this entity is immune to the damage type.
+    pub fn is_immune(&self, damage_type: DamageType) -> bool {
+        self.0.contains(&damage_type)
+    }
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when a [`DamageEvent`](crate::DamageEvent) is rejected by [`DamageImmunities`].
+/// # Fields
+/// * `target`: The entity that would have taken the damage.
+/// * `source`: The entity that would have dealt it, if any.
+/// * `damage_type`: The type of the damage.
+#[derive(Debug, Clone, Event)]
+pub struct DamageImmune {
+    pub target: Entity,
+    pub source: Option<Entity>,
+    pub damage_type: DamageType,
+}
+
+pub(crate) fn immunity_stage(context: &mut DamageContext, world: &mut World) {
+    if context.event.force {
+        return;
+    }
+    let damage_type = context.event.damage_type;
+    let immune = world
+        .get::<DamageImmunities>(context.hit)
+        .is_some_and(|immunities| immunities.is_immune(damage_type));
+    if !immune {
+        return;
+    }
+
+    context.cancel();
+    if !context.is_preview() {
+        world.send_event(DamageImmune {
+            target: context.hit,
+            source: context.event.source,
+            damage_type,
+        });
+    }
+}
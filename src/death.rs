@@ -0,0 +1,241 @@
+//! Death of health systems.
+use std::borrow::Cow;
+use std::time::Duration;
+
+use bevy::ecs::event::ManualEventReader;
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+
+use crate::{DamageCause, DamageContribution, HealthSystem, RecentAttackers};
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when a [`HealthSystem`] dies.
+/// Deaths from a [`DamageEvent`](crate::DamageEvent) carry its source and cause,
+/// any other death is detected later in the frame and carries no source, with the cause of
+/// [`HealthSystem::kill_system_with_cause`] if it was killed by it.
+/// # Fields
+/// * `entity`: The entity that died.
+/// * `source`: The entity that dealt the killing blow, if any.
+/// * `cause`: What caused the death, if known.
+/// * `weapon`: The tag of the weapon of the killing blow, if any.
+/// * `contributors`: The attackers recorded by the [`RecentAttackers`] of the entity, largest damage first.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, DeathEvent, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let killer = app.world.spawn_empty().id();
+/// let victim = app.world.spawn(HealthSystem::new(100.0)).id();
+/// app.world.send_event(DamageEvent::new(victim, 150.0).with_source(killer).with_cause("rocket"));
+/// app.update();
+///
+/// let events = app.world.resource::<Events<DeathEvent>>();
+/// let mut deaths = events.iter_current_update_events();
+/// let death = deaths.next().unwrap();
+/// assert_eq!(victim, death.entity);
+/// assert_eq!(Some(killer), death.source);
+/// assert_eq!("rocket", death.cause.as_ref().unwrap().as_str());
+/// assert!(deaths.next().is_none());
+/// ```
+#[derive(Debug, Clone, Event)]
+pub struct DeathEvent {
+    pub entity: Entity,
+    pub source: Option<Entity>,
+    pub cause: Option<DamageCause>,
+    pub weapon: Option<Cow<'static, str>>,
+    pub contributors: Vec<DamageContribution>,
+}
+
+pub(crate) fn detect_deaths(
+    mut reader: Local<ManualEventReader<DeathEvent>>,
+    mut dead: Local<HashSet<Entity>>,
+    mut deaths: ResMut<Events<DeathEvent>>,
+    time: Res<Time>,
+    changed: Query<(Entity, &HealthSystem, Option<&RecentAttackers>), Changed<HealthSystem>>,
+    mut removed: RemovedComponents<HealthSystem>,
+) {
+    for entity in removed.iter() {
+        dead.remove(&entity);
+    }
+
+    for event in reader.iter(&deaths) {
+        dead.insert(event.entity);
+    }
+
+    let mut detected = Vec::new();
+    for (entity, health_system, attackers) in &changed {
+        if !health_system.is_dead() {
+            dead.remove(&entity);
+        } else if dead.insert(entity) {
+            detected.push(DeathEvent {
+                entity,
+                source: None,
+                cause: health_system.get_death_cause().cloned(),
+                weapon: None,
+                contributors: attackers
+                    .map(|attackers| attackers.contributions(time.elapsed()))
+                    .unwrap_or_default(),
+            });
+        }
+    }
+
+    for event in detected {
+        deaths.send(event);
+    }
+    // Skip the events sent above.
+    reader.iter(&deaths).for_each(drop);
+}
+
+/// What happens to an entity once its [`HealthSystem`] dies.
+/// Entities without this component behave as [`DeathBehavior::MarkOnly`].
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, DeathBehavior, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app.world.spawn((HealthSystem::new(100.0), DeathBehavior::Despawn)).id();
+/// app.world.send_event(DamageEvent::new(entity, 100.0));
+/// app.update();
+///
+/// assert!(app.world.get_entity(entity).is_none());
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub enum DeathBehavior {
+    /// Despawn the entity.
+    Despawn,
+    /// Despawn the entity and all of its children.
+    DespawnRecursive,
+    /// Mark the entity as [`Dead`] and a [`Corpse`], then despawn it and all of its children after the duration.
+    /// Reviving the entity before that cancels the despawn.
+    DespawnAfter(Duration),
+    /// __Default__. Only mark the entity as [`Dead`].
+    #[default]
+    MarkOnly,
+}
+
+/// Marker inserted by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) on dead entities that are not despawned.
+/// It is removed when the entity is revived.
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_health_system::Dead;
+///
+/// fn play_dead_animation(query: Query<Entity, Added<Dead>>) {
+///     todo!();
+/// }
+/// ```
+#[derive(Debug, Default, Copy, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Dead;
+
+/// Inserted by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) on entities dying with [`DeathBehavior::DespawnAfter`].
+/// The entity is despawned with its children once [`Time::elapsed`] reaches `despawn_at`, sending [`CorpseRemoved`].
+/// # Fields
+/// * `despawn_at`: When the corpse is despawned, as [`Time::elapsed`].
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{Corpse, CorpseRemoved, DamageEvent, DeathBehavior, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+///
+/// let entity = app
+///     .world
+///     .spawn((
+///         HealthSystem::new(100.0),
+///         DeathBehavior::DespawnAfter(Duration::from_secs(2)),
+///     ))
+///     .id();
+/// app.world.send_event(DamageEvent::new(entity, 100.0));
+/// app.update();
+/// assert!(app.world.get::<Corpse>(entity).is_some());
+///
+/// for _ in 0..3 {
+///     app.update();
+/// }
+/// assert!(app.world.get_entity(entity).is_none());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Corpse {
+    pub despawn_at: Duration,
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when a [`Corpse`] is despawned.
+/// # Fields
+/// * `entity`: The despawned entity.
+#[derive(Debug, Clone, Event)]
+pub struct CorpseRemoved {
+    pub entity: Entity,
+}
+
+pub(crate) fn apply_death_behavior(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut deaths: EventReader<DeathEvent>,
+    query: Query<(Option<&HealthSystem>, Option<&DeathBehavior>)>,
+) {
+    for event in deaths.iter() {
+        let Ok((health_system, behavior)) = query.get(event.entity) else {
+            continue;
+        };
+        // Members of a shared health pool may not have a health system of their own.
+        if health_system.is_some_and(|health_system| !health_system.is_dead()) {
+            continue;
+        }
+
+        let mut entity = commands.entity(event.entity);
+        match behavior.copied().unwrap_or_default() {
+            DeathBehavior::Despawn => entity.despawn(),
+            DeathBehavior::DespawnRecursive => entity.despawn_recursive(),
+            DeathBehavior::DespawnAfter(duration) => {
+                entity.insert((
+                    Dead,
+                    Corpse {
+                        despawn_at: time.elapsed() + duration,
+                    },
+                ));
+            }
+            DeathBehavior::MarkOnly => {
+                entity.insert(Dead);
+            }
+        }
+    }
+}
+
+pub(crate) fn remove_corpses(
+    mut commands: Commands,
+    time: Res<Time>,
+    query: Query<(Entity, Option<&HealthSystem>, &Corpse)>,
+    mut removed: EventWriter<CorpseRemoved>,
+) {
+    for (entity, health_system, corpse) in &query {
+        if health_system.is_some_and(|health_system| !health_system.is_dead()) {
+            commands.entity(entity).remove::<Corpse>();
+        } else if time.elapsed() >= corpse.despawn_at {
+            commands.entity(entity).despawn_recursive();
+            removed.send(CorpseRemoved { entity });
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn remove_dead_marker(
+    mut commands: Commands,
+    query: Query<(Entity, &HealthSystem), (With<Dead>, Changed<HealthSystem>)>,
+) {
+    for (entity, health_system) in &query {
+        if !health_system.is_dead() {
+            commands.entity(entity).remove::<Dead>();
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! Gizmo overlay drawing the health of every entity, for development builds.
+use bevy::prelude::*;
+
+use crate::{HealthSystem, HealthSystemModifier, Invulnerability};
+
+/// Add this plugin to draw the health of every entity with a [`HealthSystem`] with gizmos, see [`HealthDebugOverlay`].
+/// It is not part of the [`HealthSystemPlugin`](crate::HealthSystemPlugin), add both. Requires the `GizmoPlugin`.
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_health_system::{HealthDebugPlugin, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((DefaultPlugins, HealthSystemPlugin));
+/// #[cfg(debug_assertions)]
+/// app.add_plugins(HealthDebugPlugin);
+/// app.run();
+/// ```
+#[derive(Debug, Default)]
+pub struct HealthDebugPlugin;
+
+impl Plugin for HealthDebugPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HealthDebugOverlay>().add_systems(
+            PostUpdate,
+            (
+                toggle_health_debug_overlay,
+                draw_health_debug_overlay
+                    .run_if(|overlay: Res<HealthDebugOverlay>| overlay.enabled),
+            )
+                .chain(),
+        );
+    }
+}
+
+/// Settings of the overlay drawn by the [`HealthDebugPlugin`]: a bar above each entity, colored by its state.
+/// Dead entities are crossed out. Bars face the first 3D camera, if any.
+/// Bevy 0.11 gizmos do not draw text, the health is shown by how much of the bar is filled.
+/// # Fields
+/// * `enabled`: Whether the overlay is drawn.
+/// * `toggle_key`: The key toggling the overlay, if any.
+/// * `offset`: The position of the bars relative to the entities.
+/// * `size`: The size of the bars, in world units.
+/// * `alive`: The color of the bars of alive entities.
+/// * `invincible`: The color of the bars of invincible or [`Invulnerability`] protected entities.
+/// * `dead`: The color of the bars of dead entities.
+#[derive(Debug, Clone, Resource)]
+pub struct HealthDebugOverlay {
+    pub enabled: bool,
+    pub toggle_key: Option<KeyCode>,
+    pub offset: Vec3,
+    pub size: Vec2,
+    pub alive: Color,
+    pub invincible: Color,
+    pub dead: Color,
+}
+
+impl Default for HealthDebugOverlay {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            toggle_key: Some(KeyCode::F3),
+            offset: Vec3::Y * 2.0,
+            size: Vec2::new(1.0, 0.15),
+            alive: Color::GREEN,
+            invincible: Color::YELLOW,
+            dead: Color::RED,
+        }
+    }
+}
+
+fn toggle_health_debug_overlay(
+    keys: Option<Res<Input<KeyCode>>>,
+    mut overlay: ResMut<HealthDebugOverlay>,
+) {
+    let (Some(keys), Some(key)) = (keys, overlay.toggle_key) else {
+        return;
+    };
+    if keys.just_pressed(key) {
+        overlay.enabled = !overlay.enabled;
+    }
+}
+
+fn draw_health_debug_overlay(
+    mut gizmos: Gizmos,
+    overlay: Res<HealthDebugOverlay>,
+    cameras: Query<&GlobalTransform, With<Camera3d>>,
+    query: Query<(&HealthSystem, &GlobalTransform, Option<&Invulnerability>)>,
+) {
+    let rotation = cameras
+        .iter()
+        .next()
+        .map_or(Quat::IDENTITY, |camera| camera.compute_transform().rotation);
+    let (right, up) = (rotation * Vec3::X, rotation * Vec3::Y);
+    let half = overlay.size / 2.0;
+
+    for (health_system, transform, invulnerability) in &query {
+        let center = transform.translation() + overlay.offset;
+        let invincible = health_system.has_modifier(HealthSystemModifier::INVINCIBLE)
+            || invulnerability.is_some_and(Invulnerability::is_active);
+        let color = if health_system.is_dead() {
+            overlay.dead
+        } else if invincible {
+            overlay.invincible
+        } else {
+            overlay.alive
+        };
+
+        gizmos.rect(center, rotation, overlay.size, color);
+        if health_system.is_dead() {
+            let (corner, other) = (right * half.x + up * half.y, right * half.x - up * half.y);
+            gizmos.line(center - corner, center + corner, color);
+            gizmos.line(center - other, center + other, color);
+            continue;
+        }
+
+        // Fill the health with horizontal lines, gizmos only draw lines.
+        let fraction = health_system.get_health_normalized().clamp(0.0, 1.0);
+        let left = center - right * half.x;
+        const LINES: usize = 5;
+        for line in 0..LINES {
+            let y = -half.y + overlay.size.y * (line as f32 + 0.5) / LINES as f32;
+            let start = left + up * y;
+            gizmos.line(start, start + right * overlay.size.x * fraction, color);
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Health draining over time.
+use bevy::prelude::*;
+
+use crate::{CombatRule, DamageCause, DeathEvent, HealthSystem, InCombat, RecentAttackers};
+
+/// Drain the health of an entity over time, eg: for "health is time" games.
+/// The drain follows the [`Time`] of the app, pausing it pauses the decay.
+/// Entities killed by the decay send a [`DeathEvent`] caused by [`DamageCause::DECAY`].
+/// # Fields
+/// * `rate_per_second`: Health drained every second.
+/// * `floor`: The lowest health the decay can leave, `0.0` lets it kill.
+/// * `combat`: Whether the decay runs in or out of combat, see [`InCombat`].
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{HealthDecay, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+///
+/// let entity = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), HealthDecay::new(30.0).with_floor(20.0)))
+///     .id();
+///
+/// for _ in 0..5 {
+///     app.update();
+/// }
+/// assert_eq!(20.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct HealthDecay {
+    pub rate_per_second: f32,
+    pub floor: f32,
+    pub combat: CombatRule,
+}
+
+impl HealthDecay {
+    /// Create a new decay able to kill.
+    /// # Arguments
+    /// * `rate_per_second`: Health drained every second.
+    pub fn new(rate_per_second: f32) -> Self {
+        Self {
+            rate_per_second,
+            floor: 0.0,
+            combat: CombatRule::default(),
+        }
+    }
+
+    /// Set the lowest health the decay can leave.
+    pub fn with_floor(mut self, floor: f32) -> Self {
+        self.floor = floor;
+        self
+    }
+
+    /// Set whether the decay runs in or out of combat.
+    pub fn with_combat(mut self, combat: CombatRule) -> Self {
+        self.combat = combat;
+        self
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn decay_health(
+    time: Res<Time>,
+    mut query: Query<(
+        Entity,
+        &HealthDecay,
+        &mut HealthSystem,
+        Option<&InCombat>,
+        Option<&RecentAttackers>,
+    )>,
+    mut deaths: EventWriter<DeathEvent>,
+) {
+    if time.is_paused() {
+        return;
+    }
+
+    for (entity, decay, mut health_system, in_combat, attackers) in &mut query {
+        if !health_system.is_alive()
+            || health_system.get_health() <= decay.floor
+            || !decay.combat.allows(in_combat.is_some())
+        {
+            continue;
+        }
+
+        let amount = decay.rate_per_second * time.delta_seconds();
+        health_system.deal_damage_non_lethal(amount, decay.floor, false);
+        if health_system.is_dead() {
+            deaths.send(DeathEvent {
+                entity,
+                source: None,
+                cause: Some(DamageCause::DECAY),
+                weapon: None,
+                contributors: attackers
+                    .map(|attackers| attackers.contributions(time.elapsed()))
+                    .unwrap_or_default(),
+            });
+        }
+    }
+}
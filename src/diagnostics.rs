@@ -0,0 +1,106 @@
+//! Health diagnostics, shown by the `LogDiagnosticsPlugin` and perf overlays.
+use bevy::diagnostic::{Diagnostic, DiagnosticId, Diagnostics, RegisterDiagnostic};
+use bevy::prelude::*;
+
+use crate::{DamageEvent, HealthSystem};
+
+/// Add this plugin to measure the health of your entities through Bevy diagnostics:
+/// the number of entities with a [`HealthSystem`], how many are dead, their average fraction of max health
+/// and the number of [`DamageEvent`]s per second.
+/// It is not part of the [`HealthSystemPlugin`](crate::HealthSystemPlugin), add both.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::diagnostic::DiagnosticsStore;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, HealthDiagnosticsPlugin, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin, HealthDiagnosticsPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_millis(500)));
+///
+/// let entity = app.world.spawn(HealthSystem::new(100.0)).id();
+/// app.world.spawn(HealthSystem::new(100.0));
+/// app.update();
+/// app.world.send_event(DamageEvent::new(entity, 200.0));
+/// app.update();
+///
+/// let diagnostics = app.world.resource::<DiagnosticsStore>();
+/// let value = |id| diagnostics.get(id).unwrap().value().unwrap();
+/// assert_eq!(2.0, value(HealthDiagnosticsPlugin::ENTITIES_WITH_HEALTH));
+/// assert_eq!(1.0, value(HealthDiagnosticsPlugin::DEAD_ENTITIES));
+/// assert_eq!(0.5, value(HealthDiagnosticsPlugin::AVERAGE_HEALTH_FRACTION));
+/// assert_eq!(2.0, value(HealthDiagnosticsPlugin::DAMAGE_EVENTS_PER_SECOND));
+/// ```
+#[derive(Debug, Default)]
+pub struct HealthDiagnosticsPlugin;
+
+impl Plugin for HealthDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(
+            Self::ENTITIES_WITH_HEALTH,
+            "entities_with_health",
+            20,
+        ))
+        .register_diagnostic(Diagnostic::new(Self::DEAD_ENTITIES, "dead_entities", 20))
+        .register_diagnostic(Diagnostic::new(
+            Self::AVERAGE_HEALTH_FRACTION,
+            "average_health_fraction",
+            20,
+        ))
+        .register_diagnostic(
+            Diagnostic::new(
+                Self::DAMAGE_EVENTS_PER_SECOND,
+                "damage_events_per_second",
+                20,
+            )
+            .with_suffix("/s"),
+        )
+        .add_systems(PostUpdate, Self::diagnostic_system);
+    }
+}
+
+impl HealthDiagnosticsPlugin {
+    /// The number of entities with a [`HealthSystem`].
+    pub const ENTITIES_WITH_HEALTH: DiagnosticId =
+        DiagnosticId::from_u128(234426618360033408517604555481577005283);
+    /// The number of dead entities.
+    pub const DEAD_ENTITIES: DiagnosticId =
+        DiagnosticId::from_u128(156182219593528372313810125412206197002);
+    /// The average health of the entities, as a fraction of max health.
+    pub const AVERAGE_HEALTH_FRACTION: DiagnosticId =
+        DiagnosticId::from_u128(302552530031893866801205284225896425595);
+    /// The number of [`DamageEvent`]s sent per second.
+    pub const DAMAGE_EVENTS_PER_SECOND: DiagnosticId =
+        DiagnosticId::from_u128(13353142012992020511308964067625675467);
+
+    fn diagnostic_system(
+        mut diagnostics: Diagnostics,
+        time: Res<Time>,
+        mut damage_events: EventReader<DamageEvent>,
+        query: Query<&HealthSystem>,
+    ) {
+        let count = query.iter().len();
+        diagnostics.add_measurement(Self::ENTITIES_WITH_HEALTH, || count as f64);
+        diagnostics.add_measurement(Self::DEAD_ENTITIES, || {
+            query.iter().filter(|health| health.is_dead()).count() as f64
+        });
+        if count > 0 {
+            diagnostics.add_measurement(Self::AVERAGE_HEALTH_FRACTION, || {
+                let total: f32 = query
+                    .iter()
+                    .map(|health| health.get_health_normalized().clamp(0.0, 1.0))
+                    .sum();
+                total as f64 / count as f64
+            });
+        }
+
+        let damage_events = damage_events.iter().count();
+        let delta = time.delta_seconds_f64();
+        if delta > 0.0 {
+            diagnostics.add_measurement(Self::DAMAGE_EVENTS_PER_SECOND, || {
+                damage_events as f64 / delta
+            });
+        }
+    }
+}
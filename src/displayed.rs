@@ -0,0 +1,230 @@
+//! Health values for HUDs, animated behind the actual health.
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::HealthSystem;
+
+/// The health shown by a HUD, lagging behind the actual health after damage, eg: the white chunk of fighting game bars.
+/// Kept up to date by the [`HealthSystemPlugin`](crate::HealthSystemPlugin): after the health drops, the displayed health
+/// holds for `delay` then drains toward the actual health. Heals are shown at once.
+/// Draw the actual health over the displayed health to show the health recently lost.
+/// # Fields
+/// * `delay`: How long the displayed health holds after the last damage.
+/// * `drain_rate`: How fast the displayed health drains once the delay passed, in fractions of max health per second.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, DisplayedHealth, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+///
+/// let entity = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), DisplayedHealth::new(Duration::from_secs(1), 0.25)))
+///     .id();
+/// app.update();
+///
+/// app.world.send_event(DamageEvent::new(entity, 50.0));
+/// app.update();
+/// let displayed = app.world.get::<DisplayedHealth>(entity).unwrap();
+/// assert_eq!((100.0, 50.0), (displayed.health(), displayed.recently_lost()));
+///
+/// app.update();
+/// assert_eq!(75.0, app.world.get::<DisplayedHealth>(entity).unwrap().health());
+/// app.update();
+/// assert_eq!(50.0, app.world.get::<DisplayedHealth>(entity).unwrap().health());
+/// ```
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct DisplayedHealth {
+    pub delay: Duration,
+    pub drain_rate: f32,
+    health: Option<f32>,
+    actual: f32,
+    max: f32,
+    since_damage: Duration,
+}
+
+impl DisplayedHealth {
+    /// Create a displayed health, starting at the actual health.
+    /// # Arguments
+    /// * `delay`: How long the displayed health holds after the last damage.
+    /// * `drain_rate`: How fast the displayed health drains once the delay passed, in fractions of max health per second.
+    pub fn new(delay: Duration, drain_rate: f32) -> Self {
+        Self {
+            delay,
+            drain_rate,
+            health: None,
+            actual: 0.0,
+            max: 0.0,
+            since_damage: Duration::ZERO,
+        }
+    }
+
+    /// The health to display.
+    pub fn health(&self) -> f32 {
+        self.health.unwrap_or(self.actual)
+    }
+
+    /// The health to display, as a fraction of max health.
+    pub fn health_normalized(&self) -> f32 {
+        if self.max > 0.0 {
+            self.health() / self.max
+        } else {
+            0.0
+        }
+    }
+
+    /// The health lost and not drained yet.
+    pub fn recently_lost(&self) -> f32 {
+        (self.health() - self.actual).max(0.0)
+    }
+}
+
+impl Default for DisplayedHealth {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(500), 0.5)
+    }
+}
+
+pub(crate) fn update_displayed_health(
+    time: Res<Time>,
+    mut query: Query<(&HealthSystem, &mut DisplayedHealth)>,
+) {
+    for (health_system, mut displayed) in &mut query {
+        let actual = health_system.get_health().max(0.0);
+        let max = health_system.get_health_max();
+        let Some(health) = displayed.health else {
+            displayed.health = Some(actual);
+            displayed.actual = actual;
+            displayed.max = max;
+            continue;
+        };
+
+        let since_damage = if actual < displayed.actual {
+            Duration::ZERO
+        } else if health > actual {
+            displayed.since_damage + time.delta()
+        } else {
+            displayed.since_damage
+        };
+        let health = if actual >= health {
+            actual
+        } else if since_damage >= displayed.delay {
+            (health - displayed.drain_rate * max * time.delta_seconds()).max(actual)
+        } else {
+            health
+        };
+
+        if displayed.health != Some(health)
+            || displayed.actual != actual
+            || displayed.max != max
+            || displayed.since_damage != since_damage
+        {
+            displayed.health = Some(health);
+            displayed.actual = actual;
+            displayed.max = max;
+            displayed.since_damage = since_damage;
+        }
+    }
+}
+
+/// The health shown by a HUD, smoothly following the actual health, kept up to date by the [`HealthSystemPlugin`](crate::HealthSystemPlugin).
+/// Each frame the smoothed health moves toward the actual health by a share of the distance left,
+/// it closes `1 - e^-smoothing` of the distance each second.
+/// # Fields
+/// * `smoothing`: How fast the smoothed health follows the actual health, higher is faster.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, HealthSystem, HealthSystemPlugin, SmoothedHealth};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+///
+/// // Halve the distance left every second.
+/// let entity = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), SmoothedHealth::new(2f32.ln())))
+///     .id();
+/// app.update();
+///
+/// app.world.send_event(DamageEvent::new(entity, 50.0));
+/// app.update();
+/// let smoothed = app.world.get::<SmoothedHealth>(entity).unwrap();
+/// assert!((smoothed.health() - 75.0).abs() < 0.01);
+/// ```
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct SmoothedHealth {
+    pub smoothing: f32,
+    health: Option<f32>,
+    max: f32,
+}
+
+impl SmoothedHealth {
+    /// Create a smoothed health, starting at the actual health.
+    /// # Arguments
+    /// * `smoothing`: How fast the smoothed health follows the actual health, higher is faster.
+    pub fn new(smoothing: f32) -> Self {
+        Self {
+            smoothing,
+            health: None,
+            max: 0.0,
+        }
+    }
+
+    /// The health to display.
+    pub fn health(&self) -> f32 {
+        self.health.unwrap_or_default()
+    }
+
+    /// The health to display, as a fraction of max health.
+    pub fn health_normalized(&self) -> f32 {
+        if self.max > 0.0 {
+            self.health() / self.max
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Default for SmoothedHealth {
+    fn default() -> Self {
+        Self::new(10.0)
+    }
+}
+
+pub(crate) fn update_smoothed_health(
+    time: Res<Time>,
+    mut query: Query<(&HealthSystem, &mut SmoothedHealth)>,
+) {
+    for (health_system, mut smoothed) in &mut query {
+        let actual = health_system.get_health().max(0.0);
+        let max = health_system.get_health_max();
+        let health = match smoothed.health {
+            Some(health) => {
+                let t = 1.0 - (-smoothed.smoothing.max(0.0) * time.delta_seconds()).exp();
+                let health = health + (actual - health) * t;
+                // Settle once the difference can not be seen.
+                if (actual - health).abs() <= max * 0.001 {
+                    actual
+                } else {
+                    health
+                }
+            }
+            None => actual,
+        };
+
+        if smoothed.health != Some(health) || smoothed.max != max {
+            smoothed.health = Some(health);
+            smoothed.max = max;
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Downed state, also known as death's door, bleeding out before dying.
+use std::time::Duration;
+
+use bevy::ecs::entity::{EntityMapper, MapEntities};
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::prelude::*;
+
+use crate::{DeathEvent, HealthSystem, RecentAttackers};
+
+/// Let an entity go down instead of dying when a [`DamageEvent`](crate::DamageEvent) would kill it.
+/// A downed entity ignores non forced damage and dies once it bled out, unless it was revived
+/// with a [`ReviveEvent`](crate::ReviveEvent) first. Forced damage finishes it immediately.
+/// # Fields
+/// * `bleed_out`: How long a downed entity waits before dying.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageEvent, Downable, HealthSystem, HealthSystemPlugin, HealthSystemReviveHealType,
+///     ReviveEvent,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let medic = app.world.spawn_empty().id();
+/// let entity = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), Downable::new(Duration::from_secs(10))))
+///     .id();
+///
+/// app.world.send_event(DamageEvent::new(entity, 150.0));
+/// app.update();
+/// assert!(app.world.get::<HealthSystem>(entity).unwrap().is_downed());
+///
+/// app.world.send_event(
+///     ReviveEvent::new(entity, HealthSystemReviveHealType::HealTo(25.0)).with_reviver(medic),
+/// );
+/// app.update();
+/// assert!(app.world.get::<HealthSystem>(entity).unwrap().is_alive());
+/// assert_eq!(25.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Downable {
+    pub bleed_out: Duration,
+}
+
+impl Downable {
+    /// Let an entity go down instead of dying.
+    /// # Arguments
+    /// * `bleed_out`: How long a downed entity waits before dying.
+    pub fn new(bleed_out: Duration) -> Self {
+        Self { bleed_out }
+    }
+}
+
+/// Inserted by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) on downed entities, removed once they are revived or dead.
+/// # Fields
+/// * `bleed_out`: The time left before the entity dies.
+/// * `source`: The entity that downed this entity, credited for the kill once it bleeds out.
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct Downed {
+    pub bleed_out: Timer,
+    pub source: Option<Entity>,
+}
+
+/// Keep the source pointing to the same entity when spawned from a scene.
+impl MapEntities for Downed {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        if let Some(source) = &mut self.source {
+            *source = entity_mapper.get_or_reserve(*source);
+        }
+    }
+}
+
+impl Downed {
+    pub(crate) fn new(bleed_out: Duration, source: Option<Entity>) -> Self {
+        Self {
+            bleed_out: Timer::new(bleed_out, TimerMode::Once),
+            source,
+        }
+    }
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when an entity goes down.
+/// # Fields
+/// * `entity`: The downed entity.
+/// * `source`: The entity that downed it, if any.
+#[derive(Debug, Clone, Event)]
+pub struct DownedEvent {
+    pub entity: Entity,
+    pub source: Option<Entity>,
+}
+
+pub(crate) fn bleed_out(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(
+        Entity,
+        &mut HealthSystem,
+        &mut Downed,
+        Option<&RecentAttackers>,
+    )>,
+    mut deaths: EventWriter<DeathEvent>,
+) {
+    for (entity, mut health_system, mut downed, attackers) in &mut query {
+        if !health_system.is_downed() {
+            commands.entity(entity).remove::<Downed>();
+            continue;
+        }
+
+        if downed.bleed_out.tick(time.delta()).finished() {
+            health_system.kill_system(true);
+            commands.entity(entity).remove::<Downed>();
+            deaths.send(DeathEvent {
+                entity,
+                source: downed.source,
+                cause: None,
+                weapon: None,
+                contributors: attackers
+                    .map(|attackers| attackers.contributions(time.elapsed()))
+                    .unwrap_or_default(),
+            });
+        }
+    }
+}
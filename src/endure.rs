@@ -0,0 +1,39 @@
+//! Surviving a single lethal hit, also known as last stand.
+use bevy::prelude::*;
+
+/// The next hit that would kill, or down, this entity leaves it at `1.0` health instead.
+/// The component is removed once it saved the entity, and [`EnduredLethalHit`] is sent.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, Endure, EnduredLethalHit, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app.world.spawn((HealthSystem::new(100.0), Endure)).id();
+/// app.world.send_event(DamageEvent::new(entity, 150.0));
+/// app.update();
+///
+/// assert_eq!(1.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// assert!(app.world.get::<Endure>(entity).is_none());
+/// let events = app.world.resource::<Events<EnduredLethalHit>>();
+/// assert_eq!(entity, events.iter_current_update_events().next().unwrap().entity);
+///
+/// app.world.send_event(DamageEvent::new(entity, 150.0));
+/// app.update();
+/// assert!(app.world.get::<HealthSystem>(entity).unwrap().is_dead());
+/// ```
+#[derive(Debug, Default, Copy, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Endure;
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when [`Endure`] saved an entity from a lethal hit.
+/// # Fields
+/// * `entity`: The entity that survived.
+/// * `source`: The entity that dealt the hit, if any.
+#[derive(Debug, Clone, Event)]
+pub struct EnduredLethalHit {
+    pub entity: Entity,
+    pub source: Option<Entity>,
+}
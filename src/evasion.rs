@@ -0,0 +1,72 @@
+//! Dodging incoming damage.
+use bevy::prelude::*;
+
+use crate::{DamageContext, HealthRng};
+
+/// Give an entity a chance to dodge a [`DamageEvent`](crate::DamageEvent), rolled with the [`HealthRng`](crate::HealthRng)
+/// before any mitigation. A dodged hit deals no damage and sends [`DamageDodged`]. Forced damage can't be dodged.
+/// # Fields
+/// * `chance`: The chance to dodge, from `0.0` to `1.0`.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageDodged, DamageEvent, Evasion, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app.world.spawn((HealthSystem::new(100.0), Evasion::new(1.0))).id();
+/// app.world.send_event(DamageEvent::new(entity, 10.0));
+/// app.update();
+///
+/// assert_eq!(100.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// let events = app.world.resource::<Events<DamageDodged>>();
+/// assert_eq!(entity, events.iter_current_update_events().next().unwrap().target);
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Evasion {
+    pub chance: f32,
+}
+
+impl Evasion {
+    /// Give an entity a chance to dodge.
+    /// # Arguments
+    /// * `chance`: The chance to dodge, from `0.0` to `1.0`.
+    pub fn new(chance: f32) -> Self {
+        Self { chance }
+    }
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when an entity dodged a [`DamageEvent`](crate::DamageEvent).
+/// # Fields
+/// * `target`: The entity that dodged.
+/// * `source`: The entity that dealt the damage, if any.
+/// * `amount`: The amount of damage dodged.
+#[derive(Debug, Clone, Event)]
+pub struct DamageDodged {
+    pub target: Entity,
+    pub source: Option<Entity>,
+    pub amount: f32,
+}
+
+pub(crate) fn evasion_stage(context: &mut DamageContext, world: &mut World) {
+    if context.event.force || context.is_preview() {
+        return;
+    }
+    let Some(chance) = world
+        .get::<Evasion>(context.target)
+        .map(|evasion| evasion.chance)
+    else {
+        return;
+    };
+
+    if world.resource_mut::<HealthRng>().chance(chance) {
+        world.send_event(DamageDodged {
+            target: context.hit,
+            source: context.event.source,
+            amount: context.amount,
+        });
+        context.cancel();
+    }
+}
@@ -0,0 +1,76 @@
+//! Executions, killing targets left with little health.
+use bevy::prelude::*;
+
+use crate::{DamageContext, Fraction, HealthSystem};
+
+/// Kill the target of a hit instead if the hit would leave its health below a fraction of its max health.
+/// Put it on an attacker for all the damage it deals, or on a single [`DamageEvent`](crate::DamageEvent)
+/// with [`DamageEvent::with_execute`](crate::DamageEvent::with_execute), which takes precedence.
+/// Non-lethal damage never executes, and a [`DamageCap`](crate::DamageCap) on the target limits executions too. The [`DamageAppliedEvent::was_execute`](crate::DamageAppliedEvent::was_execute)
+/// of executions is set.
+/// # Fields
+/// * `fraction`: The fraction of max health below which the target is executed, from `0.0` to `1.0`.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageAppliedEvent, DamageEvent, ExecuteThreshold, Fraction, HealthSystem,
+///     HealthSystemPlugin,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let attacker = app.world.spawn(ExecuteThreshold::new(Fraction::new(0.2))).id();
+/// let entity = app.world.spawn(HealthSystem::new(100.0)).id();
+///
+/// app.world.send_event(DamageEvent::new(entity, 70.0).with_source(attacker));
+/// app.update();
+/// assert_eq!(30.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+///
+/// app.world.send_event(DamageEvent::new(entity, 15.0).with_source(attacker));
+/// app.update();
+/// assert!(app.world.get::<HealthSystem>(entity).unwrap().is_dead());
+/// let events = app.world.resource::<Events<DamageAppliedEvent>>();
+/// assert!(events.iter_current_update_events().next().unwrap().was_execute);
+/// ```
+#[derive(Debug, Default, PartialEq, Copy, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct ExecuteThreshold {
+    pub fraction: f32,
+}
+
+impl ExecuteThreshold {
+    /// Execute targets left below a fraction of their max health.
+    /// # Arguments
+    /// * `fraction`: The fraction of max health below which the target is executed, from `0.0` to `1.0`.
+    pub fn new(fraction: Fraction) -> Self {
+        Self {
+            fraction: fraction.get(),
+        }
+    }
+}
+
+pub(crate) fn execute_stage(context: &mut DamageContext, world: &mut World) {
+    if context.event.health_floor.is_some() || context.amount <= 0.0 {
+        return;
+    }
+    let Some(threshold) = context.event.execute.or_else(|| {
+        context
+            .event
+            .source
+            .and_then(|source| world.get::<ExecuteThreshold>(source))
+            .copied()
+    }) else {
+        return;
+    };
+    let Some(health_system) = world.get::<HealthSystem>(context.target) else {
+        return;
+    };
+
+    let health_after = health_system.get_health() - context.amount;
+    if health_after > 0.0 && health_after < threshold.fraction * health_system.get_health_max() {
+        context.amount = health_system.get_health();
+        context.was_execute = true;
+    }
+}
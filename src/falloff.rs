@@ -0,0 +1,53 @@
+//! Attenuation of an effect over distance, eg: explosions, shotguns, auras,...
+use bevy::math::cubic_splines::CubicCurve;
+
+/// How an effect, eg: [`HealthWriter::apply_damage_in_radius`](crate::HealthWriter::apply_damage_in_radius),
+/// weakens with the distance from its center. Every falloff has no effect outside of the radius.
+/// # Examples
+/// ```
+/// use bevy::math::cubic_splines::{Bezier, CubicGenerator};
+/// use bevy_health_system::Falloff;
+///
+/// assert_eq!(1.0, Falloff::None.multiplier(5.0, 10.0));
+/// assert_eq!(0.5, Falloff::Linear.multiplier(5.0, 10.0));
+/// assert_eq!(0.2, Falloff::InverseSquare.multiplier(2.0, 10.0));
+/// assert_eq!(0.0, Falloff::Linear.multiplier(15.0, 10.0));
+///
+/// let curve = Bezier::new([[1.0, 1.0, 0.0, 0.0]]).to_curve();
+/// assert_eq!(0.5, Falloff::Curve(curve).multiplier(5.0, 10.0));
+/// ```
+#[derive(Debug, Default, PartialEq, Clone)]
+pub enum Falloff {
+    /// __Default__. The full effect anywhere in the radius.
+    #[default]
+    None,
+    /// The full effect at the center, down to nothing at the edge of the radius.
+    Linear,
+    /// The inverse square of the distance, offset by one so the center has the full effect.
+    InverseSquare,
+    /// A curve sampled over its whole length, from the center to the edge of the radius.
+    /// Its positions are the multipliers, clamped from `0.0` to `1.0`.
+    Curve(CubicCurve<f32>),
+}
+
+impl Falloff {
+    /// Returns the multiplier of the effect, from `0.0` to `1.0`.
+    /// # Arguments
+    /// * `distance`: The distance from the center.
+    /// * `radius`: The radius of the effect.
+    pub fn multiplier(&self, distance: f32, radius: f32) -> f32 {
+        if distance > radius {
+            return 0.0;
+        }
+
+        match self {
+            Falloff::None => 1.0,
+            Falloff::Linear => 1.0 - distance / radius,
+            Falloff::InverseSquare => 1.0 / (1.0 + distance * distance),
+            Falloff::Curve(curve) => {
+                let t = distance / radius * curve.segments().len() as f32;
+                curve.position(t).clamp(0.0, 1.0)
+            }
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Fixed-point health, for simulations of your own that must not use floats, eg: lockstep multiplayer.
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use bevy::prelude::*;
+use fixed::traits::{FromFixed, ToFixed};
+use fixed::types::I32F32;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::HealthNum;
+
+/// A health value in the `I32F32` fixed-point number of the `fixed` crate, see [`HealthSystemFixed`](crate::HealthSystemFixed).
+/// It wraps the number so it can be reflected, every operation saturates instead of overflowing.
+/// With the `serde` feature, it is saved as the bits of the number so it reads back exactly.
+/// # Example
+/// ```
+/// use bevy_health_system::fixed_point::Fixed;
+/// use bevy_health_system::HealthSystemFixed;
+///
+/// let mut health_system = HealthSystemFixed::new(Fixed::from_num(100));
+/// health_system.deal_damage(Fixed::from_num(0.1), false);
+/// health_system.deal_damage(Fixed::from_num(0.2), false);
+/// assert_eq!(Fixed::from_num(100) - Fixed::from_num(0.3), health_system.get_health());
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Reflect)]
+#[reflect_value(Debug, PartialEq, Hash, Default)]
+pub struct Fixed(pub I32F32);
+
+impl Fixed {
+    /// Convert any number to fixed-point, values out of range saturate.
+    pub fn from_num(num: impl ToFixed) -> Self {
+        Self(I32F32::saturating_from_num(num))
+    }
+
+    /// Convert to any number, eg: `f32` for health bars.
+    pub fn to_num<N: FromFixed>(self) -> N {
+        self.0.to_num()
+    }
+}
+
+impl From<I32F32> for Fixed {
+    fn from(value: I32F32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Fixed> for I32F32 {
+    fn from(value: Fixed) -> Self {
+        value.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Fixed {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.to_bits().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Fixed {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        i64::deserialize(deserializer).map(|bits| Self(I32F32::from_bits(bits)))
+    }
+}
+
+impl fmt::Display for Fixed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl std::ops::Add for Fixed {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl std::ops::Sub for Fixed {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl HealthNum for Fixed {
+    const ZERO: Self = Self(I32F32::ZERO);
+
+    /// NaN converts to zero, values out of range saturate.
+    fn from_f32(value: f32) -> Self {
+        if value.is_nan() {
+            return Self::ZERO;
+        }
+        Self::from_num(value)
+    }
+
+    fn to_f32(self) -> f32 {
+        self.to_num()
+    }
+
+    fn add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+
+    fn sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+
+    fn scale(self, fraction: f32) -> Self {
+        Self(self.0.saturating_mul(Self::from_f32(fraction).0))
+    }
+
+    /// Dividing by zero saturates, zero divided by zero is `0.0`.
+    fn ratio(self, of: Self) -> f32 {
+        let quotient = self
+            .0
+            .checked_div(of.0)
+            .unwrap_or(if self.0 == I32F32::ZERO {
+                I32F32::ZERO
+            } else if (self.0 > I32F32::ZERO) == (of.0 >= I32F32::ZERO) {
+                I32F32::MAX
+            } else {
+                I32F32::MIN
+            });
+        quotient.to_num()
+    }
+
+    fn hash_bits<H: Hasher>(&self, state: &mut H) {
+        self.0.to_bits().hash(state);
+    }
+}
@@ -0,0 +1,80 @@
+//! Fractions of max health, so `0.0` to `1.0` values are never mistaken for `0` to `100` percentages.
+use std::fmt;
+
+use bevy::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A fraction of max health, `0.0` being none of it and `1.0` all of it.
+/// Every API taking a fraction takes a [`Fraction`], built with [`Fraction::new`] or [`Fraction::from_percent`].
+/// The value is kept as is, above `1.0` or below `0.0` included.
+/// # Examples
+/// ```
+/// use bevy_health_system::{Fraction, HealthSystem, HealthSystemReviveHealType};
+///
+/// assert_eq!(Fraction::new(0.25), Fraction::from_percent(25.0));
+/// assert_eq!(75.0, Fraction::new(0.75).to_percent());
+/// assert_eq!("50%", Fraction::HALF.to_string());
+///
+/// let mut health_system = HealthSystem::new(200.0);
+/// health_system.deal_damage(50.0, false);
+/// assert_eq!(Fraction::new(0.75), health_system.get_health_fraction());
+///
+/// health_system.kill_system(false);
+/// let revive_type = HealthSystemReviveHealType::HealFraction(Fraction::from_percent(25.0));
+/// health_system.revive_system(revive_type, false).unwrap();
+/// assert_eq!(50.0, health_system.get_health());
+/// ```
+#[derive(Debug, Default, PartialEq, PartialOrd, Copy, Clone, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Fraction(f32);
+
+impl Fraction {
+    /// No health.
+    pub const ZERO: Self = Self(0.0);
+    /// Half of max health.
+    pub const HALF: Self = Self(0.5);
+    /// Max health.
+    pub const ONE: Self = Self(1.0);
+
+    /// Create a fraction, eg: `0.25` for a quarter of max health.
+    pub fn new(fraction: f32) -> Self {
+        Self(fraction)
+    }
+
+    /// Create a fraction from a percentage, eg: `25.0` for a quarter of max health.
+    pub fn from_percent(percent: f32) -> Self {
+        Self(percent / 100.0)
+    }
+
+    /// Returns the fraction, from `0.0` for none to `1.0` for all of max health.
+    pub fn get(self) -> f32 {
+        self.0
+    }
+
+    /// Returns the fraction as a percentage, from `0.0` to `100.0`.
+    pub fn to_percent(self) -> f32 {
+        self.0 * 100.0
+    }
+
+    /// Returns the fraction clamped from `0.0` to `1.0`, eg: for the fill of a bar.
+    pub fn clamped(self) -> Self {
+        Self(self.0.clamp(0.0, 1.0))
+    }
+}
+
+impl From<Fraction> for f32 {
+    fn from(fraction: Fraction) -> Self {
+        fraction.0
+    }
+}
+
+/// Show the fraction as a percentage, eg: "75%", the precision is the number of decimals.
+impl fmt::Display for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.precision$}%", self.to_percent()),
+            None => write!(f, "{}%", self.to_percent()),
+        }
+    }
+}
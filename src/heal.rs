@@ -0,0 +1,175 @@
+//! Heal requests and the systems applying them.
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::{
+    CombatLog, CombatLogEntry, CombatLogKind, CombatStats, GlobalDamageSettings, HealingModifier,
+    HealthSystem, IntegerHealth, SharedHealthMember, TemporaryHealth,
+};
+
+/// Request to heal an entity's [`HealthSystem`], or its pool if it is a [`SharedHealthMember`].
+/// Send it with an [`EventWriter`], the [`HealthSystemPlugin`](crate::HealthSystemPlugin) applies it.
+/// Dead or downed systems are not healed, the amount is scaled by the target [`HealingModifier`]
+/// and the [`GlobalDamageSettings`].
+/// # Fields
+/// * `target`: The entity receiving the heal.
+/// * `amount`: The amount of health healed.
+/// * `overheal`: Health overflowing max health is added to the target [`TemporaryHealth`] instead of being lost.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{HealEvent, HealthSystem, HealthSystemPlugin, TemporaryHealth};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let mut health_system = HealthSystem::new(100.0);
+/// health_system.deal_damage(10.0, false);
+/// let entity = app
+///     .world
+///     .spawn((health_system, TemporaryHealth::new(0.0, 0.0)))
+///     .id();
+///
+/// app.world.send_event(HealEvent::new(entity, 30.0).with_overheal());
+/// app.update();
+///
+/// assert_eq!(100.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// assert_eq!(20.0, app.world.get::<TemporaryHealth>(entity).unwrap().amount);
+/// ```
+#[derive(Debug, Clone, Event)]
+pub struct HealEvent {
+    pub target: Entity,
+    pub amount: f32,
+    pub overheal: bool,
+}
+
+impl HealEvent {
+    /// Create a new heal request.
+    /// # Arguments
+    /// * `target`: The entity receiving the heal.
+    /// * `amount`: The amount of health healed.
+    pub fn new(target: Entity, amount: f32) -> Self {
+        Self {
+            target,
+            amount,
+            overheal: false,
+        }
+    }
+
+    /// Turn health overflowing max health into [`TemporaryHealth`].
+    pub fn with_overheal(mut self) -> Self {
+        self.overheal = true;
+        self
+    }
+}
+
+#[allow(clippy::type_complexity)]
+#[derive(SystemParam)]
+pub(crate) struct HealPipeline<'w, 's> {
+    time: Res<'w, Time>,
+    settings: Res<'w, GlobalDamageSettings>,
+    log: ResMut<'w, CombatLog>,
+    members: Query<'w, 's, &'static SharedHealthMember>,
+    targets: Query<
+        'w,
+        's,
+        (
+            &'static mut HealthSystem,
+            Option<&'static mut TemporaryHealth>,
+            Option<&'static HealingModifier>,
+            Option<&'static mut CombatStats>,
+            Option<&'static IntegerHealth>,
+        ),
+    >,
+}
+
+impl HealPipeline<'_, '_> {
+    /// Apply a heal request.
+    pub(crate) fn apply(&mut self, event: &HealEvent) {
+        self.heal(event.target, event.amount, event.overheal, None);
+    }
+
+    /// Resolve the entity whose health changes for this target, its pool if it is a [`SharedHealthMember`].
+    pub(crate) fn resolve(&self, target: Entity) -> Entity {
+        self.members
+            .get(target)
+            .map_or(target, |member| member.pool)
+    }
+
+    /// Returns true if the target, or its pool, has a living [`HealthSystem`].
+    pub(crate) fn is_alive(&self, target: Entity) -> bool {
+        self.targets
+            .get(self.resolve(target))
+            .is_ok_and(|(health_system, ..)| health_system.is_alive())
+    }
+
+    /// Take health from a target without healing anything, like damage bypassing the [`DamagePipeline`](crate::DamagePipeline).
+    /// Returns the health actually taken.
+    pub(crate) fn drain(&mut self, target: Entity, amount: f32) -> f32 {
+        let target = self.resolve(target);
+        let Ok((mut health_system, ..)) = self.targets.get_mut(target) else {
+            return 0.0;
+        };
+        let Some(amount) = self.settings.invalid_amounts.check(amount) else {
+            return 0.0;
+        };
+
+        let health_before = health_system.get_health();
+        health_system.deal_damage(amount.min(health_before), false);
+        health_before - health_system.get_health()
+    }
+
+    /// Heal a target, returns the health actually restored.
+    pub(crate) fn heal(
+        &mut self,
+        target: Entity,
+        amount: f32,
+        overheal: bool,
+        source: Option<Entity>,
+    ) -> f32 {
+        let target = self.resolve(target);
+        let Ok((mut health_system, temporary_health, healing_modifier, stats, integer_health)) =
+            self.targets.get_mut(target)
+        else {
+            return 0.0;
+        };
+
+        if !health_system.is_alive() {
+            return 0.0;
+        }
+
+        let amount = amount * self.settings.healing;
+        let amount = healing_modifier.map_or(amount, |modifier| modifier.apply(amount));
+        let Some(amount) = self.settings.invalid_amounts.check(amount) else {
+            return 0.0;
+        };
+        let amount = integer_health.map_or(amount, |integer_health| {
+            integer_health.healing.round(amount)
+        });
+        let health_before = health_system.get_health();
+        let overflow = health_system.heal(amount);
+        let healed = health_system.get_health() - health_before;
+        if let Some(mut stats) = stats {
+            stats.healing_received += healed;
+        }
+        self.log.push(CombatLogEntry {
+            time: self.time.elapsed_seconds_f64(),
+            kind: CombatLogKind::Heal,
+            target,
+            source,
+            amount: healed,
+            damage_type: None,
+            cause: None,
+        });
+        if let (true, Some(mut temporary_health)) = (overheal, temporary_health) {
+            temporary_health.amount += overflow;
+        }
+        healed
+    }
+}
+
+pub(crate) fn apply_heal_events(mut events: EventReader<HealEvent>, mut pipeline: HealPipeline) {
+    for event in events.iter() {
+        pipeline.apply(event);
+    }
+}
@@ -0,0 +1,97 @@
+//! Healing reduction, also known as anti-heal or grievous wounds.
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+/// Scale every heal received by an entity, from [`HealEvent`](crate::HealEvent), heal over time and [`HealthRegen`](crate::HealthRegen).
+/// A multiplier of `0.0` blocks healing, use [`HealthSystemModifier::HEAL_BLOCKED`](crate::HealthSystemModifier::HEAL_BLOCKED)
+/// to block healing from any source, including direct [`HealthSystem::heal`](crate::HealthSystem::heal) calls.
+/// The component is removed once its duration runs out.
+/// # Fields
+/// * `multiplier`: The multiplier applied to healing, eg: `0.6` removes 40% of it.
+/// * `duration`: The time left before the modifier is removed, `None` lasts forever.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{HealEvent, HealingModifier, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let mut health_system = HealthSystem::new(100.0);
+/// health_system.deal_damage(50.0, false);
+/// let entity = app
+///     .world
+///     .spawn((health_system, HealingModifier::new(0.5, Duration::from_secs(5))))
+///     .id();
+///
+/// app.world.send_event(HealEvent::new(entity, 20.0));
+/// app.update();
+///
+/// assert_eq!(60.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// ```
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct HealingModifier {
+    pub multiplier: f32,
+    pub duration: Option<Timer>,
+}
+
+/// A modifier leaving healing unchanged, lasting until removed.
+impl Default for HealingModifier {
+    fn default() -> Self {
+        Self::permanent(1.0)
+    }
+}
+
+impl HealingModifier {
+    /// Create a new healing modifier.
+    /// # Arguments
+    /// * `multiplier`: The multiplier applied to healing.
+    /// * `duration`: How long the modifier lasts.
+    pub fn new(multiplier: f32, duration: Duration) -> Self {
+        Self {
+            multiplier,
+            duration: Some(Timer::new(duration, TimerMode::Once)),
+        }
+    }
+
+    /// Create a new healing modifier lasting until removed.
+    /// # Arguments
+    /// * `multiplier`: The multiplier applied to healing.
+    pub fn permanent(multiplier: f32) -> Self {
+        Self {
+            multiplier,
+            duration: None,
+        }
+    }
+
+    /// Returns the heal amount after this modifier scaled it.
+    /// # Example
+    /// ```
+    /// use bevy_health_system::HealingModifier;
+    ///
+    /// assert_eq!(0.0, HealingModifier::permanent(0.0).apply(50.0));
+    /// assert_eq!(25.0, HealingModifier::permanent(0.5).apply(50.0));
+    /// ```
+    pub fn apply(&self, amount: f32) -> f32 {
+        amount * self.multiplier.max(0.0)
+    }
+}
+
+pub(crate) fn tick_healing_modifiers(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut HealingModifier)>,
+) {
+    for (entity, mut modifier) in &mut query {
+        let Some(duration) = modifier.duration.as_mut() else {
+            continue;
+        };
+
+        if duration.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<HealingModifier>();
+        }
+    }
+}
@@ -0,0 +1,61 @@
+//! Hit zones, eg: heads, limbs, weak spots,... of an entity.
+use std::borrow::Cow;
+
+use bevy::prelude::*;
+
+/// A hurtbox child of an entity with a [`HealthSystem`](crate::HealthSystem).
+/// A [`DamageEvent`](crate::DamageEvent) sent to it is multiplied and forwarded to its parent,
+/// the [`DamageAppliedEvent`](crate::DamageAppliedEvent) being tagged with the zone.
+/// # Fields
+/// * `multiplier`: The multiplier of the damage dealt to this zone.
+/// * `zone_tag`: The name of this zone, eg: `"head"`.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageAppliedEvent, DamageEvent, HealthSystem, HealthSystemPlugin, HitZone};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let head = app.world.spawn(HitZone::new(2.0, "head")).id();
+/// let entity = app
+///     .world
+///     .spawn(HealthSystem::new(100.0))
+///     .push_children(&[head])
+///     .id();
+///
+/// app.world.send_event(DamageEvent::new(head, 10.0));
+/// app.update();
+///
+/// assert_eq!(80.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// let events = app.world.resource::<Events<DamageAppliedEvent>>();
+/// let applied = events.iter_current_update_events().next().unwrap();
+/// assert_eq!(entity, applied.target);
+/// assert_eq!(Some("head"), applied.zone.as_deref());
+/// ```
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct HitZone {
+    pub multiplier: f32,
+    pub zone_tag: Cow<'static, str>,
+}
+
+/// A zone without tag, taking the damage unchanged.
+impl Default for HitZone {
+    fn default() -> Self {
+        Self::new(1.0, "")
+    }
+}
+
+impl HitZone {
+    /// Create a new hit zone.
+    /// # Arguments
+    /// * `multiplier`: The multiplier of the damage dealt to this zone.
+    /// * `zone_tag`: The name of this zone, eg: `"head"`.
+    pub fn new(multiplier: f32, zone_tag: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            multiplier,
+            zone_tag: zone_tag.into(),
+        }
+    }
+}
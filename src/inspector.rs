@@ -0,0 +1,184 @@
+//! `bevy-inspector-egui` widget editing a [`HealthSystem`] through its methods.
+use std::any::{Any, TypeId};
+
+use bevy::prelude::*;
+use bevy_inspector_egui::egui;
+use bevy_inspector_egui::inspector_egui_impls::InspectorEguiImpl;
+use bevy_inspector_egui::inspector_options::InspectorOptionsType;
+use bevy_inspector_egui::reflect_inspector::InspectorUi;
+
+use crate::{HealthSystem, HealthSystemReviveHealType, MaxHealthChangePolicy};
+
+/// How the inspector widget of a [`HealthSystem`] edits it, the default is used unless set with `#[inspector(...)]`
+/// on a field of an `InspectorOptions` type, eg: `#[inspector(max_policy = MaxHealthChangePolicy::ScaleProportionally)]`.
+/// # Fields
+/// * `max_policy`: What happens to the health when the max health is edited.
+/// * `revive`: How much health the revive button restores.
+/// # Examples
+/// ```
+/// use std::any::TypeId;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{HealthSystem, HealthSystemPlugin, MaxHealthChangePolicy};
+/// use bevy_inspector_egui::inspector_egui_impls::InspectorEguiImpl;
+/// use bevy_inspector_egui::prelude::*;
+///
+/// #[derive(Default, Reflect, Resource, InspectorOptions)]
+/// #[reflect(Resource, InspectorOptions)]
+/// struct Fortress {
+///     #[inspector(max_policy = MaxHealthChangePolicy::ScaleProportionally)]
+///     walls: HealthSystem,
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.register_type::<Fortress>();
+///
+/// let registry = app.world.resource::<AppTypeRegistry>().read();
+/// let registration = registry.get(TypeId::of::<HealthSystem>()).unwrap();
+/// assert!(registration.data::<InspectorEguiImpl>().is_some());
+/// ```
+#[derive(Debug, Clone)]
+pub struct HealthSystemInspectorOptions {
+    pub max_policy: MaxHealthChangePolicy,
+    pub revive: HealthSystemReviveHealType,
+}
+
+impl Default for HealthSystemInspectorOptions {
+    fn default() -> Self {
+        Self {
+            max_policy: MaxHealthChangePolicy::default(),
+            revive: HealthSystemReviveHealType::HealFull,
+        }
+    }
+}
+
+impl InspectorOptionsType for HealthSystem {
+    type DeriveOptions = HealthSystemInspectorOptions;
+    type Options = HealthSystemInspectorOptions;
+
+    fn options_from_derive(options: Self::DeriveOptions) -> Self::Options {
+        options
+    }
+}
+
+/// Replace the reflected fields of [`HealthSystem`] in the inspector by a widget calling its methods:
+/// sliders for the health and max health, and buttons to kill, revive and fully heal.
+pub(crate) fn register_health_system_inspector(app: &mut App) {
+    let registry = app.world.resource::<AppTypeRegistry>().clone();
+    let mut registry = registry.write();
+    let Some(registration) = registry.get_mut(TypeId::of::<HealthSystem>()) else {
+        return;
+    };
+    registration.insert(InspectorEguiImpl::new(
+        health_system_ui,
+        health_system_ui_readonly,
+        health_system_ui_many,
+    ));
+}
+
+fn health_system_ui(
+    value: &mut dyn Any,
+    ui: &mut egui::Ui,
+    options: &dyn Any,
+    id: egui::Id,
+    _env: InspectorUi<'_, '_>,
+) -> bool {
+    let Some(health_system) = value.downcast_mut::<HealthSystem>() else {
+        return false;
+    };
+    let options = options
+        .downcast_ref::<HealthSystemInspectorOptions>()
+        .cloned()
+        .unwrap_or_default();
+    let mut changed = false;
+
+    let mut max_health = health_system.get_health_max();
+    let mut health = health_system.get_health();
+    egui::Grid::new(id).show(ui, |ui| {
+        ui.label("Health");
+        let slider = egui::Slider::new(&mut health, 0.0..=max_health.max(0.0));
+        if ui.add(slider).changed() {
+            health_system.set_health(health);
+            changed = true;
+        }
+        ui.end_row();
+
+        ui.label("Max health");
+        let drag = egui::DragValue::new(&mut max_health).clamp_range(0.0..=f32::MAX);
+        if ui.add(drag).changed() {
+            health_system.set_health_max_with_policy(max_health, options.max_policy);
+            changed = true;
+        }
+        ui.end_row();
+    });
+    state_ui(health_system, ui);
+
+    ui.horizontal(|ui| {
+        if ui
+            .add_enabled(!health_system.is_dead(), egui::Button::new("Kill"))
+            .clicked()
+        {
+            health_system.kill_system(true);
+            changed = true;
+        }
+        if ui
+            .add_enabled(!health_system.is_alive(), egui::Button::new("Revive"))
+            .clicked()
+        {
+            let _ = health_system.revive_system(options.revive, false);
+            changed = true;
+        }
+        if ui
+            .add_enabled(health_system.is_alive(), egui::Button::new("Heal full"))
+            .clicked()
+        {
+            health_system.heal_full();
+            changed = true;
+        }
+    });
+
+    changed
+}
+
+fn health_system_ui_readonly(
+    value: &dyn Any,
+    ui: &mut egui::Ui,
+    _options: &dyn Any,
+    _id: egui::Id,
+    _env: InspectorUi<'_, '_>,
+) {
+    let Some(health_system) = value.downcast_ref::<HealthSystem>() else {
+        return;
+    };
+    ui.label(format!(
+        "Health: {}/{}",
+        health_system.get_health(),
+        health_system.get_health_max()
+    ));
+    state_ui(health_system, ui);
+}
+
+fn health_system_ui_many(
+    ui: &mut egui::Ui,
+    _options: &dyn Any,
+    _id: egui::Id,
+    _env: InspectorUi<'_, '_>,
+    _values: &mut [&mut dyn Reflect],
+    _projector: &dyn Fn(&mut dyn Reflect) -> &mut dyn Reflect,
+) -> bool {
+    ui.label("Editing many health systems at once is not supported.");
+    false
+}
+
+fn state_ui(health_system: &HealthSystem, ui: &mut egui::Ui) {
+    let modifiers: Vec<_> = health_system
+        .get_modifiers()
+        .iter()
+        .map(|modifier| format!("{modifier:?}"))
+        .collect();
+    ui.label(format!(
+        "State: {:?}, modifiers: [{}]",
+        health_system.get_state(),
+        modifiers.join(", ")
+    ));
+}
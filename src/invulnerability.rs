@@ -0,0 +1,129 @@
+//! Timed invulnerability, also known as invincibility frames.
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::DamageAppliedEvent;
+
+/// Make an entity ignore any non forced [`DamageEvent`](crate::DamageEvent) until the timer finishes.
+/// The component is removed once it runs out.
+/// # Fields
+/// * `remaining`: The time left before the entity can be damaged again.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, HealthSystem, HealthSystemPlugin, Invulnerability};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), Invulnerability::from_seconds(1.0)))
+///     .id();
+/// app.world.send_event(DamageEvent::new(entity, 10.0));
+/// app.update();
+/// assert_eq!(100.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+///
+/// // Forced damage ignores invulnerability.
+/// app.world.send_event(DamageEvent::new(entity, 10.0).forced());
+/// app.update();
+/// assert_eq!(90.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Invulnerability {
+    pub remaining: Timer,
+}
+
+impl Invulnerability {
+    /// Create a new invulnerability window.
+    /// # Arguments
+    /// * `duration`: How long the entity stays invulnerable.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            remaining: Timer::new(duration, TimerMode::Once),
+        }
+    }
+
+    /// Create a new invulnerability window lasting `seconds`.
+    pub fn from_seconds(seconds: f32) -> Self {
+        Self::new(Duration::from_secs_f32(seconds))
+    }
+
+    /// Returns true while the entity should not take any non forced damage.
+    pub fn is_active(&self) -> bool {
+        !self.remaining.finished()
+    }
+}
+
+pub(crate) fn tick_invulnerability(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut Invulnerability)>,
+) {
+    for (entity, mut invulnerability) in &mut query {
+        if invulnerability.remaining.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<Invulnerability>();
+        }
+    }
+}
+
+/// Grant an [`Invulnerability`] to an entity each time it takes damage, eg: the invincibility frames of a player.
+/// A hit does not extend an invulnerability the entity already has.
+/// # Fields
+/// * `duration`: How long the entity stays invulnerable after a hit.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, HealthSystem, HealthSystemPlugin, HitInvulnerability};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), HitInvulnerability::new(Duration::from_secs(1))))
+///     .id();
+/// app.world.send_event(DamageEvent::new(entity, 10.0));
+/// app.update();
+/// app.world.send_event(DamageEvent::new(entity, 10.0));
+/// app.update();
+/// assert_eq!(90.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct HitInvulnerability {
+    pub duration: Duration,
+}
+
+impl HitInvulnerability {
+    /// Create a new invulnerability granted on hit.
+    /// # Arguments
+    /// * `duration`: How long the entity stays invulnerable after a hit.
+    pub fn new(duration: Duration) -> Self {
+        Self { duration }
+    }
+}
+
+pub(crate) fn grant_hit_invulnerability(
+    mut commands: Commands,
+    mut damage_events: EventReader<DamageAppliedEvent>,
+    query: Query<(&HitInvulnerability, Option<&Invulnerability>)>,
+) {
+    for event in damage_events.iter() {
+        if event.amount <= 0.0 {
+            continue;
+        }
+        let Ok((hit_invulnerability, invulnerability)) = query.get(event.target) else {
+            continue;
+        };
+        if invulnerability.is_some_and(Invulnerability::is_active) {
+            continue;
+        }
+        commands
+            .entity(event.target)
+            .insert(Invulnerability::new(hit_invulnerability.duration));
+    }
+}
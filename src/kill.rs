@@ -0,0 +1,65 @@
+//! Kill feed events, built from the attribution of deaths.
+use std::borrow::Cow;
+
+use bevy::prelude::*;
+
+use crate::{DamageCause, DeathEvent};
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) for every [`DeathEvent`], ready to be shown in a kill feed.
+/// # Fields
+/// * `killer`: The entity that dealt the killing blow, if any.
+/// * `victim`: The entity that died.
+/// * `assists`: The other contributors of the [`RecentAttackers`](crate::RecentAttackers) of the victim, largest damage first.
+/// * `cause`: What caused the death, if known.
+/// * `weapon_tag`: The weapon of the killing blow, see [`DamageEvent::with_weapon`](crate::DamageEvent::with_weapon).
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, HealthSystem, HealthSystemPlugin, Kill, RecentAttackers};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let killer = app.world.spawn_empty().id();
+/// let assist = app.world.spawn_empty().id();
+/// let victim = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), RecentAttackers::new(Duration::from_secs(10))))
+///     .id();
+///
+/// app.world.send_event(DamageEvent::new(victim, 50.0).with_source(assist));
+/// app.world.send_event(DamageEvent::new(victim, 50.0).with_source(killer).with_weapon("railgun"));
+/// app.update();
+///
+/// let events = app.world.resource::<Events<Kill>>();
+/// let kill = events.iter_current_update_events().next().unwrap();
+/// assert_eq!((Some(killer), victim), (kill.killer, kill.victim));
+/// assert_eq!(vec![assist], kill.assists);
+/// assert_eq!(Some("railgun"), kill.weapon_tag.as_deref());
+/// ```
+#[derive(Debug, Clone, Event)]
+pub struct Kill {
+    pub killer: Option<Entity>,
+    pub victim: Entity,
+    pub assists: Vec<Entity>,
+    pub cause: Option<DamageCause>,
+    pub weapon_tag: Option<Cow<'static, str>>,
+}
+
+pub(crate) fn send_kills(mut deaths: EventReader<DeathEvent>, mut kills: EventWriter<Kill>) {
+    for death in deaths.iter() {
+        kills.send(Kill {
+            killer: death.source,
+            victim: death.entity,
+            assists: death
+                .contributors
+                .iter()
+                .map(|contribution| contribution.source)
+                .filter(|&source| Some(source) != death.source)
+                .collect(),
+            cause: death.cause.clone(),
+            weapon_tag: death.weapon.clone(),
+        });
+    }
+}
@@ -0,0 +1,77 @@
+//! Record of the last damage taken by an entity.
+use std::time::Duration;
+
+use bevy::ecs::entity::{EntityMapper, MapEntities};
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::prelude::*;
+
+use crate::{DamageAppliedEvent, DamageCause, DamageType};
+
+/// The last damage an entity took from a [`DamageEvent`](crate::DamageEvent).
+/// Inserted and kept up to date by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) on every damaged entity.
+/// # Fields
+/// * `amount`: The amount of health lost.
+/// * `source`: The entity that dealt the damage, if any.
+/// * `damage_type`: The type of the damage.
+/// * `cause`: What caused the damage, if known.
+/// * `time`: When the damage was taken, as [`Time::elapsed`].
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, DamageType, HealthSystem, HealthSystemPlugin, LastDamage};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let attacker = app.world.spawn_empty().id();
+/// let entity = app.world.spawn(HealthSystem::new(100.0)).id();
+/// app.world.send_event(
+///     DamageEvent::new(entity, 10.0)
+///         .with_source(attacker)
+///         .with_type(DamageType::FIRE),
+/// );
+/// app.update();
+///
+/// let last_damage = app.world.get::<LastDamage>(entity).unwrap();
+/// assert_eq!(10.0, last_damage.amount);
+/// assert_eq!(Some(attacker), last_damage.source);
+/// assert_eq!(DamageType::FIRE, last_damage.damage_type);
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct LastDamage {
+    pub amount: f32,
+    pub source: Option<Entity>,
+    pub damage_type: DamageType,
+    pub cause: Option<DamageCause>,
+    pub time: Duration,
+}
+
+/// Keep the source pointing to the same entity when spawned from a scene.
+impl MapEntities for LastDamage {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        if let Some(source) = &mut self.source {
+            *source = entity_mapper.get_or_reserve(*source);
+        }
+    }
+}
+
+pub(crate) fn record_last_damage(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut damaged: EventReader<DamageAppliedEvent>,
+) {
+    for event in damaged.iter() {
+        let Some(mut entity) = commands.get_entity(event.target) else {
+            continue;
+        };
+
+        entity.insert(LastDamage {
+            amount: event.amount,
+            source: event.source,
+            damage_type: event.damage_type,
+            cause: event.cause.clone(),
+            time: time.elapsed(),
+        });
+    }
+}
@@ -21,54 +21,696 @@
 //!
 //! ## Structs
 //! 1. [HealthSystem]
-//! 2. [HealthSystemState]
-//! 3. [HealthSystemModifier]
-//! 4. [HealthSystemReviveHealType]
+//! 2. [Health]
+//! 3. [HealthNum]
+//! 4. [HealthSystemF64]
+//! 5. [HealthSystemI32]
+//! 6. [HealthSystemState]
+//! 7. [HealthSystemModifier]
+//! 8. [HealthSystemModifiers]
+//! 9. [HealthSystemReviveHealType]
+//! 10. [MaxHealthChangePolicy]
+//! 11. [HealthError]
+//! 12. [Fraction]
+//! 13. [ReviveOutcome]
+//! 14. [ReviveError]
+//! 15. [NotEnoughHealth]
+//!
+//! ## Plugin
+//! Add [`HealthSystemPlugin`] to use the components and events below.
+//! 1. [HealthSet]
+//! 2. [DamageEvent]
+//! 3. [DamageAppliedEvent]
+//! 4. [DamageCause]
+//! 5. [HitInfo]
+//! 6. [DamagePipeline]
+//! 7. [DamageStage]
+//! 8. [DamageContext]
+//! 9. [DamageBreakdown]
+//! 10. [DamageModifier]
+//! 11. [DamagePipelineAppExt]
+//! 12. [HealthWriter]
+//! 13. [HealthSpender]
+//! 14. [HealthSpent]
+//! 15. [HealthTransferEvent]
+//! 16. [HealthTransferred]
+//! 17. [HealthQuery]
+//! 18. [DamageResolution]
+//! 19. [HealthRequestQueue]
+//! 20. [Falloff]
+//! 21. [ContactDamage]
+//! 22. [DamageZone]
+//! 23. [HealZone]
+//! 24. [SafeZone]
+//! 25. [InSafeZone]
+//! 26. [HealingAura]
+//! 27. [HitZone]
+//! 28. [CritStats]
+//! 29. [ExecuteThreshold]
+//! 30. [DamageTakenMultiplier]
+//! 31. [DamageDealtMultiplier]
+//! 32. [HealthRng]
+//! 33. [Evasion]
+//! 34. [DamageDodged]
+//! 35. [BlockState]
+//! 36. [BlockedHit]
+//! 37. [Parried]
+//! 38. [Poise]
+//! 39. [Staggered]
+//! 40. [Team]
+//! 41. [TeamFilter]
+//! 42. [FriendlyFirePolicy]
+//! 43. [GlobalDamageSettings]
+//! 44. [Player]
+//! 45. [FriendlyFireBlocked]
+//! 46. [SharedHealthPool]
+//! 47. [SharedHealthMember]
+//! 48. [AggregateHealth]
+//! 49. [AggregateDeathRule]
+//! 50. [HealthPart]
+//! 51. [PartDestroyed]
+//! 52. [DeathEvent]
+//! 53. [DeathBehavior]
+//! 54. [Dead]
+//! 55. [Corpse]
+//! 56. [CorpseRemoved]
+//! 57. [Loot]
+//! 58. [LootDropped]
+//! 59. [Downable]
+//! 60. [Downed]
+//! 61. [DownedEvent]
+//! 62. [Endure]
+//! 63. [EnduredLethalHit]
+//! 64. [ReviveEvent]
+//! 65. [RevivedEvent]
+//! 66. [Lives]
+//! 67. [RespawnTimer]
+//! 68. [RespawnPoint]
+//! 69. [OutOfLives]
+//! 70. [AutoRevive]
+//! 71. [AutoRevived]
+//! 72. [LastDamage]
+//! 73. [RecentAttackers]
+//! 74. [RecentHit]
+//! 75. [DamageContribution]
+//! 76. [Kill]
+//! 77. [CombatStats]
+//! 78. [DpsMeter]
+//! 79. [DpsSource]
+//! 80. [DisplayedHealth]
+//! 81. [SmoothedHealth]
+//! 82. [PredictedHealth]
+//! 83. [HealthMispredicted]
+//! 84. [HealthSync]
+//! 85. [HealthQuantum]
+//! 86. [encode_health]
+//! 87. [decode_health]
+//! 88. [CombatLog]
+//! 89. [CombatLogEntry]
+//! 90. [CombatLogKind]
+//! 91. [InCombat]
+//! 92. [CombatTimeout]
+//! 93. [CombatRule]
+//! 94. [HealthThresholds]
+//! 95. [ThresholdCrossed]
+//! 96. [ThresholdDirection]
+//! 97. [HealthPhases]
+//! 98. [CurrentPhase]
+//! 99. [PhaseChanged]
+//! 100. [HealEvent]
+//! 101. [Invulnerability]
+//! 102. [HitInvulnerability]
+//! 103. [SpawnProtection]
+//! 104. [SpawnProtectionExpired]
+//! 105. [HealthRegen]
+//! 106. [HealthDecay]
+//! 107. [MaxHealthModifiers]
+//! 108. [MaxHealthBonus]
+//! 109. [Shield]
+//! 110. [ShieldBroken]
+//! 111. [ShieldRecharge]
+//! 112. [ShieldRechargeStarted]
+//! 113. [ShieldRecharged]
+//! 114. [TemporaryHealth]
+//! 115. [Armor]
+//! 116. [DamageCap]
+//! 117. [IntegerHealth]
+//! 118. [Rounding]
+//! 119. [DamageType]
+//! 120. [DamageTypeRegistry]
+//! 121. [Resistances]
+//! 122. [DamageImmunities]
+//! 123. [DamageImmune]
+//! 124. [DamageOverTime]
+//! 125. [DamageOverTimeEffects]
+//! 126. [ApplyDamageOverTime]
+//! 127. [DamageOverTimeApplied]
+//! 128. [DamageOverTimeExpired]
+//! 129. [HealOverTime]
+//! 130. [HealOverTimeEffects]
+//! 131. [ApplyHealOverTime]
+//! 132. [HealOverTimeApplied]
+//! 133. [HealOverTimeExpired]
+//! 134. [HealthStatusKind]
+//! 135. [HealthStatusEffect]
+//! 136. [HealthStatusEffects]
+//! 137. [ApplyHealthStatusEffect]
+//! 138. [HealthStatusApplied]
+//! 139. [HealthStatusExpired]
+//! 140. [HealingModifier]
+//! 141. [Lifesteal]
+//! 142. [Thorns]
+//!
+//! ## Bundles
+//! Spawn the health of common kinds of entities in one go, with the [`HealthSystemPlugin`].
+//! 1. [PlayerHealthBundle]
+//! 2. [EnemyHealthBundle]
+//!
+//! ## UI
+//! Add [`HealthBarUiPlugin`] to build the widgets below.
+//! 1. [HealthBar]
+//! 2. [BossBar]
+//! 3. [HealthText]
+//! 4. [HealthTextFormat]
+//!
+//! Add [`WorldHealthBarPlugin`] to build the bars below.
+//! 1. [WorldHealthBar]
+//!
+//! Add [`HealthDebugPlugin`] to draw the overlay below.
+//! 1. [HealthDebugOverlay]
+//!
+//! Add [`HealthDiagnosticsPlugin`] to measure the health of your entities through Bevy diagnostics.
+//!
+//! ## Run conditions
+//! Run your systems depending on the health of entities, without the plugin.
+//! 1. [any_entity_dead]
+//! 2. [entity_below]
+//! 3. [all_dead]
+//!
+//! ## Saving
+//! Capture the health of a world and restore it later, without the plugin.
+//! 1. [SaveData]
+//! 2. [HealthSnapshot]
+//! 3. [SaveId]
+//! 4. [HealthSaveMigrations]
+//! 5. [RestoreError]
+//!
+//! # Features
+//! - `serde`: `Serialize` and `Deserialize` for [`HealthSystem`] and its enums, and [`SaveData`] with the components it captures.
+//! - `rapier`: [`ContactDamage`] reads contacts from `bevy_rapier3d`.
+//! - `avian`: [`ContactDamage`] reads contacts from `bevy_xpbd_3d`, the Bevy 0.11 release of avian.
+//! - `damage_numbers`: `DamageNumbersPlugin`, floating combat text spawned where entities are hit or healed.
+//! - `inspector`: A `bevy-inspector-egui` widget editing [`HealthSystem`]s through their methods, see `HealthSystemInspectorOptions`.
+//! - `console`: `HealthConsolePlugin`, `bevy_console` commands to damage, heal, kill, revive and make entities invincible.
+//! - `egui`: `HealthPanelPlugin`, an egui window listing every entity with health, with sort, filter and actions.
+//! - `preset`: `HealthPreset` assets initializing health, armor, resistances and regen, loaded from `.health.ron` files and hot reloaded.
+//! - `replicon`: `HealthReplicationPlugin`, `bevy_replicon` replication of health, with `DamageRequest`s sent by clients and validated by the server.
+//! - `ggrs`: `HealthRollbackPlugin`, `bevy_ggrs` rollback of health, with requests resolved in the rollback schedule.
+//! - `fixed`: `HealthSystemFixed`, a [`Health`] stored in the `I32F32` fixed-point number of the `fixed` crate, see `fixed_point`.
+//!   Like the other backings it is not read by the [`HealthSystemPlugin`], which keeps [`HealthSystem`] and its `f32` math unchanged.
+//! - `theme`: `HealthBarTheme` assets styling [`HealthBar`]s, loaded from `.healthbar.ron` files and hot reloaded.
 //! # License
 //! MIT
 
+pub use self::aggregate::{AggregateDeathRule, AggregateHealth, HealthPart, PartDestroyed};
+pub use self::armor::Armor;
+pub use self::attackers::{DamageContribution, RecentAttackers, RecentHit};
+pub use self::aura::HealingAura;
+pub use self::auto_revive::{AutoRevive, AutoRevived};
+pub use self::block::{BlockState, BlockedHit, Parried};
+pub use self::bundles::{EnemyHealthBundle, PlayerHealthBundle};
+pub use self::cap::DamageCap;
+pub use self::combat::{CombatRule, CombatTimeout, InCombat};
+pub use self::condition::{all_dead, any_entity_dead, entity_below};
+#[cfg(feature = "console")]
+pub use self::console::HealthConsolePlugin;
+pub use self::contact::ContactDamage;
+pub use self::crit::CritStats;
+pub use self::damage::{DamageAppliedEvent, DamageCause, DamageEvent, HitInfo};
+#[cfg(feature = "damage_numbers")]
+pub use self::damage_numbers::{
+    DamageNumber, DamageNumberKind, DamageNumberLook, DamageNumberPool, DamageNumberSpace,
+    DamageNumberStyle, DamageNumbersPlugin,
+};
+pub use self::damage_type::{
+    DamageImmune, DamageImmunities, DamageType, DamageTypeRegistry, Resistances,
+};
+pub use self::death::{Corpse, CorpseRemoved, Dead, DeathBehavior, DeathEvent};
+pub use self::debug::{HealthDebugOverlay, HealthDebugPlugin};
+pub use self::decay::HealthDecay;
+pub use self::diagnostics::HealthDiagnosticsPlugin;
+pub use self::displayed::{DisplayedHealth, SmoothedHealth};
+pub use self::downed::{Downable, Downed, DownedEvent};
+pub use self::endure::{Endure, EnduredLethalHit};
+pub use self::evasion::{DamageDodged, Evasion};
+pub use self::execute::ExecuteThreshold;
+pub use self::falloff::Falloff;
+pub use self::fraction::Fraction;
+pub use self::heal::HealEvent;
+pub use self::healing_modifier::HealingModifier;
+#[cfg(feature = "fixed")]
+pub use self::health_system::HealthSystemFixed;
 pub use self::health_system::{
-    HealthSystem, HealthSystemModifier, HealthSystemReviveHealType, HealthSystemState,
+    Health, HealthError, HealthSystem, HealthSystemF64, HealthSystemI32, HealthSystemModifier,
+    HealthSystemModifiers, HealthSystemReviveHealType, HealthSystemState, MaxHealthChangePolicy,
+    NotEnoughHealth, ReviveError, ReviveOutcome,
+};
+pub use self::hit_zone::HitZone;
+#[cfg(feature = "inspector")]
+pub use self::inspector::HealthSystemInspectorOptions;
+pub use self::invulnerability::{HitInvulnerability, Invulnerability};
+pub use self::kill::Kill;
+pub use self::last_damage::LastDamage;
+pub use self::lifesteal::Lifesteal;
+pub use self::log::{CombatLog, CombatLogEntry, CombatLogKind};
+pub use self::loot::{Loot, LootDropped};
+pub use self::max_health::{MaxHealthBonus, MaxHealthModifiers};
+pub use self::multiplier::{DamageDealtMultiplier, DamageTakenMultiplier};
+pub use self::num::HealthNum;
+pub use self::over_time::{
+    ApplyDamageOverTime, ApplyHealOverTime, DamageOverTime, DamageOverTimeApplied,
+    DamageOverTimeEffects, DamageOverTimeExpired, HealOverTime, HealOverTimeApplied,
+    HealOverTimeEffects, HealOverTimeExpired,
+};
+#[cfg(feature = "egui")]
+pub use self::panel::{HealthPanel, HealthPanelPlugin, HealthPanelSort};
+pub use self::phase::{CurrentPhase, HealthPhases, PhaseChanged};
+pub use self::pipeline::{
+    DamageBreakdown, DamageContext, DamageModifier, DamagePipeline, DamagePipelineAppExt,
+    DamageStage,
+};
+pub use self::plugin::{HealthSet, HealthSystemPlugin};
+pub use self::poise::{Poise, Staggered};
+pub use self::prediction::{HealthMispredicted, PredictedHealth};
+#[cfg(feature = "preset")]
+pub use self::preset::{HealthPreset, HealthPresetHandle, HealthPresetLoader, HealthPresetRegen};
+pub use self::quantize::{decode_health, encode_health, HealthQuantum, HealthSync};
+pub use self::query::HealthQuery;
+pub use self::queue::{DamageResolution, HealthRequestQueue};
+pub use self::regen::HealthRegen;
+#[cfg(feature = "replicon")]
+pub use self::replicon::{
+    DamageRequest, DamageRequestPolicy, DamageRequestRejected, HealthReplicationPlugin,
 };
+pub use self::respawn::{Lives, OutOfLives, RespawnPoint, RespawnTimer};
+pub use self::revive::{ReviveEvent, RevivedEvent};
+pub use self::rng::HealthRng;
+#[cfg(feature = "ggrs")]
+pub use self::rollback::{HealthRollbackExt, HealthRollbackPlugin, HealthRollbackSet};
+pub use self::rounding::{IntegerHealth, Rounding};
+pub use self::settings::{GlobalDamageSettings, InvalidAmountPolicy, Player};
+pub use self::shared_pool::{SharedHealthMember, SharedHealthPool};
+pub use self::shield::{
+    Shield, ShieldBroken, ShieldRecharge, ShieldRechargeStarted, ShieldRecharged,
+};
+pub use self::snapshot::{HealthSaveMigrations, HealthSnapshot, RestoreError, SaveData, SaveId};
+pub use self::spawn_protection::{SpawnProtection, SpawnProtectionExpired};
+pub use self::spend::{HealthSpender, HealthSpent};
+pub use self::stats::{CombatStats, DpsMeter, DpsSource};
+pub use self::status::{
+    ApplyHealthStatusEffect, HealthStatusApplied, HealthStatusEffect, HealthStatusEffects,
+    HealthStatusExpired, HealthStatusKind,
+};
+pub use self::team::{FriendlyFireBlocked, FriendlyFirePolicy, Team, TeamFilter};
+pub use self::temporary_health::TemporaryHealth;
+#[cfg(feature = "theme")]
+pub use self::theme::{HealthBarTheme, HealthBarThemeLoader, ThemedHealthBar};
+pub use self::thorns::Thorns;
+pub use self::threshold::{HealthThresholds, ThresholdCrossed, ThresholdDirection};
+pub use self::transfer::{HealthTransferEvent, HealthTransferred};
+pub use self::ui::{BossBar, HealthBar, HealthBarUiPlugin, HealthText, HealthTextFormat};
+pub use self::world_bar::{WorldHealthBar, WorldHealthBarPlugin};
+pub use self::writer::HealthWriter;
+pub use self::zone::{DamageZone, HealZone, InSafeZone, SafeZone};
 
+pub mod aggregate;
+pub mod armor;
+pub mod attackers;
+pub mod aura;
+pub mod auto_revive;
+pub mod block;
+pub mod bundles;
+pub mod cap;
+pub mod combat;
+pub mod condition;
+#[cfg(feature = "console")]
+pub mod console;
+pub mod contact;
+pub mod crit;
+pub mod damage;
+#[cfg(feature = "damage_numbers")]
+pub mod damage_numbers;
+pub mod damage_type;
+pub mod death;
+pub mod debug;
+pub mod decay;
+pub mod diagnostics;
+pub mod displayed;
+pub mod downed;
+pub mod endure;
+pub mod evasion;
+pub mod execute;
+pub mod falloff;
+#[cfg(feature = "fixed")]
+pub mod fixed_point;
+pub mod fraction;
+pub mod heal;
+pub mod healing_modifier;
+pub mod hit_zone;
+#[cfg(feature = "inspector")]
+pub mod inspector;
+pub mod invulnerability;
+pub mod kill;
+pub mod last_damage;
+pub mod lifesteal;
+pub mod log;
+pub mod loot;
+pub mod max_health;
+pub mod multiplier;
+pub mod num;
+pub mod over_time;
+#[cfg(feature = "egui")]
+pub mod panel;
+pub mod phase;
+pub mod pipeline;
+pub mod plugin;
+pub mod poise;
+pub mod prediction;
+#[cfg(feature = "preset")]
+pub mod preset;
+pub mod quantize;
+pub mod query;
+pub mod queue;
+pub mod regen;
+#[cfg(feature = "replicon")]
+pub mod replicon;
+pub mod require;
+pub mod respawn;
+pub mod revive;
+pub mod rng;
+#[cfg(feature = "ggrs")]
+pub mod rollback;
+pub mod rounding;
+pub mod settings;
+pub mod shared_pool;
+pub mod shield;
+pub mod snapshot;
+pub mod spawn_protection;
+pub mod spend;
+pub mod stats;
+pub mod status;
+pub mod team;
+pub mod temporary_health;
+#[cfg(feature = "theme")]
+pub mod theme;
+pub mod thorns;
+pub mod threshold;
+pub mod transfer;
+pub mod ui;
+pub mod world_bar;
+pub mod writer;
+pub mod zone;
+
+// The derives of `HealthSystemReviveHealType` expand to uses of its deprecated `HealPercentage`.
+#[allow(deprecated)]
 pub mod health_system {
-    use bevy::prelude::Component;
+    use std::fmt;
+    use std::hash::{Hash, Hasher};
+    use std::time::Duration;
+
+    use bevy::prelude::{Component, Reflect, ReflectComponent};
+    #[cfg(feature = "serde")]
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+    use crate::{DamageCause, Fraction, HealthNum};
+
+    /// Returns the value if it is neither NaN nor infinite.
+    fn finite<T: HealthNum>(value: T) -> Result<T, HealthError> {
+        if value.is_finite() {
+            Ok(value)
+        } else {
+            Err(HealthError::NotFinite {
+                value: value.to_f32(),
+            })
+        }
+    }
+
+    /// The lowest of two health values.
+    fn min<T: PartialOrd>(a: T, b: T) -> T {
+        if b < a {
+            b
+        } else {
+            a
+        }
+    }
+
+    /// The highest of two health values.
+    fn max<T: PartialOrd>(a: T, b: T) -> T {
+        if b > a {
+            b
+        } else {
+            a
+        }
+    }
+
+    #[derive(Debug, Default, PartialEq, Eq, Hash, Copy, Clone, Reflect)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
     pub enum HealthSystemState {
         /// __Default__. The entity having this system should be alive.
         #[default]
         ALIVE,
         /// The entity having this system should be dead.
         DEAD,
+        /// The entity having this system is down and bleeding out, see [`Downable`](crate::Downable).
+        /// It is not dead yet and can still be revived.
+        DOWNED,
     }
 
-    #[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+    #[derive(Debug, Default, PartialEq, Eq, Hash, Copy, Clone, Reflect)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[allow(non_camel_case_types)]
     pub enum HealthSystemModifier {
         /// __Default__.
         #[default]
         NONE,
         /// The entity having this system can't take any damage unless it was forced.
         INVINCIBLE,
+        /// The entity having this system can't be healed.
+        HEAL_BLOCKED,
+    }
+
+    impl HealthSystemModifier {
+        fn bit(self) -> u32 {
+            match self {
+                HealthSystemModifier::NONE => 0,
+                HealthSystemModifier::INVINCIBLE => 1 << 0,
+                HealthSystemModifier::HEAL_BLOCKED => 1 << 1,
+            }
+        }
+
+        const ALL: [HealthSystemModifier; 2] = [
+            HealthSystemModifier::INVINCIBLE,
+            HealthSystemModifier::HEAL_BLOCKED,
+        ];
+    }
+
+    /// A set of [`HealthSystemModifier`], letting a system have many modifiers at once.
+    /// [`HealthSystemModifier::NONE`] is never part of the set, an empty set means no modifier.
+    /// # Example
+    /// ```
+    /// use bevy_health_system::{HealthSystemModifier, HealthSystemModifiers};
+    ///
+    /// let mut modifiers = HealthSystemModifiers::default();
+    /// assert!(modifiers.contains(HealthSystemModifier::NONE));
+    ///
+    /// modifiers.insert(HealthSystemModifier::INVINCIBLE);
+    /// assert!(modifiers.contains(HealthSystemModifier::INVINCIBLE));
+    /// assert!(!modifiers.contains(HealthSystemModifier::NONE));
+    ///
+    /// modifiers.remove(HealthSystemModifier::INVINCIBLE);
+    /// assert!(modifiers.is_empty());
+    /// ```
+    #[derive(Debug, Default, PartialEq, Eq, Hash, Copy, Clone, Reflect)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct HealthSystemModifiers(u32);
+
+    impl HealthSystemModifiers {
+        /// Add a modifier to the set.
+        pub fn insert(&mut self, modifier: HealthSystemModifier) {
+            self.0 |= modifier.bit();
+        }
+
+        /// Remove a modifier from the set.
+        pub fn remove(&mut self, modifier: HealthSystemModifier) {
+            self.0 &= !modifier.bit();
+        }
+
+        /// Returns true if the set has the modifier.
+        /// [`HealthSystemModifier::NONE`] is only contained by an empty set.
+        pub fn contains(&self, modifier: HealthSystemModifier) -> bool {
+            match modifier {
+                HealthSystemModifier::NONE => self.is_empty(),
+                modifier => self.0 & modifier.bit() != 0,
+            }
+        }
+
+        /// Returns true if the set has no modifier.
+        pub fn is_empty(&self) -> bool {
+            self.0 == 0
+        }
+
+        /// Returns every modifier of the set.
+        pub fn iter(&self) -> impl Iterator<Item = HealthSystemModifier> + '_ {
+            HealthSystemModifier::ALL
+                .into_iter()
+                .filter(|modifier| self.contains(*modifier))
+        }
     }
 
+    impl From<HealthSystemModifier> for HealthSystemModifiers {
+        fn from(modifier: HealthSystemModifier) -> Self {
+            Self(modifier.bit())
+        }
+    }
+
+    impl FromIterator<HealthSystemModifier> for HealthSystemModifiers {
+        fn from_iter<I: IntoIterator<Item = HealthSystemModifier>>(iter: I) -> Self {
+            let mut modifiers = Self::default();
+            for modifier in iter {
+                modifiers.insert(modifier);
+            }
+            modifiers
+        }
+    }
+
+    #[derive(Debug, Default, PartialEq, Copy, Clone, Reflect)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
     pub enum HealthSystemReviveHealType {
-        /// Fully heal the system when revive.
+        /// __Default__. Fully heal the system when revive.
+        #[default]
         HealFull,
 
         /// Heal to this value when revive. Overflow health will be returned.
         HealTo(f32),
 
-        /// Heal to a percentage of max health.
+        /// Heal to a percentage of max health, from `0.0` to `100.0`.
+        #[deprecated(note = "use `HealFraction` with `Fraction::from_percent` instead")]
         HealPercentage(f32),
+
+        /// Heal to a fraction of max health, from `0.0` to `1.0`, see [`Fraction`].
+        HealFraction(Fraction),
+    }
+
+    /// Hash the bits of the health, like [`HealthSystem`].
+    impl Hash for HealthSystemReviveHealType {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            std::mem::discriminant(self).hash(state);
+            match self {
+                Self::HealFull => {}
+                Self::HealTo(value) | Self::HealPercentage(value) => value.to_bits().hash(state),
+                Self::HealFraction(fraction) => fraction.get().to_bits().hash(state),
+            }
+        }
+    }
+
+    /// What happens to the current health of a system when its max health changes,
+    /// see [`HealthSystem::set_health_max_with_policy`].
+    #[derive(Debug, Default, PartialEq, Eq, Hash, Copy, Clone, Reflect)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub enum MaxHealthChangePolicy {
+        /// Keep the current health as is, even above the new max health.
+        KeepCurrent,
+        /// Keep the same fraction of max health, eg: for level-ups.
+        ScaleProportionally,
+        /// __Default__. Keep the current health, lowered to the new max health if above it.
+        #[default]
+        ClampToNewMax,
+        /// Fully heal the system.
+        HealToFull,
+    }
+
+    /// Why a `try_` method of a [`Health`] refused a value, eg: [`HealthSystem::try_deal_damage`].
+    /// The system is left untouched.
+    #[derive(Debug, PartialEq, Copy, Clone)]
+    pub enum HealthError {
+        /// The value is NaN or infinite.
+        NotFinite {
+            /// The value, as an `f32`.
+            value: f32,
+        },
+    }
+
+    impl fmt::Display for HealthError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                Self::NotFinite { value } => write!(f, "health value {value} is not finite"),
+            }
+        }
+    }
+
+    impl std::error::Error for HealthError {}
+
+    /// The health a system was revived with, returned by [`HealthSystem::revive_system`].
+    /// # Fields
+    /// * `health`: The health of the system after revival.
+    /// * `overflow`: The health above max health that was not restored, eg: of a [`HealthSystemReviveHealType::HealTo`].
+    #[derive(Debug, Default, PartialEq, Copy, Clone)]
+    pub struct ReviveOutcome<T: HealthNum = f32> {
+        pub health: T,
+        pub overflow: T,
+    }
+
+    /// Why [`HealthSystem::revive_system`] refused to revive a system.
+    /// The system is left untouched.
+    #[derive(Debug, PartialEq, Eq, Copy, Clone)]
+    pub enum ReviveError {
+        /// The system is alive, allow it to revive it anyway.
+        AlreadyAlive,
+    }
+
+    impl fmt::Display for ReviveError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                Self::AlreadyAlive => write!(f, "the health system is already alive"),
+            }
+        }
+    }
+
+    impl std::error::Error for ReviveError {}
+
+    /// Why [`HealthSystem::spend_health`] refused to spend health.
+    /// The system is left untouched.
+    /// # Fields
+    /// * `available`: The health that could be spent, `0` for dead or downed systems.
+    /// * `cost`: The health asked for.
+    #[derive(Debug, PartialEq, Copy, Clone)]
+    pub struct NotEnoughHealth<T: HealthNum = f32> {
+        pub available: T,
+        pub cost: T,
+    }
+
+    impl<T: HealthNum + fmt::Display> fmt::Display for NotEnoughHealth<T> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                f,
+                "cannot spend {} health, only {} is available",
+                self.cost, self.available
+            )
+        }
     }
 
+    impl<T: HealthNum + fmt::Display> std::error::Error for NotEnoughHealth<T> {}
+
     /// Health System struct, the core of the entire crate.
+    /// The health is stored in any [`HealthNum`], [`HealthSystem`] stores it in `f32` and is the one
+    /// read by the [`HealthSystemPlugin`](crate::HealthSystemPlugin). Other backings have the same methods
+    /// but are not read by the plugin, eg: for turn based games with integer health, see [`HealthSystemI32`],
+    /// or `HealthSystemFixed` with the `fixed` feature.
     /// # Fields
     /// * `health`: The current health of the system.
     /// * `max_health`: The max health of the system.
     /// * `system_state`: The current state of the system.
-    /// * `system_modifier`: Modifier to this system, eg: Invincible,...
+    /// * `system_modifiers`: Modifiers to this system, eg: Invincible,...
     /// # Examples
     /// ```no_run
     /// use bevy::prelude::*;
@@ -81,21 +723,144 @@ pub mod health_system {
     /// fn get_player_health_system(query:Query<&HealthSystem>) {todo!();}
     ///
     ///```
-    #[derive(Debug, Component)]
-    pub struct HealthSystem {
-        health: f32,
-        max_health: f32,
+    /// Every component of this crate is registered for reflection by the [`HealthSystemPlugin`](crate::HealthSystemPlugin),
+    /// author your enemies as scenes: spawning one restores the current health, not only the max health.
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy::scene::serde::SceneDeserializer;
+    /// use bevy_health_system::{Armor, HealthSystem, HealthSystemPlugin};
+    /// use serde::de::DeserializeSeed;
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+    ///
+    /// let goblin = r#"(
+    ///     resources: {},
+    ///     entities: {
+    ///         0: (
+    ///             components: {
+    ///                 "bevy_health_system::health_system::Health": (
+    ///                     health: 37.5,
+    ///                     max_health: 100.0,
+    ///                 ),
+    ///                 "bevy_health_system::armor::Armor": (
+    ///                     flat_reduction: 2.0,
+    ///                     percent_reduction: 0.0,
+    ///                 ),
+    ///             },
+    ///         ),
+    ///     },
+    /// )"#;
+    /// let registry = app.world.resource::<AppTypeRegistry>().clone();
+    /// let mut deserializer = ron::de::Deserializer::from_str(goblin).unwrap();
+    /// let scene = SceneDeserializer { type_registry: &registry.read() }
+    ///     .deserialize(&mut deserializer)
+    ///     .unwrap();
+    /// scene.write_to_world(&mut app.world, &mut Default::default()).unwrap();
+    /// app.update();
+    ///
+    /// let mut query = app.world.query::<(&HealthSystem, &Armor)>();
+    /// let (health_system, armor) = query.single(&app.world);
+    /// assert_eq!(37.5, health_system.get_health());
+    /// assert_eq!(100.0, health_system.get_health_max());
+    /// assert!(health_system.is_alive());
+    /// assert_eq!(2.0, armor.flat_reduction);
+    ///
+    /// // Saving the world as a scene keeps the exact health too.
+    /// let mut builder = DynamicSceneBuilder::from_world(&app.world);
+    /// builder.extract_entities(app.world.iter_entities().map(|entity| entity.id()));
+    /// let saved = builder.build().serialize_ron(&registry).unwrap();
+    /// assert!(saved.contains("health: 37.5"));
+    /// ```
+    /// Systems are plain values too, eg: for an undo stack.
+    /// ```
+    /// use bevy_health_system::HealthSystem;
+    ///
+    /// let mut health_system = HealthSystem::new(100.0);
+    /// let mut undo = vec![health_system.clone()];
+    /// health_system.deal_damage(30.0, false);
+    /// assert_ne!(undo[0], health_system);
+    ///
+    /// health_system = undo.pop().unwrap();
+    /// assert_eq!(HealthSystem::new(100.0), health_system);
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Component, Reflect)]
+    #[reflect(Component, Hash, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct Health<T: HealthNum = f32> {
+        health: T,
+        max_health: T,
         system_state: HealthSystemState,
-        system_modifier: HealthSystemModifier,
+        system_modifiers: HealthSystemModifiers,
+        #[cfg_attr(feature = "serde", serde(default))]
+        death_cause: Option<DamageCause>,
+    }
+
+    /// The health system read by the [`HealthSystemPlugin`](crate::HealthSystemPlugin), its health is an `f32`.
+    pub type HealthSystem = Health<f32>;
+
+    /// A health system whose health is an `f64`, eg: for idle games with huge numbers.
+    pub type HealthSystemF64 = Health<f64>;
+
+    /// A health system whose health is an `i32`, eg: for old-school RPGs without float artifacts like `99.99999`.
+    pub type HealthSystemI32 = Health<i32>;
+
+    /// A health system whose health is a [`Fixed`](crate::fixed_point::Fixed) point number,
+    /// eg: for a lockstep simulation of your own that must not use floats.
+    #[cfg(feature = "fixed")]
+    pub type HealthSystemFixed = Health<crate::fixed_point::Fixed>;
+
+    /// A system of `100.0` max health, used when a system is built through reflection, eg: by the inspector.
+    impl<T: HealthNum> Default for Health<T> {
+        fn default() -> Self {
+            Self::new(T::from_f32(100.0))
+        }
+    }
+
+    /// Hash the bits of the health, eg: for the checksums of rollback netcode.
+    impl<T: HealthNum> Hash for Health<T> {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.health.hash_bits(state);
+            self.max_health.hash_bits(state);
+            self.system_state.hash(state);
+            self.system_modifiers.hash(state);
+            self.death_cause.hash(state);
+        }
+    }
+
+    /// Show the health out of the max health, eg: "75 / 100", negative health is shown as `0`.
+    /// The precision applies to both, eg: `format!("{health_system:.1}")` shows "75.0 / 100.0".
+    /// # Example
+    /// ```
+    /// use bevy_health_system::HealthSystem;
+    ///
+    /// let mut health_system = HealthSystem::new(100.0);
+    /// health_system.deal_damage(25.0, false);
+    /// assert_eq!("75 / 100", health_system.to_string());
+    ///
+    /// health_system.deal_damage(0.25, false);
+    /// assert_eq!("75 / 100", format!("{health_system:.0}"));
+    /// assert_eq!("74.8 / 100.0", format!("{health_system:.1}"));
+    /// ```
+    impl<T: HealthNum + fmt::Display> fmt::Display for Health<T> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let health = max(self.health, T::ZERO);
+            match f.precision() {
+                Some(precision) => {
+                    write!(f, "{health:.precision$} / {:.precision$}", self.max_health)
+                }
+                None => write!(f, "{health} / {}", self.max_health),
+            }
+        }
     }
 
-    impl HealthSystem {
+    impl<T: HealthNum> Health<T> {
         /// Create a new health system
         /// # Arguments
         /// * `max_health`: The max health of the health system, __health cannot exceed this value__
-        pub fn new(max_health: f32) -> Self {
+        pub fn new(max_health: T) -> Self {
             let health_system_state = {
-                if max_health < 0.0 {
+                if max_health < T::ZERO {
                     HealthSystemState::DEAD
                 } else {
                     HealthSystemState::default()
@@ -106,10 +871,81 @@ pub mod health_system {
                 health: max_health,
                 max_health,
                 system_state: health_system_state,
-                system_modifier: HealthSystemModifier::NONE,
+                system_modifiers: HealthSystemModifiers::default(),
+                death_cause: None,
             }
         }
 
+        /// Create a health system from every one of its parts.
+        /// Useful for save systems that need to restore a system exactly as it was.
+        /// # Arguments
+        /// * `health`: The current health of the system.
+        /// * `max_health`: The max health of the system.
+        /// * `state`: The current state of the system.
+        /// * `modifiers`: The current modifiers of the system, a single [`HealthSystemModifier`] works too.
+        /// # Example
+        /// ```
+        /// use bevy_health_system::{HealthSystem, HealthSystemModifier, HealthSystemState};
+        ///
+        /// let health_system = HealthSystem::from_parts(
+        ///     25.0,
+        ///     100.0,
+        ///     HealthSystemState::ALIVE,
+        ///     HealthSystemModifier::INVINCIBLE,
+        /// );
+        /// assert_eq!(25.0, health_system.get_health());
+        /// assert_eq!(100.0, health_system.get_health_max());
+        /// assert_eq!(HealthSystemModifier::INVINCIBLE, health_system.get_modifier());
+        /// ```
+        pub fn from_parts(
+            health: T,
+            max_health: T,
+            state: HealthSystemState,
+            modifiers: impl Into<HealthSystemModifiers>,
+        ) -> Self {
+            Self {
+                health,
+                max_health,
+                system_state: state,
+                system_modifiers: modifiers.into(),
+                death_cause: None,
+            }
+        }
+
+        /// Set why the system died, eg: to restore a system killed by [`HealthSystem::kill_system_with_cause`]
+        /// along with [`HealthSystem::from_parts`].
+        /// # Example
+        /// ```
+        /// use bevy_health_system::{DamageCause, HealthSystem, HealthSystemModifiers, HealthSystemState};
+        ///
+        /// let health_system =
+        ///     HealthSystem::from_parts(0.0, 100.0, HealthSystemState::DEAD, HealthSystemModifiers::default())
+        ///         .with_death_cause(Some(DamageCause::FALL));
+        /// assert_eq!(Some(&DamageCause::FALL), health_system.get_death_cause());
+        /// ```
+        pub fn with_death_cause(mut self, cause: Option<DamageCause>) -> Self {
+            self.death_cause = cause;
+            self
+        }
+
+        /// Returns the current state of the system.
+        /// # Example
+        /// ```
+        /// use bevy_health_system::{HealthSystem, HealthSystemState};
+        ///
+        /// let health_system = HealthSystem::new(100.0);
+        /// assert_eq!(HealthSystemState::ALIVE, health_system.get_state());
+        /// ```
+        pub fn get_state(&self) -> HealthSystemState {
+            self.system_state
+        }
+
+        /// Returns why the system was killed by [`HealthSystem::kill_system_with_cause`], if it is dead from it.
+        /// Deaths from damage carry their cause in the [`DeathEvent`](crate::DeathEvent) instead.
+        pub fn get_death_cause(&self) -> Option<&DamageCause> {
+            self.death_cause.as_ref()
+        }
+
         /// Returns the current health of the system.
         /// # Examples
         /// ```no_run
@@ -122,7 +958,7 @@ pub mod health_system {
         ///     assert_eq!(100.0, health_system.get_health_max());
         /// }
         /// ```
-        pub fn get_health(&self) -> f32 {
+        pub fn get_health(&self) -> T {
             self.health
         }
         /// Returns the current max health of the system.
@@ -137,7 +973,7 @@ pub mod health_system {
         ///     assert_eq!(100.0, health_system.get_health());
         /// }
         /// ```
-        pub fn get_health_max(&self) -> f32 {
+        pub fn get_health_max(&self) -> T {
             self.max_health
         }
         /// Returns true if the system is dead, else false
@@ -159,6 +995,17 @@ pub mod health_system {
         pub fn is_dead(&self) -> bool {
             self.system_state == HealthSystemState::DEAD
         }
+
+        /// Returns true if the system is downed, else false.
+        /// A downed system is not dead, see [`HealthSystemState::DOWNED`].
+        pub fn is_downed(&self) -> bool {
+            self.system_state == HealthSystemState::DOWNED
+        }
+
+        /// Returns true if the system is alive, neither dead nor downed.
+        pub fn is_alive(&self) -> bool {
+            self.system_state == HealthSystemState::ALIVE
+        }
         /// Returns the normalized health of the system.
         /// this means: `health/max_health`
         /// # Example
@@ -174,7 +1021,28 @@ pub mod health_system {
         /// }
         /// ```
         pub fn get_health_normalized(&self) -> f32 {
-            self.health / self.max_health
+            self.health.ratio(self.max_health)
+        }
+
+        /// Returns the health as a [`Fraction`] of max health, like [`HealthSystem::get_health_normalized`].
+        pub fn get_health_fraction(&self) -> Fraction {
+            Fraction::new(self.get_health_normalized())
+        }
+
+        /// Returns the health as a percentage of max health, eg: "75.0%" with a precision of `1`.
+        /// # Arguments
+        /// * `precision`: The number of decimals shown.
+        /// # Example
+        /// ```
+        /// use bevy_health_system::HealthSystem;
+        ///
+        /// let mut health_system = HealthSystem::new(200.0);
+        /// health_system.deal_damage(50.0, false);
+        /// assert_eq!("75.0%", health_system.format_percent(1));
+        /// assert_eq!("75%", health_system.format_percent(0));
+        /// ```
+        pub fn format_percent(&self, precision: usize) -> String {
+            format!("{:.precision$}", self.get_health_fraction())
         }
 
         /// Deal damage to the system.
@@ -193,16 +1061,42 @@ pub mod health_system {
         ///     assert_eq!(90.0, health_system.get_health());
         /// }
         /// ```
-        pub fn deal_damage(&mut self, amount: f32, force: bool) {
-            if self.current_modifier_is(HealthSystemModifier::INVINCIBLE) && !force {
+        pub fn deal_damage(&mut self, amount: T, force: bool) {
+            if self.has_modifier(HealthSystemModifier::INVINCIBLE) && !force {
                 return;
             }
-            self.health -= amount;
+            self.health = self.health.sub(amount);
             self.check_is_dead();
         }
 
-        /// Kill the health system.
-        // # Arguments
+        /// Deal damage to the system without bringing its health below `floor`.
+        /// Health already at or below `floor` is left untouched.
+        /// # Arguments
+        /// * `amount`: The amount of damage dealt to this system.
+        /// * `floor`: The lowest health the damage can leave, above `0.0` for the system to survive.
+        /// * `force`: Ignore any modifier that prevents dealing damages to this system.
+        /// # Example
+        /// ```
+        /// use bevy_health_system::HealthSystem;
+        ///
+        /// let mut health_system = HealthSystem::new(100.0);
+        /// health_system.deal_damage_non_lethal(500.0, 1.0, false);
+        /// assert_eq!(1.0, health_system.get_health());
+        /// assert!(health_system.is_alive());
+        /// ```
+        pub fn deal_damage_non_lethal(&mut self, amount: T, floor: T, force: bool) {
+            if self.has_modifier(HealthSystemModifier::INVINCIBLE) && !force {
+                return;
+            }
+            if self.health <= floor {
+                return;
+            }
+            self.health = max(self.health.sub(amount), floor);
+            self.check_is_dead();
+        }
+
+        /// Kill the health system, with [`DamageCause::SCRIPTED_KILL`] as the cause of its death.
+        /// # Arguments
         /// * `force`: Ignore any modifier that prevents killing this system.
         /// # Example
         /// ```no_run
@@ -216,52 +1110,167 @@ pub mod health_system {
         /// }
         /// ```
         pub fn kill_system(&mut self, force: bool) {
-            if self.current_modifier_is(HealthSystemModifier::INVINCIBLE) && !force {
+            self.kill_system_with_cause(force, DamageCause::SCRIPTED_KILL);
+        }
+
+        /// Kill the health system, the cause is sent with the [`DeathEvent`](crate::DeathEvent) of the
+        /// [`HealthSystemPlugin`](crate::HealthSystemPlugin), eg: for a death screen.
+        /// # Arguments
+        /// * `force`: Ignore any modifier that prevents killing this system.
+        /// * `cause`: What killed the system, eg: [`DamageCause::OUT_OF_BOUNDS`] or a custom tag.
+        /// # Example
+        /// ```
+        /// use bevy::prelude::*;
+        /// use bevy_health_system::{DamageCause, DeathEvent, HealthSystem, HealthSystemPlugin};
+        ///
+        /// let mut app = App::new();
+        /// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+        ///
+        /// let player = app.world.spawn(HealthSystem::new(100.0)).id();
+        /// app.update();
+        /// app.world
+        ///     .get_mut::<HealthSystem>(player)
+        ///     .unwrap()
+        ///     .kill_system_with_cause(true, DamageCause::OUT_OF_BOUNDS);
+        /// app.update();
+        ///
+        /// let events = app.world.resource::<Events<DeathEvent>>();
+        /// let death = events.iter_current_update_events().next().unwrap();
+        /// assert_eq!(Some(DamageCause::OUT_OF_BOUNDS), death.cause);
+        /// ```
+        pub fn kill_system_with_cause(&mut self, force: bool, cause: impl Into<DamageCause>) {
+            if self.has_modifier(HealthSystemModifier::INVINCIBLE) && !force {
                 return;
             }
-            self.health = 0.0;
+            self.health = T::ZERO;
             self.system_state = HealthSystemState::DEAD;
+            self.death_cause = Some(cause.into());
+        }
+
+        /// Down the health system, it stays at 0 health until revived or killed.
+        /// Dead systems can't be downed.
+        /// # Example
+        /// ```
+        /// use bevy_health_system::{HealthSystem, HealthSystemReviveHealType};
+        ///
+        /// let mut health_system = HealthSystem::new(100.0);
+        /// health_system.down_system();
+        /// assert!(health_system.is_downed());
+        /// assert!(!health_system.is_dead());
+        ///
+        /// health_system.revive_system(HealthSystemReviveHealType::HealTo(30.0), false).unwrap();
+        /// assert!(health_system.is_alive());
+        /// assert_eq!(30.0, health_system.get_health());
+        /// ```
+        pub fn down_system(&mut self) {
+            if self.is_dead() {
+                return;
+            }
+            self.health = T::ZERO;
+            self.system_state = HealthSystemState::DOWNED;
         }
 
-        /// - Revive the health system, heal to full.
-        /// - Also returns the the amount of health overflowed after revival.
+        /// - Revive a dead or downed health system.
+        /// - Returns the health it was revived with and the amount of health overflowed after revival.
         /// # Arguments
         /// * `revive_type`: The type of the revival.
+        /// * `allow_alive`: Revive the system even if it is alive, setting its health like a dead one.
+        /// # Errors
+        /// [`ReviveError::AlreadyAlive`] if the system is alive and `allow_alive` is false, the system is left untouched.
         /// # Example
         /// ```
-        /// use bevy::prelude::*;
-        /// use bevy_health_system::HealthSystem;
-        /// use bevy_health_system::HealthSystemReviveHealType;
+        /// use bevy_health_system::{HealthSystem, HealthSystemReviveHealType, ReviveError, ReviveOutcome};
         ///
-        /// // Assuming health system was initialized with 100 health
-        /// fn get_health_system(mut query: Query<&mut HealthSystem>) {
-        ///     let mut health_system = query.get_single_mut().unwrap();
-        ///     health_system.kill_system(false);
-        ///     assert_eq!(true, health_system.is_dead());
+        /// let mut health_system = HealthSystem::new(100.0);
+        /// health_system.deal_damage(60.0, false);
+        /// assert_eq!(
+        ///     Err(ReviveError::AlreadyAlive),
+        ///     health_system.revive_system(HealthSystemReviveHealType::HealFull, false),
+        /// );
+        /// assert_eq!(40.0, health_system.get_health());
         ///
-        ///     health_system.revive_system(HealthSystemReviveHealType::HealFull);
-        ///     assert_eq!(false, health_system.is_dead());
-        ///     assert_eq!(100.0, health_system.get_health());
-        /// }
+        /// health_system.kill_system(false);
+        /// assert_eq!(
+        ///     Ok(ReviveOutcome { health: 100.0, overflow: 20.0 }),
+        ///     health_system.revive_system(HealthSystemReviveHealType::HealTo(120.0), false),
+        /// );
+        /// assert!(health_system.is_alive());
+        ///
+        /// // Explicitly allowed on living systems.
+        /// health_system.deal_damage(50.0, false);
+        /// health_system.revive_system(HealthSystemReviveHealType::HealFull, true).unwrap();
+        /// assert_eq!(100.0, health_system.get_health());
         /// ```
-        pub fn revive_system(&mut self, revive_type: HealthSystemReviveHealType) -> f32 {
+        pub fn revive_system(
+            &mut self,
+            revive_type: HealthSystemReviveHealType,
+            allow_alive: bool,
+        ) -> Result<ReviveOutcome<T>, ReviveError> {
+            if self.is_alive() && !allow_alive {
+                return Err(ReviveError::AlreadyAlive);
+            }
             self.system_state = HealthSystemState::ALIVE;
-            let mut overflow_amount = 0.0;
+            self.death_cause = None;
+            let mut overflow_amount = T::ZERO;
 
             match revive_type {
                 HealthSystemReviveHealType::HealFull => self.heal_full(),
                 HealthSystemReviveHealType::HealPercentage(value) => {
-                    let new_health = self.max_health * (value / 100.0);
+                    let new_health = self.max_health.scale(value.ratio(100.0));
                     self.set_health(new_health);
                 }
+                HealthSystemReviveHealType::HealFraction(fraction) => {
+                    self.set_health(self.max_health.scale(fraction.get()));
+                }
                 HealthSystemReviveHealType::HealTo(value) => {
-                    overflow_amount = self.set_health(value);
+                    overflow_amount = self.set_health(T::from_f32(value));
                 }
             }
 
-            overflow_amount
+            Ok(ReviveOutcome {
+                health: self.health,
+                overflow: overflow_amount,
+            })
+        }
+
+        /// - Revive the health system, like [`HealthSystem::revive_system`].
+        /// - Returns the outcome of the revival and an [`Invulnerability`](crate::Invulnerability)
+        ///   lasting `duration`, insert it on the entity so it doesn't get killed again right away.
+        /// # Arguments
+        /// * `revive_type`: The type of the revival.
+        /// * `duration`: How long the system stays invulnerable after revival.
+        /// * `allow_alive`: Revive the system even if it is alive.
+        /// # Errors
+        /// [`ReviveError::AlreadyAlive`] if the system is alive and `allow_alive` is false.
+        /// # Example
+        /// ```no_run
+        /// use std::time::Duration;
+        /// use bevy::prelude::*;
+        /// use bevy_health_system::{HealthSystem, HealthSystemReviveHealType};
+        ///
+        /// fn revive_players(mut commands: Commands, mut query: Query<(Entity, &mut HealthSystem)>) {
+        ///     for (entity, mut health_system) in &mut query {
+        ///         if let Ok((_, invulnerability)) = health_system.revive_system_invulnerable(
+        ///             HealthSystemReviveHealType::HealFull,
+        ///             Duration::from_secs(3),
+        ///             false,
+        ///         ) {
+        ///             commands.entity(entity).insert(invulnerability);
+        ///         }
+        ///     }
+        /// }
+        /// ```
+        pub fn revive_system_invulnerable(
+            &mut self,
+            revive_type: HealthSystemReviveHealType,
+            duration: Duration,
+            allow_alive: bool,
+        ) -> Result<(ReviveOutcome<T>, crate::Invulnerability), ReviveError> {
+            let outcome = self.revive_system(revive_type, allow_alive)?;
+
+            Ok((outcome, crate::Invulnerability::new(duration)))
         }
-        /// - Heal the system.
+        /// - Heal the system, unless it has the [`HealthSystemModifier::HEAL_BLOCKED`] modifier.
         /// - Returns the amount of health that overflowed after heal.
         /// # Arguments
         /// * `amount`: The amount of damage dealt to this system.
@@ -281,11 +1290,15 @@ pub mod health_system {
         ///     assert_eq!(10.0, over_flow_amount);
         /// }
         /// ```
-        pub fn heal(&mut self, amount: f32) -> f32 {
-            let overflow_value = self.max_health - amount;
-            self.health += amount;
+        pub fn heal(&mut self, amount: T) -> T {
+            if self.has_modifier(HealthSystemModifier::HEAL_BLOCKED) {
+                return T::ZERO;
+            }
 
-            overflow_value
+            let new_health = self.health.add(amount);
+            self.health = min(new_health, self.max_health);
+
+            max(new_health.sub(self.max_health), T::ZERO)
         }
 
         /// Heal the system fully.
@@ -328,20 +1341,65 @@ pub mod health_system {
         ///     assert_eq!(100.0, over_flow_amount);
         /// }
         /// ```
-        pub fn set_health(&mut self, value: f32) -> f32 {
+        pub fn set_health(&mut self, value: T) -> T {
             let mut final_value = value;
 
-            if value < 0.0 {
-                final_value = 0.0;
+            if value < T::ZERO {
+                final_value = T::ZERO;
             } else if value > self.max_health {
                 final_value = self.max_health;
             }
 
-            self.check_is_dead();
             self.health = final_value;
+            self.check_is_dead();
 
             // overflow
-            self.max_health - value
+            max(value.sub(self.max_health), T::ZERO)
+        }
+
+        /// Spend health, eg: for an ability that costs health, only if enough of it is left.
+        /// Spending is not damage: nothing mitigates it and no modifier prevents it.
+        /// Spending down to `0` kills the system, keep `min_remaining` above it to never kill.
+        /// # Arguments
+        /// * `amount`: The health spent.
+        /// * `min_remaining`: The lowest health the system can be left with.
+        /// # Errors
+        /// [`NotEnoughHealth`] if the system is not alive, `amount` is not finite,
+        /// or spending it would leave less than `min_remaining`. Nothing is spent.
+        /// # Example
+        /// ```
+        /// use bevy_health_system::{HealthSystem, NotEnoughHealth};
+        ///
+        /// let mut health_system = HealthSystem::new(100.0);
+        /// assert_eq!(Ok(()), health_system.spend_health(30.0, 1.0));
+        /// assert_eq!(70.0, health_system.get_health());
+        ///
+        /// assert_eq!(
+        ///     Err(NotEnoughHealth { available: 69.0, cost: 80.0 }),
+        ///     health_system.spend_health(80.0, 1.0),
+        /// );
+        /// assert_eq!(70.0, health_system.get_health());
+        /// ```
+        pub fn spend_health(
+            &mut self,
+            amount: T,
+            min_remaining: T,
+        ) -> Result<(), NotEnoughHealth<T>> {
+            let available = if self.is_alive() {
+                max(self.health.sub(min_remaining), T::ZERO)
+            } else {
+                T::ZERO
+            };
+            if !self.is_alive() || !amount.is_finite() || amount > available {
+                return Err(NotEnoughHealth {
+                    available,
+                    cost: amount,
+                });
+            }
+
+            self.health = self.health.sub(max(amount, T::ZERO));
+            self.check_is_dead();
+            Ok(())
         }
 
         /// Set the max health of the system
@@ -364,11 +1422,11 @@ pub mod health_system {
         ///     assert_eq!(400.0, health_system.get_health());
         ///     assert_eq!(400.0, health_system.get_health_max());
         /// }
-        pub fn set_health_max(&mut self, value: f32, heal_system: bool) {
+        pub fn set_health_max(&mut self, value: T, heal_system: bool) {
             let mut final_value = value;
 
-            if value < 0.0 {
-                final_value = 0.0;
+            if value < T::ZERO {
+                final_value = T::ZERO;
             }
 
             self.max_health = final_value;
@@ -378,7 +1436,101 @@ pub mod health_system {
 
             self.check_is_dead();
         }
-        /// Set a new modifier for this system
+
+        /// Set the max health of the system, moving its current health according to `policy`.
+        /// The health of a system that is not alive is left untouched.
+        /// # Arguments
+        /// * `value`: This system new max health value.
+        /// * `policy`: What happens to the current health.
+        /// # Example
+        /// ```
+        /// use bevy_health_system::{HealthSystem, MaxHealthChangePolicy};
+        ///
+        /// let mut health_system = HealthSystem::new(100.0);
+        /// health_system.set_health(50.0);
+        /// health_system.set_health_max_with_policy(200.0, MaxHealthChangePolicy::ScaleProportionally);
+        /// assert_eq!(100.0, health_system.get_health());
+        ///
+        /// health_system.set_health_max_with_policy(80.0, MaxHealthChangePolicy::ClampToNewMax);
+        /// assert_eq!(80.0, health_system.get_health());
+        /// ```
+        pub fn set_health_max_with_policy(&mut self, value: T, policy: MaxHealthChangePolicy) {
+            let health = self.health;
+            let fraction = if self.max_health > T::ZERO {
+                self.health.ratio(self.max_health)
+            } else {
+                1.0
+            };
+            self.set_health_max(value, false);
+            if !self.is_alive() {
+                return;
+            }
+
+            match policy {
+                MaxHealthChangePolicy::KeepCurrent => {}
+                MaxHealthChangePolicy::ScaleProportionally => {
+                    self.set_health(self.max_health.scale(fraction));
+                }
+                MaxHealthChangePolicy::ClampToNewMax => {
+                    self.set_health(min(health, self.max_health));
+                }
+                MaxHealthChangePolicy::HealToFull => self.heal_full(),
+            }
+        }
+
+        /// Deal damage to the system like [`HealthSystem::deal_damage`], unless the amount is NaN or infinite.
+        /// # Errors
+        /// [`HealthError::NotFinite`] if `amount` is NaN or infinite, the system is left untouched.
+        /// # Example
+        /// ```
+        /// use bevy_health_system::{HealthError, HealthSystem};
+        ///
+        /// let mut health_system = HealthSystem::new(100.0);
+        /// assert!(health_system.try_deal_damage(f32::NAN, false).is_err());
+        /// assert_eq!(
+        ///     Err(HealthError::NotFinite { value: f32::INFINITY }),
+        ///     health_system.try_set_health_max(f32::INFINITY, false),
+        /// );
+        /// assert_eq!(100.0, health_system.get_health());
+        /// assert_eq!(100.0, health_system.get_health_max());
+        ///
+        /// assert_eq!(Ok(()), health_system.try_deal_damage(10.0, false));
+        /// assert_eq!(90.0, health_system.get_health());
+        /// ```
+        pub fn try_deal_damage(&mut self, amount: T, force: bool) -> Result<(), HealthError> {
+            self.deal_damage(finite(amount)?, force);
+            Ok(())
+        }
+
+        /// Heal the system like [`HealthSystem::heal`], unless the amount is NaN or infinite.
+        /// Returns the amount of health that overflowed after heal.
+        /// # Errors
+        /// [`HealthError::NotFinite`] if `amount` is NaN or infinite, the system is left untouched.
+        pub fn try_heal(&mut self, amount: T) -> Result<T, HealthError> {
+            Ok(self.heal(finite(amount)?))
+        }
+
+        /// Set the health of the system like [`HealthSystem::set_health`], unless the value is NaN or infinite.
+        /// Returns the health that overflowed.
+        /// # Errors
+        /// [`HealthError::NotFinite`] if `value` is NaN or infinite, the system is left untouched.
+        pub fn try_set_health(&mut self, value: T) -> Result<T, HealthError> {
+            Ok(self.set_health(finite(value)?))
+        }
+
+        /// Set the max health of the system like [`HealthSystem::set_health_max`], unless the value is NaN or infinite.
+        /// # Errors
+        /// [`HealthError::NotFinite`] if `value` is NaN or infinite, the system is left untouched.
+        pub fn try_set_health_max(
+            &mut self,
+            value: T,
+            heal_system: bool,
+        ) -> Result<(), HealthError> {
+            self.set_health_max(finite(value)?, heal_system);
+            Ok(())
+        }
+
+        /// Set a new modifier for this system, replacing every other modifier.
         /// # Arguments
         /// * `value`: This system new modifier.
         /// # Example
@@ -396,10 +1548,11 @@ pub mod health_system {
         /// }
         ///```
         pub fn set_modifier(&mut self, value: HealthSystemModifier) {
-            self.change_modifier(value);
+            self.change_modifiers(value.into());
         }
 
-        /// Returns the system current modifier
+        /// Returns the system current modifier.
+        /// If the system has many modifiers, only the first one is returned, see [`HealthSystem::get_modifiers`].
         /// # Example
         /// ```
         /// use bevy::prelude::*;
@@ -412,10 +1565,63 @@ pub mod health_system {
         /// }
         ///```
         pub fn get_modifier(&self) -> HealthSystemModifier {
-            self.system_modifier
+            self.system_modifiers
+                .iter()
+                .next()
+                .unwrap_or(HealthSystemModifier::NONE)
+        }
+
+        /// Returns every modifier of the system.
+        pub fn get_modifiers(&self) -> HealthSystemModifiers {
+            self.system_modifiers
+        }
+
+        /// Set the modifiers of the system, replacing every other modifier.
+        /// # Arguments
+        /// * `modifiers`: This system new modifiers.
+        pub fn set_modifiers(&mut self, modifiers: HealthSystemModifiers) {
+            self.change_modifiers(modifiers);
+        }
+
+        /// Add a modifier to the system, keeping the others.
+        /// # Arguments
+        /// * `modifier`: The modifier to add.
+        /// # Example
+        /// ```
+        /// use bevy_health_system::{HealthSystem, HealthSystemModifier};
+        ///
+        /// let mut health_system = HealthSystem::new(100.0);
+        /// health_system.add_modifier(HealthSystemModifier::INVINCIBLE);
+        /// assert!(health_system.has_modifier(HealthSystemModifier::INVINCIBLE));
+        ///
+        /// health_system.remove_modifier(HealthSystemModifier::INVINCIBLE);
+        /// assert!(health_system.has_modifier(HealthSystemModifier::NONE));
+        /// ```
+        pub fn add_modifier(&mut self, modifier: HealthSystemModifier) {
+            let mut modifiers = self.system_modifiers;
+            modifiers.insert(modifier);
+            self.change_modifiers(modifiers);
+        }
+
+        /// Remove a modifier from the system, keeping the others.
+        /// # Arguments
+        /// * `modifier`: The modifier to remove.
+        pub fn remove_modifier(&mut self, modifier: HealthSystemModifier) {
+            let mut modifiers = self.system_modifiers;
+            modifiers.remove(modifier);
+            self.change_modifiers(modifiers);
+        }
+
+        /// Returns true if the system has the modifier.
+        /// [`HealthSystemModifier::NONE`] means the system has no modifier at all.
+        /// # Arguments
+        /// * `modifier`: The modifier to check.
+        pub fn has_modifier(&self, modifier: HealthSystemModifier) -> bool {
+            self.system_modifiers.contains(modifier)
         }
 
         /// Returns whenever the current modifier is the specified modifier.
+        /// Same as [`HealthSystem::has_modifier`].
         /// # Arguments
         /// * `modifier`: The modifier to check.
         /// # Example
@@ -430,18 +1636,19 @@ pub mod health_system {
         /// }
         ///```
         pub fn current_modifier_is(&self, modifier: HealthSystemModifier) -> bool {
-            self.get_modifier() == modifier
+            self.has_modifier(modifier)
         }
 
-        fn change_modifier(&mut self, modifier: HealthSystemModifier) {
+        fn change_modifiers(&mut self, modifiers: HealthSystemModifiers) {
             if self.is_dead() {
                 return;
             }
-            self.system_modifier = modifier;
+            self.system_modifiers = modifiers;
         }
         fn check_is_dead(&mut self) {
-            if self.health <= 0.0 {
+            if self.health <= T::ZERO {
                 self.system_state = HealthSystemState::DEAD;
+                self.death_cause = None;
             }
         }
     }
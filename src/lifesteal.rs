@@ -0,0 +1,62 @@
+//! Lifesteal, healing the attacker for the damage it deals.
+use bevy::prelude::*;
+
+use crate::{DamageAppliedEvent, HealEvent};
+
+/// Heal this entity for a portion of the health lost by entities it damages.
+/// Only damage from a [`DamageEvent`](crate::DamageEvent) with this entity as its source counts,
+/// damage absorbed by shields, temporary health or armor is not stolen.
+/// # Fields
+/// * `ratio`: The portion of the damage converted into healing, eg: `0.25` heals a quarter of it.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, HealthSystem, HealthSystemPlugin, Lifesteal};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let mut vampire_health = HealthSystem::new(100.0);
+/// vampire_health.deal_damage(50.0, false);
+/// let vampire = app.world.spawn((vampire_health, Lifesteal::new(0.5))).id();
+/// let victim = app.world.spawn(HealthSystem::new(100.0)).id();
+///
+/// app.world.send_event(DamageEvent::new(victim, 20.0).with_source(vampire));
+/// app.update();
+///
+/// assert_eq!(60.0, app.world.get::<HealthSystem>(vampire).unwrap().get_health());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Lifesteal {
+    pub ratio: f32,
+}
+
+impl Lifesteal {
+    /// Create a new lifesteal.
+    /// # Arguments
+    /// * `ratio`: The portion of the damage converted into healing.
+    pub fn new(ratio: f32) -> Self {
+        Self { ratio }
+    }
+}
+
+pub(crate) fn apply_lifesteal(
+    mut damaged: EventReader<DamageAppliedEvent>,
+    attackers: Query<&Lifesteal>,
+    mut heal: EventWriter<HealEvent>,
+) {
+    for event in damaged.iter() {
+        let Some(source) = event.source.filter(|source| *source != event.target) else {
+            continue;
+        };
+        let Ok(lifesteal) = attackers.get(source) else {
+            continue;
+        };
+
+        let amount = event.amount * lifesteal.ratio;
+        if amount > 0.0 {
+            heal.send(HealEvent::new(source, amount));
+        }
+    }
+}
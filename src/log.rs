@@ -0,0 +1,253 @@
+//! Log of the damage, heals and deaths of a play session, with exports for balancing.
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+use bevy::prelude::*;
+
+use crate::{DamageAppliedEvent, DamageCause, DamageTypeRegistry, DeathEvent};
+
+/// What a [`CombatLogEntry`] records.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum CombatLogKind {
+    /// Damage applied from a [`DamageEvent`](crate::DamageEvent).
+    Damage,
+    /// Health restored by a [`HealEvent`](crate::HealEvent).
+    Heal,
+    /// A [`DeathEvent`].
+    Death,
+}
+
+impl CombatLogKind {
+    /// Returns the name of this kind, as exported.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CombatLogKind::Damage => "damage",
+            CombatLogKind::Heal => "heal",
+            CombatLogKind::Death => "death",
+        }
+    }
+}
+
+/// An entry of the [`CombatLog`].
+/// # Fields
+/// * `time`: When it happened, in seconds since startup.
+/// * `kind`: What happened.
+/// * `target`: The entity damaged, healed or killed.
+/// * `source`: The entity responsible, if any.
+/// * `amount`: The health lost or restored, `0.0` for deaths.
+/// * `damage_type`: The name of the damage type, its id if not registered, for damage only.
+/// * `cause`: What caused the damage or death, if known.
+#[derive(Debug, Clone)]
+pub struct CombatLogEntry {
+    pub time: f64,
+    pub kind: CombatLogKind,
+    pub target: Entity,
+    pub source: Option<Entity>,
+    pub amount: f32,
+    pub damage_type: Option<String>,
+    pub cause: Option<DamageCause>,
+}
+
+/// Record the damage, heals and deaths resolved by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) while enabled.
+/// The oldest entries are dropped once the log is full. Entities are exported as their [`Entity::to_bits`].
+/// # Fields
+/// * `enabled`: Whether new entries are recorded, the log is disabled by default.
+/// * `capacity`: The most entries kept.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{CombatLog, DamageEvent, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(CombatLog::new(100));
+///
+/// let entity = app.world.spawn(HealthSystem::new(100.0)).id();
+/// app.world.send_event(DamageEvent::new(entity, 10.0).with_cause("spikes"));
+/// app.update();
+///
+/// let log = app.world.resource::<CombatLog>();
+/// assert_eq!(1, log.len());
+/// let row = format!(",damage,{},,10,physical,spikes", entity.to_bits());
+/// assert!(log.to_csv().contains(&row));
+/// assert!(log.to_json().contains(r#""cause":"spikes""#));
+/// ```
+#[derive(Debug, Clone, Resource)]
+pub struct CombatLog {
+    pub enabled: bool,
+    pub capacity: usize,
+    entries: VecDeque<CombatLogEntry>,
+}
+
+impl Default for CombatLog {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            capacity: 1000,
+            entries: VecDeque::new(),
+        }
+    }
+}
+
+impl CombatLog {
+    /// Create an enabled log.
+    /// # Arguments
+    /// * `capacity`: The most entries kept.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            enabled: true,
+            capacity,
+            entries: VecDeque::new(),
+        }
+    }
+
+    /// Record an entry if the log is enabled, dropping the oldest entry if full.
+    pub fn push(&mut self, entry: CombatLogEntry) {
+        if !self.enabled || self.capacity == 0 {
+            return;
+        }
+        while self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(entry);
+    }
+
+    /// Returns every entry, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = &CombatLogEntry> {
+        self.entries.iter()
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if there is no entry.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Remove every entry.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Export the entries as a JSON array of objects.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("[");
+        for (index, entry) in self.entries.iter().enumerate() {
+            if index > 0 {
+                json.push(',');
+            }
+            let _ = write!(
+                json,
+                r#"{{"time":{},"kind":"{}","target":{},"source":{},"amount":{},"damage_type":{},"cause":{}}}"#,
+                entry.time,
+                entry.kind.as_str(),
+                entry.target.to_bits(),
+                entry
+                    .source
+                    .map_or("null".to_string(), |source| source.to_bits().to_string()),
+                entry.amount,
+                json_string(entry.damage_type.as_deref()),
+                json_string(entry.cause.as_ref().map(DamageCause::as_str)),
+            );
+        }
+        json.push(']');
+        json
+    }
+
+    /// Export the entries as CSV, with a header row.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("time,kind,target,source,amount,damage_type,cause\n");
+        for entry in &self.entries {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{},{}",
+                entry.time,
+                entry.kind.as_str(),
+                entry.target.to_bits(),
+                entry
+                    .source
+                    .map_or(String::new(), |source| source.to_bits().to_string()),
+                entry.amount,
+                csv_field(entry.damage_type.as_deref().unwrap_or_default()),
+                csv_field(entry.cause.as_ref().map_or("", DamageCause::as_str)),
+            );
+        }
+        csv
+    }
+}
+
+fn json_string(value: Option<&str>) -> String {
+    let Some(value) = value else {
+        return "null".to_string();
+    };
+
+    let mut json = String::from("\"");
+    for character in value.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            character if character.is_control() => {
+                let _ = write!(json, "\\u{:04x}", character as u32);
+            }
+            character => json.push(character),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub(crate) fn record_combat_log(
+    time: Res<Time>,
+    registry: Res<DamageTypeRegistry>,
+    mut log: ResMut<CombatLog>,
+    mut damaged: EventReader<DamageAppliedEvent>,
+    mut deaths: EventReader<DeathEvent>,
+) {
+    if !log.enabled {
+        damaged.clear();
+        deaths.clear();
+        return;
+    }
+
+    let now = time.elapsed_seconds_f64();
+    for event in damaged.iter() {
+        let damage_type = registry
+            .name(event.damage_type)
+            .map_or(event.damage_type.id().to_string(), str::to_string);
+        log.push(CombatLogEntry {
+            time: now,
+            kind: CombatLogKind::Damage,
+            target: event.target,
+            source: event.source,
+            amount: event.breakdown.applied,
+            damage_type: Some(damage_type),
+            cause: event.cause.clone(),
+        });
+    }
+
+    for event in deaths.iter() {
+        log.push(CombatLogEntry {
+            time: now,
+            kind: CombatLogKind::Death,
+            target: event.entity,
+            source: event.source,
+            amount: 0.0,
+            damage_type: None,
+            cause: event.cause.clone(),
+        });
+    }
+}
@@ -0,0 +1,94 @@
+//! Loot dropped by entities when they die.
+use std::borrow::Cow;
+
+use bevy::prelude::*;
+
+use crate::{DeathEvent, HealthRng};
+
+/// Send a [`LootDropped`] event when this entity dies, for your game to spawn the items of the loot table.
+/// The drop is rolled with the [`HealthRng`], the default loot never drops.
+/// # Fields
+/// * `table`: The name of the loot table, eg: `"goblin"`.
+/// * `chance`: The chance to drop the loot, from 0 to 1.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, HealthSystem, HealthSystemPlugin, Loot, LootDropped};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let killer = app.world.spawn_empty().id();
+/// let entity = app
+///     .world
+///     .spawn((HealthSystem::new(10.0), Loot::new("goblin"), GlobalTransform::from_xyz(1.0, 2.0, 3.0)))
+///     .id();
+/// app.world.send_event(DamageEvent::new(entity, 10.0).with_source(killer));
+/// app.update();
+///
+/// let events = app.world.resource::<Events<LootDropped>>();
+/// let loot = events.iter_current_update_events().next().unwrap();
+/// assert_eq!("goblin", loot.table);
+/// assert_eq!(Some(killer), loot.killer);
+/// assert_eq!(Some(Vec3::new(1.0, 2.0, 3.0)), loot.translation);
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Loot {
+    pub table: Cow<'static, str>,
+    pub chance: f32,
+}
+
+impl Loot {
+    /// Create a new loot, always dropped.
+    /// # Arguments
+    /// * `table`: The name of the loot table.
+    pub fn new(table: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            table: table.into(),
+            chance: 1.0,
+        }
+    }
+
+    /// Set the chance to drop the loot, from 0 to 1.
+    pub fn with_chance(mut self, chance: f32) -> Self {
+        self.chance = chance;
+        self
+    }
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when an entity with [`Loot`] dies and the drop succeeds.
+/// # Fields
+/// * `entity`: The entity that died, it may be despawned by its [`DeathBehavior`](crate::DeathBehavior).
+/// * `table`: The loot table of the entity.
+/// * `killer`: The source of the killing blow, if any.
+/// * `translation`: Where the entity died, if it has a [`GlobalTransform`].
+#[derive(Debug, Clone, Event)]
+pub struct LootDropped {
+    pub entity: Entity,
+    pub table: Cow<'static, str>,
+    pub killer: Option<Entity>,
+    pub translation: Option<Vec3>,
+}
+
+pub(crate) fn drop_loot(
+    mut deaths: EventReader<DeathEvent>,
+    mut rng: ResMut<HealthRng>,
+    query: Query<(&Loot, Option<&GlobalTransform>)>,
+    mut dropped: EventWriter<LootDropped>,
+) {
+    for death in deaths.iter() {
+        let Ok((loot, transform)) = query.get(death.entity) else {
+            continue;
+        };
+        if !rng.chance(loot.chance) {
+            continue;
+        }
+        dropped.send(LootDropped {
+            entity: death.entity,
+            table: loot.table.clone(),
+            killer: death.source,
+            translation: transform.map(|transform| transform.translation()),
+        });
+    }
+}
@@ -0,0 +1,143 @@
+//! Max health made out of a base value and a stack of bonuses, eg: from buffs or equipment.
+use std::borrow::Cow;
+
+use bevy::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{HealthSystem, MaxHealthChangePolicy};
+
+/// A bonus to the max health of an entity, see [`MaxHealthModifiers`].
+#[derive(Debug, PartialEq, Copy, Clone, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MaxHealthBonus {
+    /// Added to the base max health.
+    Flat(f32),
+    /// Fraction of the max health added once flat bonuses are summed, eg: `0.1` for +10%.
+    Percent(f32),
+}
+
+/// The max health of the [`HealthSystem`] of an entity, computed from a base value and bonuses identified by ids.
+/// The plugin sets the max health every time the stack changes, the current health follows the `policy`.
+/// Percent bonuses stack additively: `(base + flat bonuses) * (1 + percent bonuses)`.
+/// # Fields
+/// * `base`: The max health without any bonus.
+/// * `policy`: What happens to the current health when the max health changes.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     HealthSystem, HealthSystemPlugin, MaxHealthBonus, MaxHealthChangePolicy, MaxHealthModifiers,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let mut health_system = HealthSystem::new(100.0);
+/// health_system.set_health(50.0);
+/// let entity = app
+///     .world
+///     .spawn((
+///         health_system,
+///         MaxHealthModifiers::new(100.0).with_policy(MaxHealthChangePolicy::ScaleProportionally),
+///     ))
+///     .id();
+///
+/// let mut modifiers = app.world.get_mut::<MaxHealthModifiers>(entity).unwrap();
+/// modifiers.insert("ring", MaxHealthBonus::Flat(20.0));
+/// modifiers.insert("amulet", MaxHealthBonus::Percent(0.5));
+/// app.update();
+///
+/// let health_system = app.world.get::<HealthSystem>(entity).unwrap();
+/// assert_eq!(180.0, health_system.get_health_max());
+/// assert_eq!(90.0, health_system.get_health());
+/// ```
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MaxHealthModifiers {
+    pub base: f32,
+    pub policy: MaxHealthChangePolicy,
+    bonuses: Vec<(Cow<'static, str>, MaxHealthBonus)>,
+}
+
+/// A base max health of `100.0`, as the default [`HealthSystem`](crate::HealthSystem).
+impl Default for MaxHealthModifiers {
+    fn default() -> Self {
+        Self::new(100.0)
+    }
+}
+
+impl MaxHealthModifiers {
+    /// Create a stack without bonuses.
+    /// # Arguments
+    /// * `base`: The max health without any bonus.
+    pub fn new(base: f32) -> Self {
+        Self {
+            base,
+            policy: MaxHealthChangePolicy::default(),
+            bonuses: Vec::new(),
+        }
+    }
+
+    /// Set what happens to the current health when the max health changes.
+    pub fn with_policy(mut self, policy: MaxHealthChangePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Add a bonus, replacing the bonus with the same id if any.
+    /// # Arguments
+    /// * `id`: What the bonus comes from, eg: `"iron_ring"`.
+    /// * `bonus`: The bonus.
+    pub fn insert(&mut self, id: impl Into<Cow<'static, str>>, bonus: MaxHealthBonus) {
+        let id = id.into();
+        match self.bonuses.iter_mut().find(|(other, _)| *other == id) {
+            Some((_, existing)) => *existing = bonus,
+            None => self.bonuses.push((id, bonus)),
+        }
+    }
+
+    /// Remove the bonus with this id, returning it.
+    pub fn remove(&mut self, id: &str) -> Option<MaxHealthBonus> {
+        let index = self.bonuses.iter().position(|(other, _)| other == id)?;
+        Some(self.bonuses.remove(index).1)
+    }
+
+    /// Returns the bonus with this id, if any.
+    pub fn get(&self, id: &str) -> Option<MaxHealthBonus> {
+        self.bonuses
+            .iter()
+            .find(|(other, _)| other == id)
+            .map(|(_, bonus)| *bonus)
+    }
+
+    /// Returns every bonus with its id.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, MaxHealthBonus)> {
+        self.bonuses.iter().map(|(id, bonus)| (id.as_ref(), *bonus))
+    }
+
+    /// The max health with every bonus applied.
+    pub fn effective(&self) -> f32 {
+        let (flat, percent) =
+            self.bonuses
+                .iter()
+                .fold((0.0, 0.0), |(flat, percent), (_, bonus)| match bonus {
+                    MaxHealthBonus::Flat(value) => (flat + value, percent),
+                    MaxHealthBonus::Percent(value) => (flat, percent + value),
+                });
+
+        ((self.base + flat) * (1.0 + percent)).max(0.0)
+    }
+}
+
+pub(crate) fn apply_max_health_modifiers(
+    mut query: Query<(&MaxHealthModifiers, &mut HealthSystem), Changed<MaxHealthModifiers>>,
+) {
+    for (modifiers, mut health_system) in &mut query {
+        let max = modifiers.effective();
+        if max != health_system.get_health_max() {
+            health_system.set_health_max_with_policy(max, modifiers.policy);
+        }
+    }
+}
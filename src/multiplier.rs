@@ -0,0 +1,62 @@
+//! Multipliers of the damage an entity takes and deals.
+use bevy::prelude::*;
+
+use crate::DamageContext;
+
+/// Multiply the damage an entity takes, before it is mitigated, eg: `1.5` for a vulnerability debuff.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageDealtMultiplier, DamageEvent, DamageTakenMultiplier, HealthSystem, HealthSystemPlugin,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let attacker = app.world.spawn(DamageDealtMultiplier(2.0)).id();
+/// let entity = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), DamageTakenMultiplier(1.5)))
+///     .id();
+///
+/// app.world.send_event(DamageEvent::new(entity, 10.0).with_source(attacker));
+/// app.update();
+/// assert_eq!(70.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct DamageTakenMultiplier(pub f32);
+
+impl Default for DamageTakenMultiplier {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Multiply the damage an entity deals as the source of a [`DamageEvent`](crate::DamageEvent), eg: `1.2` for a damage buff.
+/// Reflected damage is not multiplied.
+#[derive(Debug, PartialEq, Copy, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct DamageDealtMultiplier(pub f32);
+
+impl Default for DamageDealtMultiplier {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+pub(crate) fn damage_multiplier_stage(context: &mut DamageContext, world: &mut World) {
+    if !context.event.reflected {
+        if let Some(dealt) = context
+            .event
+            .source
+            .and_then(|source| world.get::<DamageDealtMultiplier>(source))
+        {
+            context.amount *= dealt.0;
+        }
+    }
+    if let Some(taken) = world.get::<DamageTakenMultiplier>(context.hit) {
+        context.amount *= taken.0;
+    }
+}
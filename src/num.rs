@@ -0,0 +1,164 @@
+//! The numeric types a [`Health`](crate::Health) can be backed by, eg: integers for old-school RPGs.
+use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
+
+use bevy::prelude::*;
+use bevy::reflect::TypePath;
+
+/// A number the health of a [`Health`](crate::Health) is stored in.
+/// Integers saturate instead of overflowing, and truncate when converted from fractions.
+/// # Example
+/// ```
+/// use bevy_health_system::{Fraction, HealthSystemI32, HealthSystemReviveHealType};
+///
+/// let mut health_system = HealthSystemI32::new(100);
+/// health_system.deal_damage(30, false);
+/// assert_eq!(70, health_system.get_health());
+/// assert_eq!(0.7, health_system.get_health_normalized());
+///
+/// health_system.kill_system(false);
+/// health_system.revive_system(HealthSystemReviveHealType::HealFraction(Fraction::new(0.333)), false).unwrap();
+/// assert_eq!(33, health_system.get_health());
+/// ```
+pub trait HealthNum:
+    Copy + Default + PartialOrd + Debug + Reflect + TypePath + FromReflect + Send + Sync + 'static
+{
+    /// No health.
+    const ZERO: Self;
+
+    /// Convert from an `f32`, eg: the health of a [`HealthSystemReviveHealType::HealTo`](crate::HealthSystemReviveHealType::HealTo).
+    fn from_f32(value: f32) -> Self;
+
+    /// Convert to an `f32`, eg: for health bars.
+    fn to_f32(self) -> f32;
+
+    /// Add two health values.
+    fn add(self, rhs: Self) -> Self;
+
+    /// Subtract two health values.
+    fn sub(self, rhs: Self) -> Self;
+
+    /// Multiply a health value by a fraction, eg: `0.5` for half of it.
+    fn scale(self, fraction: f32) -> Self;
+
+    /// Returns the fraction of `of` this health value is, eg: `0.5` for half of it.
+    fn ratio(self, of: Self) -> f32;
+
+    /// Hash the exact value, eg: for the checksums of rollback netcode.
+    fn hash_bits<H: Hasher>(&self, state: &mut H);
+
+    /// Returns true if the value is neither NaN nor infinite, always true for integers.
+    fn is_finite(self) -> bool {
+        true
+    }
+}
+
+impl HealthNum for f32 {
+    const ZERO: Self = 0.0;
+
+    fn from_f32(value: f32) -> Self {
+        value
+    }
+
+    fn to_f32(self) -> f32 {
+        self
+    }
+
+    fn add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+
+    fn sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+
+    fn scale(self, fraction: f32) -> Self {
+        self * fraction
+    }
+
+    fn ratio(self, of: Self) -> f32 {
+        self / of
+    }
+
+    fn hash_bits<H: Hasher>(&self, state: &mut H) {
+        self.to_bits().hash(state);
+    }
+
+    fn is_finite(self) -> bool {
+        f32::is_finite(self)
+    }
+}
+
+impl HealthNum for f64 {
+    const ZERO: Self = 0.0;
+
+    fn from_f32(value: f32) -> Self {
+        value as f64
+    }
+
+    fn to_f32(self) -> f32 {
+        self as f32
+    }
+
+    fn add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+
+    fn sub(self, rhs: Self) -> Self {
+        self - rhs
+    }
+
+    fn scale(self, fraction: f32) -> Self {
+        self * fraction as f64
+    }
+
+    fn ratio(self, of: Self) -> f32 {
+        (self / of) as f32
+    }
+
+    fn hash_bits<H: Hasher>(&self, state: &mut H) {
+        self.to_bits().hash(state);
+    }
+
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
+}
+
+macro_rules! impl_health_num_integer {
+    ($($integer:ty),*) => {$(
+        impl HealthNum for $integer {
+            const ZERO: Self = 0;
+
+            fn from_f32(value: f32) -> Self {
+                value as $integer
+            }
+
+            fn to_f32(self) -> f32 {
+                self as f32
+            }
+
+            fn add(self, rhs: Self) -> Self {
+                self.saturating_add(rhs)
+            }
+
+            fn sub(self, rhs: Self) -> Self {
+                self.saturating_sub(rhs)
+            }
+
+            fn scale(self, fraction: f32) -> Self {
+                (self as f64 * fraction as f64) as $integer
+            }
+
+            fn ratio(self, of: Self) -> f32 {
+                (self as f64 / of as f64) as f32
+            }
+
+            fn hash_bits<H: Hasher>(&self, state: &mut H) {
+                self.hash(state);
+            }
+        }
+    )*};
+}
+
+impl_health_num_integer!(i32, i64, u32, u64);
@@ -0,0 +1,483 @@
+//! Damage and heal over time, eg: poison, burn, regeneration potions.
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{DamageCause, DamageEvent, DamageType, HealEvent, HealthSystem};
+
+/// Damage dealt every `tick_interval` for `duration`, eg: poison, burn,...
+/// Apply it to an entity with [`ApplyDamageOverTime`], an entity can have many of them at once.
+/// Each tick is dealt as a [`DamageEvent`], going through shields, resistances and armor.
+/// # Fields
+/// * `amount_per_tick`: The amount of damage dealt every tick.
+/// * `tick_interval`: The time between two ticks, a zero interval ticks every update.
+/// * `duration`: How long the effect lasts.
+/// * `damage_type`: The type of the damage dealt.
+/// * `source`: The entity that applied the effect, credited with every tick, see [`ApplyDamageOverTime::with_source`].
+/// * `cause`: What deals the damage, eg: `"poison"`, sent with every tick.
+#[derive(Debug, Clone, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DamageOverTime {
+    pub amount_per_tick: f32,
+    pub tick_interval: Duration,
+    pub duration: Duration,
+    pub damage_type: DamageType,
+    pub source: Option<Entity>,
+    pub cause: Option<DamageCause>,
+    ticker: OverTimeTicker,
+}
+
+impl DamageOverTime {
+    /// Create a new damage over time effect.
+    /// # Arguments
+    /// * `amount_per_tick`: The amount of damage dealt every tick.
+    /// * `tick_interval`: The time between two ticks.
+    /// * `duration`: How long the effect lasts.
+    pub fn new(amount_per_tick: f32, tick_interval: Duration, duration: Duration) -> Self {
+        Self {
+            amount_per_tick,
+            tick_interval,
+            duration,
+            damage_type: DamageType::default(),
+            source: None,
+            cause: None,
+            ticker: OverTimeTicker::new(tick_interval),
+        }
+    }
+
+    /// Set the type of the damage dealt.
+    pub fn with_type(mut self, damage_type: DamageType) -> Self {
+        self.damage_type = damage_type;
+        self
+    }
+
+    /// Set what deals the damage, eg: `"poison"`.
+    pub fn with_cause(mut self, cause: impl Into<DamageCause>) -> Self {
+        self.cause = Some(cause.into());
+        self
+    }
+
+    /// Returns true once the effect lasted its whole duration.
+    pub fn is_expired(&self) -> bool {
+        self.ticker.is_expired(self.duration)
+    }
+}
+
+/// Every [`DamageOverTime`] currently affecting an entity.
+/// Expired effects are removed by the [`HealthSystemPlugin`](crate::HealthSystemPlugin).
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     ApplyDamageOverTime, DamageOverTime, DamageOverTimeEffects, HealthSystem, HealthSystemPlugin,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+///
+/// let entity = app.world.spawn(HealthSystem::new(100.0)).id();
+/// let poison = DamageOverTime::new(5.0, Duration::from_secs(1), Duration::from_secs(2));
+/// app.world.send_event(ApplyDamageOverTime::new(entity, poison.clone()));
+/// app.world.send_event(ApplyDamageOverTime::new(entity, poison));
+///
+/// for _ in 0..4 {
+///     app.update();
+/// }
+///
+/// // Two stacks of poison, two ticks each.
+/// assert_eq!(80.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// assert!(app.world.get::<DamageOverTimeEffects>(entity).unwrap().is_empty());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DamageOverTimeEffects {
+    effects: Vec<DamageOverTime>,
+}
+
+impl DamageOverTimeEffects {
+    /// Add a new effect, stacking with the others.
+    pub fn add(&mut self, effect: DamageOverTime) {
+        self.effects.push(effect);
+    }
+
+    /// Returns every active effect.
+    pub fn iter(&self) -> impl Iterator<Item = &DamageOverTime> {
+        self.effects.iter()
+    }
+
+    /// Returns the number of active effects.
+    pub fn len(&self) -> usize {
+        self.effects.len()
+    }
+
+    /// Returns true if there is no active effect.
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Remove every effect.
+    pub fn clear(&mut self) {
+        self.effects.clear();
+    }
+}
+
+/// Request to apply a [`DamageOverTime`] to an entity with a [`HealthSystem`].
+/// # Fields
+/// * `target`: The entity receiving the effect.
+/// * `effect`: The effect to apply.
+/// * `source`: The entity applying the effect, if any, it becomes the source of the effect.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     ApplyDamageOverTime, DamageOverTime, DeathEvent, HealthSystem, HealthSystemPlugin,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+///
+/// let assassin = app.world.spawn_empty().id();
+/// let target = app.world.spawn(HealthSystem::new(10.0)).id();
+/// let poison = DamageOverTime::new(10.0, Duration::from_secs(1), Duration::from_secs(5))
+///     .with_cause("poison");
+/// app.world.send_event(ApplyDamageOverTime::new(target, poison).with_source(assassin));
+///
+/// app.update();
+/// app.update();
+/// let events = app.world.resource::<Events<DeathEvent>>();
+/// let death = events.iter_current_update_events().next().unwrap();
+/// assert_eq!(Some(assassin), death.source);
+/// assert_eq!("poison", death.cause.as_ref().unwrap().as_str());
+/// ```
+#[derive(Debug, Clone, Event)]
+pub struct ApplyDamageOverTime {
+    pub target: Entity,
+    pub effect: DamageOverTime,
+    pub source: Option<Entity>,
+}
+
+impl ApplyDamageOverTime {
+    /// Create a new request to apply a damage over time effect.
+    pub fn new(target: Entity, effect: DamageOverTime) -> Self {
+        Self {
+            target,
+            effect,
+            source: None,
+        }
+    }
+
+    /// Set the entity applying the effect, credited with the damage of every tick.
+    pub fn with_source(mut self, source: Entity) -> Self {
+        self.source = Some(source);
+        self
+    }
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when a [`DamageOverTime`] is applied.
+/// # Fields
+/// * `target`: The entity receiving the effect.
+/// * `damage_type`: The type of the damage dealt by the effect.
+#[derive(Debug, Clone, Event)]
+pub struct DamageOverTimeApplied {
+    pub target: Entity,
+    pub damage_type: DamageType,
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when a [`DamageOverTime`] expires.
+/// # Fields
+/// * `target`: The entity the effect expired on.
+/// * `damage_type`: The type of the damage dealt by the effect.
+#[derive(Debug, Clone, Event)]
+pub struct DamageOverTimeExpired {
+    pub target: Entity,
+    pub damage_type: DamageType,
+}
+
+/// Health healed every `tick_interval` for `duration`, eg: regeneration potions, healer abilities,...
+/// Apply it to an entity with [`ApplyHealOverTime`], an entity can have many of them at once.
+/// Each tick is healed as a [`HealEvent`].
+/// # Fields
+/// * `amount_per_tick`: The amount of health healed every tick.
+/// * `tick_interval`: The time between two ticks, a zero interval ticks every update.
+/// * `duration`: How long the effect lasts.
+#[derive(Debug, Clone, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HealOverTime {
+    pub amount_per_tick: f32,
+    pub tick_interval: Duration,
+    pub duration: Duration,
+    ticker: OverTimeTicker,
+}
+
+impl HealOverTime {
+    /// Create a new heal over time effect.
+    /// # Arguments
+    /// * `amount_per_tick`: The amount of health healed every tick.
+    /// * `tick_interval`: The time between two ticks.
+    /// * `duration`: How long the effect lasts.
+    pub fn new(amount_per_tick: f32, tick_interval: Duration, duration: Duration) -> Self {
+        Self {
+            amount_per_tick,
+            tick_interval,
+            duration,
+            ticker: OverTimeTicker::new(tick_interval),
+        }
+    }
+
+    /// Returns true once the effect lasted its whole duration.
+    pub fn is_expired(&self) -> bool {
+        self.ticker.is_expired(self.duration)
+    }
+}
+
+/// Every [`HealOverTime`] currently affecting an entity.
+/// Expired effects are removed by the [`HealthSystemPlugin`](crate::HealthSystemPlugin).
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     ApplyHealOverTime, HealOverTime, HealOverTimeEffects, HealthSystem, HealthSystemPlugin,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+///
+/// let mut health_system = HealthSystem::new(100.0);
+/// health_system.deal_damage(50.0, false);
+/// let entity = app.world.spawn(health_system).id();
+/// let potion = HealOverTime::new(10.0, Duration::from_secs(1), Duration::from_secs(3));
+/// app.world.send_event(ApplyHealOverTime::new(entity, potion));
+///
+/// for _ in 0..5 {
+///     app.update();
+/// }
+///
+/// assert_eq!(80.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// assert!(app.world.get::<HealOverTimeEffects>(entity).unwrap().is_empty());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HealOverTimeEffects {
+    effects: Vec<HealOverTime>,
+}
+
+impl HealOverTimeEffects {
+    /// Add a new effect, stacking with the others.
+    pub fn add(&mut self, effect: HealOverTime) {
+        self.effects.push(effect);
+    }
+
+    /// Returns every active effect.
+    pub fn iter(&self) -> impl Iterator<Item = &HealOverTime> {
+        self.effects.iter()
+    }
+
+    /// Returns the number of active effects.
+    pub fn len(&self) -> usize {
+        self.effects.len()
+    }
+
+    /// Returns true if there is no active effect.
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Remove every effect.
+    pub fn clear(&mut self) {
+        self.effects.clear();
+    }
+}
+
+/// Request to apply a [`HealOverTime`] to an entity with a [`HealthSystem`].
+/// # Fields
+/// * `target`: The entity receiving the effect.
+/// * `effect`: The effect to apply.
+#[derive(Debug, Clone, Event)]
+pub struct ApplyHealOverTime {
+    pub target: Entity,
+    pub effect: HealOverTime,
+}
+
+impl ApplyHealOverTime {
+    /// Create a new request to apply a heal over time effect.
+    pub fn new(target: Entity, effect: HealOverTime) -> Self {
+        Self { target, effect }
+    }
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when a [`HealOverTime`] is applied.
+/// # Fields
+/// * `target`: The entity receiving the effect.
+#[derive(Debug, Clone, Event)]
+pub struct HealOverTimeApplied {
+    pub target: Entity,
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when a [`HealOverTime`] expires.
+/// # Fields
+/// * `target`: The entity the effect expired on.
+#[derive(Debug, Clone, Event)]
+pub struct HealOverTimeExpired {
+    pub target: Entity,
+}
+
+/// Shared ticking logic of the over time effects.
+#[derive(Debug, Clone, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub(crate) struct OverTimeTicker {
+    timer: Timer,
+    elapsed: Duration,
+}
+
+impl OverTimeTicker {
+    fn new(tick_interval: Duration) -> Self {
+        Self {
+            timer: Timer::new(tick_interval, TimerMode::Repeating),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Returns the number of ticks that happened during `delta`.
+    fn tick(&mut self, delta: Duration, duration: Duration) -> u32 {
+        let delta = delta.min(duration.saturating_sub(self.elapsed));
+        self.elapsed += delta;
+
+        if self.timer.duration().is_zero() {
+            return u32::from(!delta.is_zero());
+        }
+        self.timer.tick(delta).times_finished_this_tick()
+    }
+
+    fn is_expired(&self, duration: Duration) -> bool {
+        self.elapsed >= duration
+    }
+}
+
+pub(crate) fn apply_damage_over_time(
+    mut commands: Commands,
+    mut events: EventReader<ApplyDamageOverTime>,
+    mut targets: Query<Option<&mut DamageOverTimeEffects>, With<HealthSystem>>,
+    mut applied: EventWriter<DamageOverTimeApplied>,
+) {
+    let mut inserted: HashMap<Entity, DamageOverTimeEffects> = HashMap::default();
+
+    for event in events.iter() {
+        let Ok(effects) = targets.get_mut(event.target) else {
+            continue;
+        };
+
+        let mut effect = event.effect.clone();
+        effect.source = event.source.or(effect.source);
+        match effects {
+            Some(mut effects) => effects.add(effect),
+            None => inserted.entry(event.target).or_default().add(effect),
+        }
+
+        applied.send(DamageOverTimeApplied {
+            target: event.target,
+            damage_type: event.effect.damage_type,
+        });
+    }
+
+    for (entity, effects) in inserted {
+        commands.entity(entity).insert(effects);
+    }
+}
+
+pub(crate) fn tick_damage_over_time(
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut DamageOverTimeEffects)>,
+    mut damage: EventWriter<DamageEvent>,
+    mut expired: EventWriter<DamageOverTimeExpired>,
+) {
+    for (entity, mut effects) in &mut query {
+        for effect in effects.effects.iter_mut() {
+            let ticks = effect.ticker.tick(time.delta(), effect.duration);
+            if ticks > 0 {
+                let mut event = DamageEvent::new(entity, effect.amount_per_tick * ticks as f32)
+                    .with_type(effect.damage_type);
+                event.source = effect.source;
+                event.cause = effect.cause.clone();
+                damage.send(event);
+            }
+        }
+
+        effects.effects.retain(|effect| {
+            if effect.is_expired() {
+                expired.send(DamageOverTimeExpired {
+                    target: entity,
+                    damage_type: effect.damage_type,
+                });
+            }
+            !effect.is_expired()
+        });
+    }
+}
+
+pub(crate) fn apply_heal_over_time(
+    mut commands: Commands,
+    mut events: EventReader<ApplyHealOverTime>,
+    mut targets: Query<Option<&mut HealOverTimeEffects>, With<HealthSystem>>,
+    mut applied: EventWriter<HealOverTimeApplied>,
+) {
+    let mut inserted: HashMap<Entity, HealOverTimeEffects> = HashMap::default();
+
+    for event in events.iter() {
+        let Ok(effects) = targets.get_mut(event.target) else {
+            continue;
+        };
+
+        match effects {
+            Some(mut effects) => effects.add(event.effect.clone()),
+            None => inserted
+                .entry(event.target)
+                .or_default()
+                .add(event.effect.clone()),
+        }
+
+        applied.send(HealOverTimeApplied {
+            target: event.target,
+        });
+    }
+
+    for (entity, effects) in inserted {
+        commands.entity(entity).insert(effects);
+    }
+}
+
+pub(crate) fn tick_heal_over_time(
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut HealOverTimeEffects)>,
+    mut heal: EventWriter<HealEvent>,
+    mut expired: EventWriter<HealOverTimeExpired>,
+) {
+    for (entity, mut effects) in &mut query {
+        for effect in effects.effects.iter_mut() {
+            let ticks = effect.ticker.tick(time.delta(), effect.duration);
+            if ticks > 0 {
+                heal.send(HealEvent::new(
+                    entity,
+                    effect.amount_per_tick * ticks as f32,
+                ));
+            }
+        }
+
+        effects.effects.retain(|effect| {
+            if effect.is_expired() {
+                expired.send(HealOverTimeExpired { target: entity });
+            }
+            !effect.is_expired()
+        });
+    }
+}
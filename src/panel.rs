@@ -0,0 +1,267 @@
+//! `bevy_egui` window listing every entity with health, for debugging large battles.
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts, EguiPlugin};
+
+use crate::{
+    DamageEvent, DamageOverTimeEffects, Fraction, HealEvent, HealOverTimeEffects,
+    HealthStatusEffects, HealthSystem, HealthSystemReviveHealType, ReviveEvent,
+};
+
+/// Add this plugin to show a window listing every entity with a [`HealthSystem`]: its name, health, state
+/// and active effects, with buttons to kill, revive and heal it. See [`HealthPanel`].
+/// It is not part of the [`HealthSystemPlugin`](crate::HealthSystemPlugin), add both.
+/// The `EguiPlugin` of `bevy_egui` is added if missing.
+/// # Example
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_health_system::{HealthPanelPlugin, HealthSystemPlugin};
+///
+/// App::new()
+///     .add_plugins((DefaultPlugins, HealthSystemPlugin, HealthPanelPlugin))
+///     .run();
+/// ```
+#[derive(Debug, Default)]
+pub struct HealthPanelPlugin;
+
+impl Plugin for HealthPanelPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin);
+        }
+        app.init_resource::<HealthPanel>().add_systems(
+            Update,
+            (
+                toggle_health_panel,
+                health_panel_ui.run_if(|panel: Res<HealthPanel>| panel.open),
+            )
+                .chain(),
+        );
+    }
+}
+
+/// How the rows of the [`HealthPanel`] are sorted.
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub enum HealthPanelSort {
+    /// By entity index.
+    #[default]
+    Entity,
+    /// By `Name`, unnamed entities first.
+    Name,
+    /// By current health.
+    Health,
+    /// By fraction of max health.
+    Fraction,
+}
+
+/// Settings of the window shown by the [`HealthPanelPlugin`], also edited through the window itself.
+/// # Fields
+/// * `open`: Whether the window is shown.
+/// * `toggle_key`: The key toggling the window, if any.
+/// * `filter`: Only list the entities whose name or entity contains this text, ignoring case.
+/// * `sort`: How the rows are sorted.
+/// * `descending`: Sort in descending order.
+/// # Examples
+/// ```
+/// use bevy_health_system::HealthPanel;
+///
+/// let panel = HealthPanel {
+///     filter: "goblin".to_string(),
+///     ..Default::default()
+/// };
+/// assert!(panel.matches("Goblin archer"));
+/// assert!(!panel.matches("Orc"));
+/// ```
+#[derive(Debug, Clone, Resource)]
+pub struct HealthPanel {
+    pub open: bool,
+    pub toggle_key: Option<KeyCode>,
+    pub filter: String,
+    pub sort: HealthPanelSort,
+    pub descending: bool,
+}
+
+impl Default for HealthPanel {
+    fn default() -> Self {
+        Self {
+            open: true,
+            toggle_key: Some(KeyCode::F4),
+            filter: String::new(),
+            sort: HealthPanelSort::default(),
+            descending: false,
+        }
+    }
+}
+
+impl HealthPanel {
+    /// Returns true if the text contains the filter, ignoring case. Every text matches an empty filter.
+    pub fn matches(&self, text: &str) -> bool {
+        let filter = self.filter.trim();
+        filter.is_empty() || text.to_lowercase().contains(&filter.to_lowercase())
+    }
+}
+
+/// A row of the window.
+struct HealthPanelRow {
+    entity: Entity,
+    name: Option<String>,
+    health: f32,
+    max: f32,
+    dead: bool,
+    state: String,
+    effects: String,
+}
+
+fn toggle_health_panel(keys: Option<Res<Input<KeyCode>>>, mut panel: ResMut<HealthPanel>) {
+    let (Some(keys), Some(key)) = (keys, panel.toggle_key) else {
+        return;
+    };
+    if keys.just_pressed(key) {
+        panel.open = !panel.open;
+    }
+}
+
+/// Describe the active effects, eg: `2 DoT, 1 HoT, Regen`.
+fn effects_summary(
+    damage_over_time: Option<&DamageOverTimeEffects>,
+    heal_over_time: Option<&HealOverTimeEffects>,
+    status: Option<&HealthStatusEffects>,
+) -> String {
+    let mut effects = Vec::new();
+    if let Some(damage_over_time) = damage_over_time.filter(|effects| !effects.is_empty()) {
+        effects.push(format!("{} DoT", damage_over_time.len()));
+    }
+    if let Some(heal_over_time) = heal_over_time.filter(|effects| !effects.is_empty()) {
+        effects.push(format!("{} HoT", heal_over_time.len()));
+    }
+    if let Some(status) = status {
+        effects.extend(status.iter().map(|effect| format!("{:?}", effect.kind)));
+    }
+    effects.join(", ")
+}
+
+#[allow(clippy::type_complexity)]
+fn health_panel_ui(
+    mut contexts: EguiContexts,
+    mut panel: ResMut<HealthPanel>,
+    query: Query<(
+        Entity,
+        &HealthSystem,
+        Option<&Name>,
+        Option<&DamageOverTimeEffects>,
+        Option<&HealOverTimeEffects>,
+        Option<&HealthStatusEffects>,
+    )>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut heal_events: EventWriter<HealEvent>,
+    mut revive_events: EventWriter<ReviveEvent>,
+) {
+    let mut rows: Vec<_> = query
+        .iter()
+        .map(
+            |(entity, health_system, name, damage_over_time, heal_over_time, status)| {
+                HealthPanelRow {
+                    entity,
+                    name: name.map(|name| name.as_str().to_string()),
+                    health: health_system.get_health(),
+                    max: health_system.get_health_max(),
+                    dead: health_system.is_dead(),
+                    state: format!("{:?}", health_system.get_state()),
+                    effects: effects_summary(damage_over_time, heal_over_time, status),
+                }
+            },
+        )
+        .filter(|row| {
+            panel.matches(&format!("{:?}", row.entity))
+                || row.name.as_deref().is_some_and(|name| panel.matches(name))
+        })
+        .collect();
+    rows.sort_by(|a, b| {
+        let ordering = match panel.sort {
+            HealthPanelSort::Entity => a.entity.cmp(&b.entity),
+            HealthPanelSort::Name => a.name.cmp(&b.name),
+            HealthPanelSort::Health => a.health.total_cmp(&b.health),
+            HealthPanelSort::Fraction => (a.health / a.max).total_cmp(&(b.health / b.max)),
+        };
+        // Keep the order stable between frames for equal keys.
+        let ordering = ordering.then(a.entity.cmp(&b.entity));
+        if panel.descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+
+    let HealthPanel {
+        open,
+        filter,
+        sort,
+        descending,
+        ..
+    } = &mut *panel;
+    egui::Window::new("Health")
+        .open(open)
+        .show(contexts.ctx_mut(), |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Filter");
+                ui.text_edit_singleline(filter);
+            });
+            ui.horizontal(|ui| {
+                ui.label("Sort");
+                ui.selectable_value(sort, HealthPanelSort::Entity, "Entity");
+                ui.selectable_value(sort, HealthPanelSort::Name, "Name");
+                ui.selectable_value(sort, HealthPanelSort::Health, "Health");
+                ui.selectable_value(sort, HealthPanelSort::Fraction, "Fraction");
+                ui.checkbox(descending, "Descending");
+            });
+            ui.label(format!("{} entities", rows.len()));
+            ui.separator();
+
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                egui::Grid::new("health_panel_rows")
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for header in ["Entity", "Name", "Health", "State", "Effects", ""] {
+                            ui.strong(header);
+                        }
+                        ui.end_row();
+
+                        for row in &rows {
+                            ui.label(format!("{:?}", row.entity));
+                            ui.label(row.name.as_deref().unwrap_or("-"));
+                            ui.label(format!("{:.1}/{:.1}", row.health, row.max));
+                            ui.label(&row.state);
+                            ui.label(&row.effects);
+                            ui.horizontal(|ui| {
+                                if ui
+                                    .add_enabled(!row.dead, egui::Button::new("Kill"))
+                                    .clicked()
+                                {
+                                    // Executing at full health kills through damage caps and mitigation.
+                                    damage_events.send(
+                                        DamageEvent::new(row.entity, row.health.max(1.0))
+                                            .forced()
+                                            .with_execute(Fraction::ONE)
+                                            .with_cause("health_panel"),
+                                    );
+                                }
+                                if ui
+                                    .add_enabled(row.dead, egui::Button::new("Revive"))
+                                    .clicked()
+                                {
+                                    revive_events.send(ReviveEvent::new(
+                                        row.entity,
+                                        HealthSystemReviveHealType::HealFull,
+                                    ));
+                                }
+                                let hurt = !row.dead && row.health < row.max;
+                                if ui.add_enabled(hurt, egui::Button::new("Heal")).clicked() {
+                                    heal_events
+                                        .send(HealEvent::new(row.entity, row.max - row.health));
+                                }
+                            });
+                            ui.end_row();
+                        }
+                    });
+            });
+        });
+}
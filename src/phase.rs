@@ -0,0 +1,127 @@
+//! Phases of an entity, eg: a boss, driven by segments of its health.
+use bevy::prelude::*;
+
+use crate::{Fraction, HealthSystem};
+
+/// Split the health of an entity into segments, each one being a phase.
+/// Phase `0` lasts until the health falls to the highest boundary, phase `1` until the next one, and so on,
+/// the same way a [`ThresholdCrossed`](crate::ThresholdCrossed) event falls at or below a threshold.
+/// The plugin keeps the [`CurrentPhase`] of the entity up to date and sends [`PhaseChanged`].
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     CurrentPhase, DamageEvent, Fraction, HealEvent, HealthPhases, HealthSystem,
+///     HealthSystemPlugin, PhaseChanged,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let boss = app
+///     .world
+///     .spawn((HealthSystem::new(300.0), HealthPhases::new([Fraction::new(2.0 / 3.0), Fraction::new(1.0 / 3.0)])))
+///     .id();
+/// app.update();
+/// assert_eq!(&CurrentPhase(0), app.world.get::<CurrentPhase>(boss).unwrap());
+///
+/// app.world.send_event(DamageEvent::new(boss, 250.0));
+/// app.update();
+/// assert_eq!(&CurrentPhase(2), app.world.get::<CurrentPhase>(boss).unwrap());
+///
+/// let events = app.world.resource::<Events<PhaseChanged>>();
+/// let changed = events.iter_current_update_events().next().unwrap();
+/// assert_eq!((0, 2), (changed.previous, changed.current));
+///
+/// app.world.send_event(HealEvent::new(boss, 100.0));
+/// app.update();
+/// assert_eq!(&CurrentPhase(1), app.world.get::<CurrentPhase>(boss).unwrap());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct HealthPhases {
+    boundaries: Vec<f32>,
+}
+
+impl HealthPhases {
+    /// Split the health into phases.
+    /// # Arguments
+    /// * `boundaries`: Where a phase ends and the next one starts, as fractions of max health, in any order.
+    pub fn new(boundaries: impl IntoIterator<Item = Fraction>) -> Self {
+        let mut boundaries: Vec<_> = boundaries
+            .into_iter()
+            .map(|boundary| boundary.get())
+            .collect();
+        boundaries.sort_by(|a, b| b.total_cmp(a));
+
+        Self { boundaries }
+    }
+
+    /// The boundaries between phases, from the highest to the lowest.
+    pub fn boundaries(&self) -> &[f32] {
+        &self.boundaries
+    }
+
+    /// The number of phases, one more than the number of boundaries.
+    pub fn phase_count(&self) -> usize {
+        self.boundaries.len() + 1
+    }
+
+    /// The phase matching a health, as a fraction of max health.
+    pub fn phase_at(&self, normalized_health: Fraction) -> usize {
+        let normalized_health = normalized_health.get();
+        self.boundaries
+            .iter()
+            .take_while(|&&boundary| normalized_health <= boundary)
+            .count()
+    }
+}
+
+/// Inserted and kept up to date by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) on entities with [`HealthPhases`].
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct CurrentPhase(pub usize);
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when the [`CurrentPhase`] of an entity changes.
+/// # Fields
+/// * `entity`: The entity whose phase changed.
+/// * `previous`: The phase before the change.
+/// * `current`: The phase after the change.
+#[derive(Debug, Clone, Event)]
+pub struct PhaseChanged {
+    pub entity: Entity,
+    pub previous: usize,
+    pub current: usize,
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_phases(
+    mut commands: Commands,
+    mut query: Query<
+        (
+            Entity,
+            &HealthSystem,
+            &HealthPhases,
+            Option<&mut CurrentPhase>,
+        ),
+        Or<(Changed<HealthSystem>, Changed<HealthPhases>)>,
+    >,
+    mut changes: EventWriter<PhaseChanged>,
+) {
+    for (entity, health_system, phases, current_phase) in &mut query {
+        let phase = phases.phase_at(health_system.get_health_fraction());
+
+        let Some(mut current_phase) = current_phase else {
+            commands.entity(entity).insert(CurrentPhase(phase));
+            continue;
+        };
+        if current_phase.0 != phase {
+            changes.send(PhaseChanged {
+                entity,
+                previous: current_phase.0,
+                current: phase,
+            });
+            current_phase.0 = phase;
+        }
+    }
+}
@@ -0,0 +1,410 @@
+//! The ordered stages a damage request goes through before being applied.
+use std::borrow::Cow;
+
+use bevy::prelude::*;
+
+use crate::{
+    DamageEvent, DamageType, Endure, GlobalDamageSettings, HealthRng, HealthSystem,
+    HealthSystemModifier, HitZone, InSafeZone, Invulnerability, Player, SharedHealthMember,
+    SpawnProtection,
+};
+
+/// A step of the [`DamagePipeline`]. Stages run in this order, then the damage is applied.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+pub enum DamageStage {
+    /// Changes to the raw damage, built-in: friendly fire, [`Evasion`](crate::Evasion), [`CritStats`](crate::CritStats),
+    /// [`BlockState`](crate::BlockState) and [`Poise`](crate::Poise).
+    PreMitigation,
+    /// Reductions from the defenses of the target, built-in: [`Resistances`](crate::Resistances),
+    /// [`Shield`](crate::Shield), [`TemporaryHealth`](crate::TemporaryHealth) and [`Armor`](crate::Armor).
+    Mitigation,
+    /// Changes to the damage left once mitigated, built-in: [`ExecuteThreshold`](crate::ExecuteThreshold),
+    /// [`DamageCap`](crate::DamageCap) and [`IntegerHealth`](crate::IntegerHealth).
+    PostMitigation,
+}
+
+/// A damage request going through the [`DamagePipeline`].
+/// Stages lower `amount` and record what they removed, the [`DamageAppliedEvent`](crate::DamageAppliedEvent) is built from it.
+/// # Fields
+/// * `event`: The request being resolved.
+/// * `hit`: The entity hit, the parent when a [`HitZone`] was hit.
+/// * `target`: The entity taking the damage, the pool when `hit` is a [`SharedHealthMember`].
+/// * `zone`: The tag of the [`HitZone`] hit, if any.
+/// * `raw`: The damage requested, multiplied by the [`HitZone`], before any stage.
+/// * `amount`: The damage left to deal.
+/// * `friendly_fire`: The damage is dealt by a teammate.
+/// * `was_crit`: The damage is a critical hit.
+/// * `was_execute`: The damage executes the target.
+/// * `blocked`: The amount of damage removed by blocking.
+/// * `resisted`: The amount of damage removed by resistances.
+/// * `after_resistances`: The damage left once resistances were applied.
+/// * `shield_absorbed`: The amount of damage absorbed by a shield.
+/// * `temporary_absorbed`: The amount of damage absorbed by temporary health.
+/// * `armor_absorbed`: The amount of damage mitigated by armor.
+/// * `capped`: The amount of damage removed by a damage cap.
+/// * `poise_damage`: The [`Poise`](crate::Poise) the hit removes, only lowered once the damage is applied.
+#[derive(Debug, Clone)]
+pub struct DamageContext {
+    pub event: DamageEvent,
+    pub hit: Entity,
+    pub target: Entity,
+    pub zone: Option<Cow<'static, str>>,
+    pub raw: f32,
+    pub amount: f32,
+    pub friendly_fire: bool,
+    pub was_crit: bool,
+    pub was_execute: bool,
+    pub blocked: f32,
+    pub resisted: f32,
+    pub after_resistances: f32,
+    pub shield_absorbed: f32,
+    pub temporary_absorbed: f32,
+    pub armor_absorbed: f32,
+    pub capped: f32,
+    pub poise_damage: f32,
+    cancelled: bool,
+    preview: bool,
+    endured: bool,
+}
+
+impl DamageContext {
+    /// Stop the request, the remaining stages are skipped and no damage is dealt.
+    pub fn cancel(&mut self) {
+        self.cancelled = true;
+    }
+
+    /// Whether a stage cancelled the request.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled
+    }
+
+    /// Whether the request is only previewed, see [`DamagePipeline::preview_damage`].
+    /// Modifiers must not change the world, send events or roll random numbers while previewing.
+    pub fn is_preview(&self) -> bool {
+        self.preview
+    }
+
+    /// Whether the damage bypasses resistances and armor, see [`DamageType::TRUE`].
+    pub fn ignores_mitigation(&self) -> bool {
+        self.event.damage_type == DamageType::TRUE
+    }
+
+    /// Whether this damage kills, or downs, a target with `health` left.
+    pub(crate) fn is_lethal(&self, health: f32) -> bool {
+        self.health_floor().is_none() && self.amount > 0.0 && self.amount >= health
+    }
+
+    /// The lowest health this damage can leave, if it is non-lethal or was endured.
+    pub(crate) fn health_floor(&self) -> Option<f32> {
+        self.event.health_floor.or(self.endured.then_some(1.0))
+    }
+
+    /// Whether [`Endure`] saves the target from this damage.
+    pub(crate) fn endured(&self) -> bool {
+        self.endured
+    }
+
+    /// The breakdown of this damage, once `applied` health was lost out of it.
+    pub(crate) fn breakdown(&self, applied: f32, lethal: bool) -> DamageBreakdown {
+        let amount = self.amount.max(0.0);
+
+        DamageBreakdown {
+            raw: self.raw,
+            blocked: self.blocked,
+            resisted: self.resisted,
+            after_resistances: self.after_resistances,
+            shield_absorbed: self.shield_absorbed,
+            temporary_absorbed: self.temporary_absorbed,
+            armor_absorbed: self.armor_absorbed,
+            capped: self.capped,
+            amount,
+            applied,
+            overkill: (amount - applied).max(0.0),
+            lethal,
+        }
+    }
+}
+
+/// A stage of the [`DamagePipeline`], implemented by any `Fn(&mut DamageContext, &mut World)`.
+pub trait DamageModifier: Send + Sync + 'static {
+    /// Change the damage of `context`, with full access to the `world`.
+    fn modify(&self, context: &mut DamageContext, world: &mut World);
+}
+
+impl<F> DamageModifier for F
+where
+    F: Fn(&mut DamageContext, &mut World) + Send + Sync + 'static,
+{
+    fn modify(&self, context: &mut DamageContext, world: &mut World) {
+        self(context, world)
+    }
+}
+
+/// Every [`DamageModifier`] a [`DamageEvent`](crate::DamageEvent) goes through, ordered by [`DamageStage`],
+/// then in the order they were added. Add yours with [`DamagePipelineAppExt::add_damage_modifier`].
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     Armor, DamageContext, DamageEvent, DamagePipelineAppExt, DamageStage, HealthSystem,
+///     HealthSystemPlugin,
+/// };
+///
+/// #[derive(Component)]
+/// struct Boss;
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.add_damage_modifier(
+///     DamageStage::PostMitigation,
+///     |context: &mut DamageContext, world: &mut World| {
+///         if world.get::<Boss>(context.target).is_some() {
+///             context.amount /= 2.0;
+///         }
+///     },
+/// );
+///
+/// let boss = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), Armor::new(10.0, 0.0), Boss))
+///     .id();
+/// app.world.send_event(DamageEvent::new(boss, 30.0));
+/// app.update();
+///
+/// assert_eq!(90.0, app.world.get::<HealthSystem>(boss).unwrap().get_health());
+/// ```
+#[derive(Default, Resource)]
+pub struct DamagePipeline {
+    modifiers: Vec<(DamageStage, Box<dyn DamageModifier>)>,
+}
+
+impl DamagePipeline {
+    /// Add a modifier after every other one of its stage.
+    /// # Arguments
+    /// * `stage`: When the modifier runs.
+    /// * `modifier`: The modifier.
+    pub fn add(&mut self, stage: DamageStage, modifier: impl DamageModifier) -> &mut Self {
+        let index = self.modifiers.partition_point(|(other, _)| *other <= stage);
+        self.modifiers.insert(index, (stage, Box::new(modifier)));
+        self
+    }
+
+    /// Compute the damage a request would deal, without applying it.
+    /// Nothing is random while previewing: the damage is never dodged nor critical, and has no variance.
+    /// Returns `None` if the request can't damage its target or would be cancelled, eg: blocked friendly fire, a parry,...
+    /// # Arguments
+    /// * `world`: The world the target lives in, the built-in stages leave it untouched.
+    /// * `request`: The damage to preview.
+    /// # Examples
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_health_system::{
+    ///     Armor, DamageEvent, DamagePipeline, HealthSystem, HealthSystemPlugin, Shield,
+    /// };
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+    ///
+    /// let entity = app
+    ///     .world
+    ///     .spawn((HealthSystem::new(100.0), Shield::new(20.0), Armor::new(10.0, 0.0)))
+    ///     .id();
+    /// let breakdown = app
+    ///     .world
+    ///     .resource_scope(|world, pipeline: Mut<DamagePipeline>| {
+    ///         pipeline.preview_damage(world, &DamageEvent::new(entity, 50.0))
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(20.0, breakdown.shield_absorbed);
+    /// assert_eq!(10.0, breakdown.armor_absorbed);
+    /// assert_eq!(20.0, breakdown.amount);
+    /// assert!(!breakdown.lethal);
+    /// assert_eq!(20.0, app.world.get::<Shield>(entity).unwrap().current);
+    /// ```
+    pub fn preview_damage(
+        &self,
+        world: &mut World,
+        request: &DamageEvent,
+    ) -> Option<DamageBreakdown> {
+        let context = self.run(world, request, true)?;
+        let health = world.get::<HealthSystem>(context.target)?.get_health();
+        let floor = context.health_floor().unwrap_or(0.0);
+        let applied = context.amount.clamp(0.0, (health - floor).max(0.0));
+
+        Some(context.breakdown(applied, context.is_lethal(health)))
+    }
+
+    /// Resolve `event` through every modifier, or `None` if it can't damage its target or was cancelled.
+    pub(crate) fn run(
+        &self,
+        world: &mut World,
+        event: &DamageEvent,
+        preview: bool,
+    ) -> Option<DamageContext> {
+        let (hit, zone) = match (
+            world.get::<HitZone>(event.target),
+            world.get::<Parent>(event.target),
+        ) {
+            (Some(zone), Some(parent)) => (parent.get(), Some(zone.clone())),
+            _ => (event.target, None),
+        };
+        let target = world
+            .get::<SharedHealthMember>(hit)
+            .map_or(hit, |member| member.pool);
+
+        let health_system = world.get::<HealthSystem>(target)?;
+        if health_system.is_dead() || (health_system.is_downed() && !event.force) {
+            return None;
+        }
+        if !event.force
+            && (health_system.has_modifier(HealthSystemModifier::INVINCIBLE)
+                || world
+                    .get::<Invulnerability>(target)
+                    .is_some_and(Invulnerability::is_active)
+                || world
+                    .get::<SpawnProtection>(target)
+                    .is_some_and(SpawnProtection::is_active)
+                || world.get::<InSafeZone>(target).is_some())
+        {
+            return None;
+        }
+        if world
+            .get_resource::<GlobalDamageSettings>()
+            .is_some_and(|settings| settings.god_mode)
+            && world.get::<Player>(hit).is_some()
+        {
+            return None;
+        }
+
+        let mut amount = event.amount * zone.as_ref().map_or(1.0, |zone| zone.multiplier);
+        if event.variance > 0.0 && !preview {
+            amount *= 1.0
+                + world
+                    .resource_mut::<HealthRng>()
+                    .range(-event.variance, event.variance);
+        }
+
+        let mut context = DamageContext {
+            event: event.clone(),
+            hit,
+            target,
+            zone: zone.map(|zone| zone.zone_tag),
+            raw: amount,
+            amount,
+            friendly_fire: false,
+            was_crit: false,
+            was_execute: false,
+            blocked: 0.0,
+            resisted: 0.0,
+            after_resistances: amount,
+            shield_absorbed: 0.0,
+            temporary_absorbed: 0.0,
+            armor_absorbed: 0.0,
+            capped: 0.0,
+            poise_damage: 0.0,
+            cancelled: false,
+            preview,
+            endured: false,
+        };
+        for (_, modifier) in &self.modifiers {
+            modifier.modify(&mut context, world);
+            if context.cancelled {
+                return None;
+            }
+        }
+
+        let health = world.get::<HealthSystem>(target)?.get_health();
+        context.endured = context.is_lethal(health) && world.get::<Endure>(target).is_some();
+
+        Some(context)
+    }
+}
+
+/// How a damage request is mitigated, sent in [`DamageAppliedEvent`](crate::DamageAppliedEvent)
+/// or computed ahead of time with [`DamagePipeline::preview_damage`].
+/// # Fields
+/// * `raw`: The damage requested, multiplied by the [`HitZone`].
+/// * `blocked`: The amount of damage removed by blocking.
+/// * `resisted`: The amount of damage removed by resistances.
+/// * `after_resistances`: The damage left once resistances were applied.
+/// * `shield_absorbed`: The amount of damage absorbed by a shield.
+/// * `temporary_absorbed`: The amount of damage absorbed by temporary health.
+/// * `armor_absorbed`: The amount of damage mitigated by armor.
+/// * `capped`: The amount of damage removed by a [`DamageCap`](crate::DamageCap).
+/// * `amount`: The damage dealt to health once mitigated.
+/// * `applied`: The amount of health lost.
+/// * `overkill`: The damage left over after health ran out.
+/// * `lethal`: The damage kills, or downs, the target.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageAppliedEvent, DamageEvent, DamageType, HealthSystem, HealthSystemPlugin, Resistances,
+///     Shield,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app
+///     .world
+///     .spawn((
+///         HealthSystem::new(30.0),
+///         Resistances::default().with(DamageType::FIRE, 0.5),
+///         Shield::new(10.0),
+///     ))
+///     .id();
+/// app.world.send_event(DamageEvent::new(entity, 100.0).with_type(DamageType::FIRE));
+/// app.update();
+///
+/// let events = app.world.resource::<Events<DamageAppliedEvent>>();
+/// let breakdown = events.iter_current_update_events().next().unwrap().breakdown;
+/// assert_eq!(50.0, breakdown.after_resistances);
+/// assert_eq!(10.0, breakdown.shield_absorbed);
+/// assert_eq!(40.0, breakdown.amount);
+/// assert_eq!(30.0, breakdown.applied);
+/// assert_eq!(10.0, breakdown.overkill);
+/// assert!(breakdown.lethal);
+/// ```
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+pub struct DamageBreakdown {
+    pub raw: f32,
+    pub blocked: f32,
+    pub resisted: f32,
+    pub after_resistances: f32,
+    pub shield_absorbed: f32,
+    pub temporary_absorbed: f32,
+    pub armor_absorbed: f32,
+    pub capped: f32,
+    pub amount: f32,
+    pub applied: f32,
+    pub overkill: f32,
+    pub lethal: bool,
+}
+
+/// Register [`DamageModifier`]s on an [`App`].
+pub trait DamagePipelineAppExt {
+    /// Add a modifier to the [`DamagePipeline`], after every other one of its stage.
+    /// # Arguments
+    /// * `stage`: When the modifier runs.
+    /// * `modifier`: The modifier.
+    fn add_damage_modifier(
+        &mut self,
+        stage: DamageStage,
+        modifier: impl DamageModifier,
+    ) -> &mut Self;
+}
+
+impl DamagePipelineAppExt for App {
+    fn add_damage_modifier(
+        &mut self,
+        stage: DamageStage,
+        modifier: impl DamageModifier,
+    ) -> &mut Self {
+        self.world
+            .get_resource_or_insert_with(DamagePipeline::default)
+            .add(stage, modifier);
+        self
+    }
+}
@@ -0,0 +1,482 @@
+//! The plugin driving every component of this crate.
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::utils::{HashMap, HashSet};
+
+use crate::aggregate::update_aggregate_health;
+use crate::armor::armor_stage;
+use crate::aura::apply_healing_auras;
+use crate::auto_revive::{start_auto_revives, tick_auto_revives};
+use crate::block::{block_stage, tick_parry_windows};
+use crate::cap::damage_cap_stage;
+use crate::combat::update_combat_state;
+use crate::contact::{apply_contact_damage, Contacts};
+use crate::crit::crit_stage;
+use crate::damage::apply_damage_events;
+use crate::damage_type::{immunity_stage, resistances_stage};
+use crate::death::{apply_death_behavior, detect_deaths, remove_corpses, remove_dead_marker};
+use crate::decay::decay_health;
+use crate::displayed::{update_displayed_health, update_smoothed_health};
+use crate::downed::bleed_out;
+use crate::evasion::evasion_stage;
+use crate::execute::execute_stage;
+use crate::heal::apply_heal_events;
+use crate::healing_modifier::tick_healing_modifiers;
+use crate::invulnerability::{grant_hit_invulnerability, tick_invulnerability};
+use crate::kill::send_kills;
+use crate::last_damage::record_last_damage;
+use crate::lifesteal::apply_lifesteal;
+use crate::log::record_combat_log;
+use crate::loot::drop_loot;
+use crate::max_health::apply_max_health_modifiers;
+use crate::multiplier::damage_multiplier_stage;
+use crate::over_time::{
+    apply_damage_over_time, apply_heal_over_time, tick_damage_over_time, tick_heal_over_time,
+    OverTimeTicker,
+};
+use crate::phase::update_phases;
+use crate::poise::{poise_stage, regenerate_poise};
+use crate::prediction::reconcile_predicted_health;
+use crate::quantize::sync_encoded_health;
+use crate::queue::{queue_health_requests, resolve_health_requests};
+use crate::regen::regenerate_health;
+use crate::require::require_health_system;
+use crate::respawn::{start_respawns, tick_respawns};
+use crate::revive::apply_revive_events;
+use crate::rounding::integer_health_stage;
+use crate::settings::global_damage_stage;
+use crate::shared_pool::{propagate_shared_deaths, revive_shared_members};
+use crate::shield::{recharge_shields, shield_stage};
+use crate::spawn_protection::tick_spawn_protection;
+use crate::stats::{update_combat_stats, update_dps_meters};
+use crate::status::{apply_health_status_effects, damage_taken_stage, tick_health_status_effects};
+use crate::team::friendly_fire_stage;
+use crate::temporary_health::{decay_temporary_health, temporary_health_stage};
+use crate::thorns::reflect_damage;
+use crate::threshold::detect_threshold_crossings;
+use crate::transfer::apply_health_transfers;
+use crate::zone::{apply_zones, update_safe_zones};
+use crate::{
+    AggregateDeathRule, AggregateHealth, ApplyDamageOverTime, ApplyHealOverTime,
+    ApplyHealthStatusEffect, Armor, AutoRevive, AutoRevived, BlockState, BlockedHit, BossBar,
+    CombatLog, CombatRule, CombatStats, CombatTimeout, ContactDamage, Corpse, CorpseRemoved,
+    CritStats, CurrentPhase, DamageAppliedEvent, DamageCap, DamageCause, DamageDealtMultiplier,
+    DamageDodged, DamageEvent, DamageImmune, DamageImmunities, DamageOverTime,
+    DamageOverTimeApplied, DamageOverTimeEffects, DamageOverTimeExpired, DamagePipelineAppExt,
+    DamageResolution, DamageStage, DamageTakenMultiplier, DamageType, DamageTypeRegistry,
+    DamageZone, Dead, DeathBehavior, DeathEvent, DisplayedHealth, Downable, Downed, DownedEvent,
+    DpsMeter, DpsSource, Endure, EnduredLethalHit, Evasion, ExecuteThreshold, Fraction,
+    FriendlyFireBlocked, FriendlyFirePolicy, GlobalDamageSettings, HealEvent, HealOverTime,
+    HealOverTimeApplied, HealOverTimeEffects, HealOverTimeExpired, HealZone, HealingAura,
+    HealingModifier, HealthBar, HealthDecay, HealthMispredicted, HealthPart, HealthPhases,
+    HealthRegen, HealthRequestQueue, HealthRng, HealthSpent, HealthStatusApplied,
+    HealthStatusEffect, HealthStatusEffects, HealthStatusExpired, HealthStatusKind, HealthSync,
+    HealthSystem, HealthSystemF64, HealthSystemI32, HealthSystemModifier, HealthSystemModifiers,
+    HealthSystemReviveHealType, HealthSystemState, HealthText, HealthTextFormat, HealthThresholds,
+    HealthTransferEvent, HealthTransferred, HitInvulnerability, HitZone, InCombat, InSafeZone,
+    IntegerHealth, Invulnerability, Kill, LastDamage, Lifesteal, Lives, Loot, LootDropped,
+    MaxHealthBonus, MaxHealthChangePolicy, MaxHealthModifiers, OutOfLives, Parried, PartDestroyed,
+    PhaseChanged, Player, Poise, PredictedHealth, RecentAttackers, RecentHit, Resistances,
+    RespawnPoint, RespawnTimer, ReviveEvent, RevivedEvent, Rounding, SafeZone, SaveId,
+    SharedHealthMember, SharedHealthPool, Shield, ShieldBroken, ShieldRecharge,
+    ShieldRechargeStarted, ShieldRecharged, SmoothedHealth, SpawnProtection,
+    SpawnProtectionExpired, Staggered, Team, TeamFilter, TemporaryHealth, Thorns, ThresholdCrossed,
+    WorldHealthBar,
+};
+
+/// Add this plugin to your app to let systems and events of this crate do their work.
+/// Using [`HealthSystem`](crate::HealthSystem) alone does not require it.
+///
+/// Components that only work with a [`HealthSystem`](crate::HealthSystem) get a default one if their entity has none,
+/// eg: a [`Shield`] added to an entity without health would never absorb anything. They are
+/// [`Shield`], [`TemporaryHealth`], [`Armor`], [`Resistances`], [`DamageImmunities`], [`DamageCap`],
+/// [`HealthRegen`], [`HealthDecay`], [`MaxHealthModifiers`], [`HealthThresholds`] and [`HitInvulnerability`].
+/// Members of a shared health pool are left alone, the [`SharedHealthPool`] holds their health.
+/// The system is inserted in `PreUpdate`, components added later in a frame get it the next frame.
+/// # Examples
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_health_system::HealthSystemPlugin;
+///
+/// App::new()
+///     .add_plugins((DefaultPlugins, HealthSystemPlugin))
+///     .run();
+/// ```
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{HealthSystem, HealthSystemPlugin, Shield};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app.world.spawn(Shield::new(25.0)).id();
+/// app.update();
+/// assert_eq!(100.0, app.world.get::<HealthSystem>(entity).unwrap().get_health_max());
+/// ```
+#[derive(Debug, Default)]
+pub struct HealthSystemPlugin;
+
+/// The sets the [`HealthSystemPlugin`] runs its systems in, one after another in `Update`.
+/// Order your systems relative to them, eg: knockback after [`HealthSet::Apply`], UI after [`HealthSet::React`].
+/// Requests sent before [`HealthSet::Accumulate`] are applied the same frame.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageAppliedEvent, DamageEvent, HealthSet, HealthSystem, HealthSystemPlugin};
+///
+/// #[derive(Resource, Default)]
+/// struct Knockbacks(usize);
+///
+/// fn knockback(mut hits: EventReader<DamageAppliedEvent>, mut knockbacks: ResMut<Knockbacks>) {
+///     knockbacks.0 += hits.iter().count();
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin))
+///     .init_resource::<Knockbacks>()
+///     .add_systems(Update, knockback.after(HealthSet::Apply));
+///
+/// let entity = app.world.spawn(HealthSystem::new(100.0)).id();
+/// app.world.send_event(DamageEvent::new(entity, 10.0));
+/// app.update();
+/// assert_eq!(1, app.world.resource::<Knockbacks>().0);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, SystemSet)]
+pub enum HealthSet {
+    /// Gather the requests of the frame, eg: damage over time, zones, contact damage, after applying the max health modifiers.
+    /// The components requiring a [`HealthSystem`](crate::HealthSystem) get one in this set of `PreUpdate`.
+    Accumulate,
+    /// Update what mitigates the requests, eg: invulnerability frames, spawn protection, parry windows.
+    Mitigate,
+    /// Apply the damage, heal and transfer requests, running the [`DamagePipeline`](crate::DamagePipeline).
+    /// With [`DamageResolution::FixedUpdate`], requests are applied in this set of `FixedUpdate`.
+    Apply,
+    /// React to the new health, eg: regen, revives, deaths, thresholds and phases.
+    React,
+    /// Clean up and record, eg: death behaviors, corpses, loot, stats, displayed health and the combat log.
+    Cleanup,
+}
+
+impl Plugin for HealthSystemPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DamageTypeRegistry>()
+            .init_resource::<FriendlyFirePolicy>()
+            .init_resource::<GlobalDamageSettings>()
+            .init_resource::<HealthRng>()
+            .init_resource::<Contacts>()
+            .init_resource::<CombatTimeout>()
+            .init_resource::<CombatLog>()
+            .init_resource::<DamageResolution>()
+            .init_resource::<HealthRequestQueue>()
+            .register_type::<AggregateDeathRule>()
+            .register_type::<AggregateHealth>()
+            .register_type::<Armor>()
+            .register_type::<AutoRevive>()
+            .register_type::<BlockState>()
+            .register_type::<BossBar>()
+            .register_type::<CombatRule>()
+            .register_type::<CombatStats>()
+            .register_type::<ContactDamage>()
+            .register_type::<Corpse>()
+            .register_type::<CritStats>()
+            .register_type::<CurrentPhase>()
+            .register_type::<DamageCap>()
+            .register_type::<DamageCause>()
+            .register_type::<DamageDealtMultiplier>()
+            .register_type::<DamageImmunities>()
+            .register_type::<DamageOverTime>()
+            .register_type::<DamageOverTimeEffects>()
+            .register_type::<DamageTakenMultiplier>()
+            .register_type::<DamageType>()
+            .register_type::<DamageZone>()
+            .register_type::<Dead>()
+            .register_type::<DeathBehavior>()
+            .register_type::<DisplayedHealth>()
+            .register_type::<Downable>()
+            .register_type::<Downed>()
+            .register_type::<DpsMeter>()
+            .register_type::<DpsSource>()
+            .register_type::<Endure>()
+            .register_type::<Evasion>()
+            .register_type::<ExecuteThreshold>()
+            .register_type::<Fraction>()
+            .register_type::<HealOverTime>()
+            .register_type::<HealOverTimeEffects>()
+            .register_type::<HealZone>()
+            .register_type::<HealingAura>()
+            .register_type::<HealingModifier>()
+            .register_type::<HealthBar>()
+            .register_type::<HealthDecay>()
+            .register_type::<HealthPart>()
+            .register_type::<HealthPhases>()
+            .register_type::<HealthRegen>()
+            .register_type::<HealthRng>()
+            .register_type::<HealthStatusEffect>()
+            .register_type::<HealthStatusEffects>()
+            .register_type::<HealthStatusKind>()
+            .register_type::<HealthSync<u8>>()
+            .register_type::<HealthSync<u16>>()
+            .register_type::<HealthSystem>()
+            .register_type::<HealthSystemF64>()
+            .register_type::<HealthSystemI32>()
+            .register_type::<HealthSystemModifier>()
+            .register_type::<HealthSystemModifiers>()
+            .register_type::<HealthSystemReviveHealType>()
+            .register_type::<HealthSystemState>()
+            .register_type::<HealthText>()
+            .register_type::<HealthTextFormat>()
+            .register_type::<HealthThresholds>()
+            .register_type::<HitInvulnerability>()
+            .register_type::<HitZone>()
+            .register_type::<InCombat>()
+            .register_type::<InSafeZone>()
+            .register_type::<IntegerHealth>()
+            .register_type::<Invulnerability>()
+            .register_type::<LastDamage>()
+            .register_type::<Lifesteal>()
+            .register_type::<Lives>()
+            .register_type::<Loot>()
+            .register_type::<MaxHealthBonus>()
+            .register_type::<MaxHealthChangePolicy>()
+            .register_type::<MaxHealthModifiers>()
+            .register_type::<OverTimeTicker>()
+            .register_type::<Player>()
+            .register_type::<Poise>()
+            .register_type::<PredictedHealth>()
+            .register_type::<RecentAttackers>()
+            .register_type::<RecentHit>()
+            .register_type::<Resistances>()
+            .register_type::<RespawnPoint>()
+            .register_type::<RespawnTimer>()
+            .register_type::<Rounding>()
+            .register_type::<SafeZone>()
+            .register_type::<SaveId>()
+            .register_type::<SharedHealthMember>()
+            .register_type::<SharedHealthPool>()
+            .register_type::<Shield>()
+            .register_type::<ShieldRecharge>()
+            .register_type::<SmoothedHealth>()
+            .register_type::<SpawnProtection>()
+            .register_type::<Team>()
+            .register_type::<TeamFilter>()
+            .register_type::<TemporaryHealth>()
+            .register_type::<Thorns>()
+            .register_type::<WorldHealthBar>()
+            // Fields of the types above, registered by plugins missing from `MinimalPlugins` or not at all.
+            .register_type::<Color>()
+            .register_type::<Transform>()
+            .register_type::<TimerMode>()
+            .register_type::<Vec<(Cow<'static, str>, MaxHealthBonus)>>()
+            .register_type::<(Cow<'static, str>, MaxHealthBonus)>()
+            .register_type::<Option<Timer>>()
+            .register_type::<(f32, Color)>()
+            .register_type::<Option<f32>>()
+            .register_type::<VecDeque<(f64, f32)>>()
+            .register_type::<(f64, f32)>()
+            .register_type::<Option<Entity>>()
+            .register_type::<Option<(f32, Color)>>()
+            .register_type::<Vec<f32>>()
+            .register_type::<Option<Duration>>()
+            .register_type::<Option<DamageCause>>()
+            .register_type::<VecDeque<RecentHit>>()
+            .register_type::<HashMap<DamageType, f32>>()
+            .register_type::<HashSet<DamageType>>()
+            .register_type::<Vec<DamageOverTime>>()
+            .register_type::<Vec<HealOverTime>>()
+            .register_type::<Vec<HealthStatusEffect>>()
+            .add_damage_modifier(DamageStage::PreMitigation, friendly_fire_stage)
+            .add_damage_modifier(DamageStage::PreMitigation, immunity_stage)
+            .add_damage_modifier(DamageStage::PreMitigation, evasion_stage)
+            .add_damage_modifier(DamageStage::PreMitigation, crit_stage)
+            .add_damage_modifier(DamageStage::PreMitigation, block_stage)
+            .add_damage_modifier(DamageStage::PreMitigation, poise_stage)
+            .add_damage_modifier(DamageStage::PreMitigation, damage_taken_stage)
+            .add_damage_modifier(DamageStage::PreMitigation, damage_multiplier_stage)
+            .add_damage_modifier(DamageStage::PreMitigation, global_damage_stage)
+            .add_damage_modifier(DamageStage::Mitigation, resistances_stage)
+            .add_damage_modifier(DamageStage::Mitigation, shield_stage)
+            .add_damage_modifier(DamageStage::Mitigation, temporary_health_stage)
+            .add_damage_modifier(DamageStage::Mitigation, armor_stage)
+            .add_damage_modifier(DamageStage::PostMitigation, execute_stage)
+            .add_damage_modifier(DamageStage::PostMitigation, damage_cap_stage)
+            .add_damage_modifier(DamageStage::PostMitigation, integer_health_stage)
+            .add_event::<DamageEvent>()
+            .add_event::<DamageAppliedEvent>()
+            .add_event::<DamageDodged>()
+            .add_event::<DamageImmune>()
+            .add_event::<BlockedHit>()
+            .add_event::<Parried>()
+            .add_event::<Staggered>()
+            .add_event::<HealEvent>()
+            .add_event::<HealthSpent>()
+            .add_event::<HealthTransferEvent>()
+            .add_event::<HealthTransferred>()
+            .add_event::<DeathEvent>()
+            .add_event::<Kill>()
+            .add_event::<DownedEvent>()
+            .add_event::<EnduredLethalHit>()
+            .add_event::<ReviveEvent>()
+            .add_event::<RevivedEvent>()
+            .add_event::<AutoRevived>()
+            .add_event::<CorpseRemoved>()
+            .add_event::<LootDropped>()
+            .add_event::<PartDestroyed>()
+            .add_event::<FriendlyFireBlocked>()
+            .add_event::<OutOfLives>()
+            .add_event::<SpawnProtectionExpired>()
+            .add_event::<ThresholdCrossed>()
+            .add_event::<HealthMispredicted>()
+            .add_event::<PhaseChanged>()
+            .add_event::<ShieldBroken>()
+            .add_event::<ShieldRechargeStarted>()
+            .add_event::<ShieldRecharged>()
+            .add_event::<ApplyDamageOverTime>()
+            .add_event::<DamageOverTimeApplied>()
+            .add_event::<DamageOverTimeExpired>()
+            .add_event::<ApplyHealOverTime>()
+            .add_event::<HealOverTimeApplied>()
+            .add_event::<HealOverTimeExpired>()
+            .add_event::<ApplyHealthStatusEffect>()
+            .add_event::<HealthStatusApplied>()
+            .add_event::<HealthStatusExpired>()
+            .configure_sets(
+                Update,
+                (
+                    HealthSet::Accumulate,
+                    HealthSet::Mitigate,
+                    HealthSet::Apply,
+                    HealthSet::React,
+                    HealthSet::Cleanup,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    apply_max_health_modifiers,
+                    apply_damage_over_time,
+                    tick_damage_over_time,
+                    apply_heal_over_time,
+                    tick_heal_over_time,
+                    apply_health_status_effects,
+                    tick_health_status_effects,
+                    (
+                        apply_contact_damage,
+                        apply_zones,
+                        update_safe_zones,
+                        apply_healing_auras,
+                    ),
+                    queue_health_requests.run_if(resource_equals(DamageResolution::FixedUpdate)),
+                )
+                    .chain()
+                    .in_set(HealthSet::Accumulate),
+            )
+            .add_systems(
+                Update,
+                (
+                    tick_invulnerability,
+                    tick_spawn_protection,
+                    tick_healing_modifiers,
+                    tick_parry_windows,
+                )
+                    .in_set(HealthSet::Mitigate),
+            )
+            .add_systems(
+                Update,
+                (
+                    apply_damage_events.run_if(resource_equals(DamageResolution::Immediate)),
+                    (apply_lifesteal, reflect_damage),
+                    apply_heal_events.run_if(resource_equals(DamageResolution::Immediate)),
+                    apply_health_transfers,
+                )
+                    .chain()
+                    .in_set(HealthSet::Apply),
+            )
+            .add_systems(
+                Update,
+                (
+                    update_combat_state,
+                    (
+                        regenerate_health,
+                        decay_health,
+                        regenerate_poise,
+                        recharge_shields,
+                        decay_temporary_health,
+                        record_last_damage,
+                        grant_hit_invulnerability,
+                    ),
+                    (bleed_out, tick_respawns, tick_auto_revives),
+                    apply_revive_events,
+                    (detect_deaths, detect_threshold_crossings, update_phases),
+                    propagate_shared_deaths,
+                    (update_aggregate_health, send_kills).chain(),
+                )
+                    .chain()
+                    .in_set(HealthSet::React),
+            )
+            .add_systems(
+                Update,
+                (
+                    apply_death_behavior,
+                    drop_loot,
+                    start_respawns,
+                    start_auto_revives,
+                    remove_corpses,
+                    remove_dead_marker,
+                    revive_shared_members,
+                    update_combat_stats,
+                    update_dps_meters,
+                    update_displayed_health,
+                    update_smoothed_health,
+                    reconcile_predicted_health,
+                    sync_encoded_health::<u8>,
+                    sync_encoded_health::<u16>,
+                    record_combat_log,
+                )
+                    .in_set(HealthSet::Cleanup),
+            )
+            .add_systems(
+                PreUpdate,
+                require_health_system.in_set(HealthSet::Accumulate),
+            )
+            .add_systems(
+                FixedUpdate,
+                resolve_health_requests
+                    .run_if(resource_equals(DamageResolution::FixedUpdate))
+                    .in_set(HealthSet::Apply),
+            );
+
+        // Sets are reflected as values, serializing them needs serde.
+        #[cfg(feature = "serde")]
+        app.register_type_data::<HashSet<DamageType>, ReflectSerialize>()
+            .register_type_data::<HashSet<DamageType>, ReflectDeserialize>();
+        #[cfg(feature = "inspector")]
+        crate::inspector::register_health_system_inspector(app);
+        #[cfg(feature = "preset")]
+        if app.world.contains_resource::<AssetServer>() {
+            app.add_asset::<crate::HealthPreset>()
+                .init_asset_loader::<crate::HealthPresetLoader>()
+                .register_type::<crate::HealthPresetHandle>()
+                .add_systems(
+                    PreUpdate,
+                    crate::preset::apply_health_presets
+                        .after(require_health_system)
+                        .in_set(HealthSet::Accumulate),
+                );
+        }
+
+        #[cfg(feature = "rapier")]
+        app.add_systems(
+            Update,
+            crate::contact::track_rapier_contacts.before(apply_contact_damage),
+        );
+        #[cfg(feature = "avian")]
+        app.add_systems(
+            Update,
+            crate::contact::track_avian_contacts.before(apply_contact_damage),
+        );
+    }
+}
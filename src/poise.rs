@@ -0,0 +1,121 @@
+//! Poise, a second pool broken by hits to stagger an entity.
+use bevy::prelude::*;
+
+use crate::DamageContext;
+
+/// A pool lowered by every [`DamageEvent`](crate::DamageEvent) landing on an entity, along with its health.
+/// Once empty, [`Staggered`] is sent and the poise is reset to `max`.
+/// Each hit lowers it by [`DamageEvent::poise_damage`](crate::DamageEvent::poise_damage), or the amount of damage by default.
+/// # Fields
+/// * `current`: The poise left.
+/// * `max`: The poise the entity resets to.
+/// * `regen`: The poise recovered per second.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, HealthSystem, HealthSystemPlugin, Poise, Staggered};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app.world.spawn((HealthSystem::new(100.0), Poise::new(30.0, 0.0))).id();
+/// app.world.send_event(DamageEvent::new(entity, 5.0).with_poise_damage(20.0));
+/// app.update();
+/// assert_eq!(10.0, app.world.get::<Poise>(entity).unwrap().current);
+///
+/// app.world.send_event(DamageEvent::new(entity, 10.0));
+/// app.update();
+/// assert_eq!(30.0, app.world.get::<Poise>(entity).unwrap().current);
+/// assert_eq!(1, app.world.resource::<Events<Staggered>>().len());
+/// ```
+/// The poise is only lowered once the damage is applied, a cancelled hit leaves it untouched.
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageContext, DamageEvent, DamagePipelineAppExt, DamageStage, HealthSystem,
+///     HealthSystemPlugin, Poise, Staggered,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.add_damage_modifier(
+///     DamageStage::PostMitigation,
+///     |context: &mut DamageContext, _: &mut World| context.cancel(),
+/// );
+///
+/// let entity = app.world.spawn((HealthSystem::new(100.0), Poise::new(30.0, 0.0))).id();
+/// app.world.send_event(DamageEvent::new(entity, 50.0));
+/// app.update();
+/// assert_eq!(30.0, app.world.get::<Poise>(entity).unwrap().current);
+/// assert!(app.world.resource::<Events<Staggered>>().is_empty());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Poise {
+    pub current: f32,
+    pub max: f32,
+    pub regen: f32,
+}
+
+impl Poise {
+    /// Create a new, full, poise pool.
+    /// # Arguments
+    /// * `max`: The poise the entity resets to.
+    /// * `regen`: The poise recovered per second.
+    pub fn new(max: f32, regen: f32) -> Self {
+        Self {
+            current: max,
+            max,
+            regen,
+        }
+    }
+
+    /// Lower the poise, returns true if it broke, resetting it to `max`.
+    pub fn damage(&mut self, amount: f32) -> bool {
+        self.current -= amount;
+        if self.current > 0.0 {
+            return false;
+        }
+
+        self.current = self.max;
+        true
+    }
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when the [`Poise`] of an entity broke.
+/// # Fields
+/// * `entity`: The staggered entity.
+/// * `source`: The entity that broke its poise, if any.
+#[derive(Debug, Clone, Event)]
+pub struct Staggered {
+    pub entity: Entity,
+    pub source: Option<Entity>,
+}
+
+pub(crate) fn regenerate_poise(time: Res<Time>, mut query: Query<&mut Poise>) {
+    for mut poise in &mut query {
+        if poise.current < poise.max {
+            poise.current = (poise.current + poise.regen * time.delta_seconds()).min(poise.max);
+        }
+    }
+}
+
+pub(crate) fn poise_stage(context: &mut DamageContext, world: &mut World) {
+    if world.get::<Poise>(context.target).is_some() {
+        context.poise_damage = context.event.poise_damage.unwrap_or(context.amount);
+    }
+}
+
+/// Lower the [`Poise`] of the target by the poise damage of an applied hit.
+pub(crate) fn apply_poise_damage(context: &DamageContext, world: &mut World) {
+    let Some(mut poise) = world.get_mut::<Poise>(context.target) else {
+        return;
+    };
+
+    if poise.damage(context.poise_damage) {
+        world.send_event(Staggered {
+            entity: context.hit,
+            source: context.event.source,
+        });
+    }
+}
@@ -0,0 +1,189 @@
+//! Client side prediction of health, reconciled with the authoritative health of the server.
+use bevy::prelude::*;
+
+use crate::HealthSystem;
+
+/// The health predicted by a client, eg: to show a hit at once instead of waiting for the server.
+/// Predict the damage and heals of the local player with [`PredictedHealth::predict_damage`] and [`PredictedHealth::predict_heal`],
+/// while the [`HealthSystem`] holds the authoritative health, eg: replicated by the server.
+/// Each time the [`HealthSystem`] changes, the [`HealthSystemPlugin`](crate::HealthSystemPlugin) reconciles the prediction with it:
+/// a prediction off by more than `tolerance` sends a [`HealthMispredicted`] event, and the predicted health
+/// smoothly moves to the authoritative health instead of snapping, it closes `1 - e^-smoothing` of the distance each second.
+/// Without [`HealthSystem`], call [`PredictedHealth::reconcile`] with the authoritative health yourself.
+/// # Fields
+/// * `smoothing`: How fast corrections are applied, higher is faster, `f32::INFINITY` snaps.
+/// * `tolerance`: The largest error that is not reported as a misprediction.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageEvent, HealthMispredicted, HealthSystem, HealthSystemPlugin, PredictedHealth,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+///
+/// // Halve the correction left every second.
+/// let entity = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), PredictedHealth::new(2f32.ln())))
+///     .id();
+/// app.update();
+///
+/// // The client predicts a hit of 30, the server only applies 20.
+/// app.world.get_mut::<PredictedHealth>(entity).unwrap().predict_damage(30.0);
+/// assert_eq!(70.0, app.world.get::<PredictedHealth>(entity).unwrap().health());
+/// app.world.send_event(DamageEvent::new(entity, 20.0));
+/// app.update();
+///
+/// let events = app.world.resource::<Events<HealthMispredicted>>();
+/// let misprediction = events.iter_current_update_events().next().unwrap();
+/// assert_eq!((70.0, 80.0), (misprediction.predicted, misprediction.authoritative));
+/// assert_eq!(70.0, app.world.get::<PredictedHealth>(entity).unwrap().health());
+///
+/// app.update();
+/// let predicted = app.world.get::<PredictedHealth>(entity).unwrap();
+/// assert!((predicted.health() - 75.0).abs() < 0.01);
+/// ```
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct PredictedHealth {
+    pub smoothing: f32,
+    pub tolerance: f32,
+    authoritative: Option<f32>,
+    max: f32,
+    predicted: Option<f32>,
+    correction: f32,
+    mispredicted: Option<f32>,
+}
+
+impl PredictedHealth {
+    /// Create a prediction, starting at the authoritative health.
+    /// # Arguments
+    /// * `smoothing`: How fast corrections are applied, higher is faster, `f32::INFINITY` snaps.
+    pub fn new(smoothing: f32) -> Self {
+        Self {
+            smoothing,
+            tolerance: 0.01,
+            authoritative: None,
+            max: 0.0,
+            predicted: None,
+            correction: 0.0,
+            mispredicted: None,
+        }
+    }
+
+    /// Set the largest error that is not reported as a misprediction.
+    pub fn with_tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// The health to display: the prediction if any, else the authoritative health with the correction left.
+    pub fn health(&self) -> f32 {
+        self.predicted
+            .unwrap_or(self.authoritative() + self.correction)
+    }
+
+    /// The last authoritative health, `0.0` before the first one.
+    pub fn authoritative(&self) -> f32 {
+        self.authoritative.unwrap_or_default()
+    }
+
+    /// Returns true while a local prediction waits for the authoritative health.
+    pub fn is_predicting(&self) -> bool {
+        self.predicted.is_some()
+    }
+
+    /// Predict damage taken by the entity, kept until the next authoritative health.
+    pub fn predict_damage(&mut self, amount: f32) {
+        self.predicted = Some((self.health() - amount).max(0.0));
+    }
+
+    /// Predict a heal of the entity, kept until the next authoritative health.
+    pub fn predict_heal(&mut self, amount: f32) {
+        let health = self.health() + amount;
+        self.predicted = Some(if self.max > 0.0 {
+            health.min(self.max)
+        } else {
+            health
+        });
+    }
+
+    /// Replace the prediction with the authoritative health, the difference is smoothed out over time.
+    /// Returns the error of the prediction, the predicted minus the authoritative health.
+    /// # Arguments
+    /// * `authoritative`: The health sent by the server.
+    /// * `max`: The max health sent by the server.
+    pub fn reconcile(&mut self, authoritative: f32, max: f32) -> f32 {
+        let error = match self.authoritative {
+            Some(_) => self.health() - authoritative,
+            None => 0.0,
+        };
+        if error.abs() > self.tolerance {
+            self.mispredicted = Some(self.health());
+        }
+        self.authoritative = Some(authoritative);
+        self.max = max;
+        self.predicted = None;
+        self.correction = error;
+        error
+    }
+}
+
+/// A prediction of `10.0` smoothing.
+impl Default for PredictedHealth {
+    fn default() -> Self {
+        Self::new(10.0)
+    }
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when a [`PredictedHealth`] was off by more than its tolerance.
+/// # Fields
+/// * `entity`: The entity whose health was mispredicted.
+/// * `predicted`: The health predicted by the client.
+/// * `authoritative`: The health sent by the server.
+#[derive(Debug, Clone, Event)]
+pub struct HealthMispredicted {
+    pub entity: Entity,
+    pub predicted: f32,
+    pub authoritative: f32,
+}
+
+pub(crate) fn reconcile_predicted_health(
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut PredictedHealth, Option<Ref<HealthSystem>>)>,
+    mut mispredicted: EventWriter<HealthMispredicted>,
+) {
+    for (entity, mut predicted, health_system) in &mut query {
+        // Smooth the correction left before reconciling, a new correction starts from the predicted health.
+        if predicted.correction != 0.0 {
+            let remaining = if predicted.smoothing.is_infinite() {
+                0.0
+            } else {
+                predicted.correction * (-predicted.smoothing * time.delta_seconds()).exp()
+            };
+            predicted.correction = if remaining.abs() < 0.001 {
+                0.0
+            } else {
+                remaining
+            };
+        }
+
+        if let Some(health_system) = health_system
+            .filter(|health_system| health_system.is_changed() || predicted.authoritative.is_none())
+        {
+            predicted.reconcile(health_system.get_health(), health_system.get_health_max());
+        }
+        if let Some(health) = predicted.mispredicted {
+            predicted.mispredicted = None;
+            mispredicted.send(HealthMispredicted {
+                entity,
+                predicted: health,
+                authoritative: predicted.authoritative(),
+            });
+        }
+    }
+}
@@ -0,0 +1,220 @@
+//! Health presets, eg: the stats of an enemy type, loaded from RON assets and hot reloaded.
+use std::time::Duration;
+
+use bevy::asset::{AssetLoader, BoxedFuture, LoadContext, LoadedAsset};
+use bevy::prelude::*;
+use bevy::reflect::{TypePath, TypeUuid};
+use bevy::utils::HashMap;
+use serde::Deserialize;
+
+use crate::{
+    Armor, DamageType, HealthRegen, HealthSystem, MaxHealthChangePolicy, MaxHealthModifiers,
+    Resistances,
+};
+
+/// The health stats of a kind of entity, loaded from `.health.ron` files, see [`HealthPresetHandle`].
+/// Presets are hot reloaded if the `AssetPlugin` watches for changes, making balance changes live.
+/// # Fields
+/// * `max_health`: The max health.
+/// * `armor`: The [`Armor`], if any.
+/// * `resistances`: The [`Resistances`] multiplier by name of [`DamageType`], eg: `"fire": 0.5`.
+/// * `regen`: The [`HealthRegen`], if any.
+/// # Examples
+/// ```
+/// use bevy_health_system::{DamageType, HealthPreset};
+///
+/// let goblin = HealthPreset::from_ron(
+///     r#"(
+///         max_health: 30.0,
+///         armor: Some((flat_reduction: 2.0, percent_reduction: 0.0)),
+///         resistances: {"fire": 1.5},
+///         regen: Some((rate_per_second: 1.0, delay_after_damage: 3.0)),
+///     )"#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(30.0, goblin.max_health);
+/// assert_eq!(1.5, goblin.resistances().get_multiplier(DamageType::FIRE));
+/// ```
+#[derive(Debug, Clone, Deserialize, TypeUuid, TypePath)]
+#[uuid = "0b6f8a53-1c2e-4d7a-8f35-2e9c4a61d7b8"]
+#[serde(default)]
+pub struct HealthPreset {
+    pub max_health: f32,
+    pub armor: Option<Armor>,
+    pub resistances: HashMap<String, f32>,
+    pub regen: Option<HealthPresetRegen>,
+}
+
+impl Default for HealthPreset {
+    fn default() -> Self {
+        Self {
+            max_health: 100.0,
+            armor: None,
+            resistances: HashMap::default(),
+            regen: None,
+        }
+    }
+}
+
+impl HealthPreset {
+    /// Parse a preset from RON.
+    pub fn from_ron(ron: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(ron)
+    }
+
+    /// Returns the [`Resistances`] of the preset.
+    pub fn resistances(&self) -> Resistances {
+        self.resistances.iter().fold(
+            Resistances::default(),
+            |resistances, (name, &multiplier)| resistances.with(DamageType::new(name), multiplier),
+        )
+    }
+}
+
+/// The [`HealthRegen`] of a [`HealthPreset`].
+/// # Fields
+/// * `rate_per_second`: The health regenerated per second.
+/// * `delay_after_damage`: The seconds without damage before regenerating.
+#[derive(Debug, Default, PartialEq, Copy, Clone, Deserialize)]
+#[serde(default)]
+pub struct HealthPresetRegen {
+    pub rate_per_second: f32,
+    pub delay_after_damage: f32,
+}
+
+/// Loads [`HealthPreset`]s from `.health.ron` files.
+#[derive(Debug, Default)]
+pub struct HealthPresetLoader;
+
+impl AssetLoader for HealthPresetLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let preset = ron::de::from_bytes::<HealthPreset>(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(preset));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["health.ron"]
+    }
+}
+
+/// Initialize the health components of this entity from a [`HealthPreset`], and update them when it is reloaded.
+/// The preset owns the [`HealthSystem`], [`Armor`], [`Resistances`] and [`HealthRegen`] of the entity:
+/// they are inserted, updated or removed to match it. A reload keeps the fraction of max health,
+/// the base of the [`MaxHealthModifiers`] is set instead of the max health if the entity has them.
+/// Presets are registered by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) if the `AssetPlugin` is added before it,
+/// load them with the `AssetServer`, eg: `asset_server.load("enemies/goblin.health.ron")`.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{Armor, HealthPreset, HealthPresetHandle, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, AssetPlugin::default(), HealthSystemPlugin));
+///
+/// let goblin = app.world.resource_mut::<Assets<HealthPreset>>().add(HealthPreset {
+///     max_health: 30.0,
+///     armor: Some(Armor::new(2.0, 0.0)),
+///     ..default()
+/// });
+/// let entity = app.world.spawn(HealthPresetHandle(goblin.clone())).id();
+/// app.update();
+/// assert_eq!(30.0, app.world.get::<HealthSystem>(entity).unwrap().get_health_max());
+/// assert_eq!(2.0, app.world.get::<Armor>(entity).unwrap().flat_reduction);
+///
+/// // Balance change: goblins are tougher, without armor.
+/// let mut presets = app.world.resource_mut::<Assets<HealthPreset>>();
+/// let preset = presets.get_mut(&goblin).unwrap();
+/// preset.max_health = 40.0;
+/// preset.armor = None;
+/// // Reloads are applied once the `AssetEvents` of the frame are sent.
+/// app.update();
+/// app.update();
+/// assert_eq!(40.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// assert!(app.world.get::<Armor>(entity).is_none());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct HealthPresetHandle(pub Handle<HealthPreset>);
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn apply_health_presets(
+    mut commands: Commands,
+    presets: Res<Assets<HealthPreset>>,
+    mut preset_events: EventReader<AssetEvent<HealthPreset>>,
+    mut entities: Query<(
+        Entity,
+        Ref<HealthPresetHandle>,
+        Option<&mut HealthSystem>,
+        Option<&mut MaxHealthModifiers>,
+        Option<&mut HealthRegen>,
+    )>,
+) {
+    let reloaded: Vec<_> = preset_events
+        .iter()
+        .filter_map(|event| match event {
+            AssetEvent::Created { handle } | AssetEvent::Modified { handle } => Some(handle.id()),
+            AssetEvent::Removed { .. } => None,
+        })
+        .collect();
+
+    for (entity, handle, health_system, max_health_modifiers, regen) in &mut entities {
+        if !handle.is_changed() && !reloaded.contains(&handle.0.id()) {
+            continue;
+        }
+        let Some(preset) = presets.get(&handle.0) else {
+            continue;
+        };
+        let mut entity = commands.entity(entity);
+
+        let has_max_health_modifiers = max_health_modifiers.is_some();
+        if let Some(mut max_health_modifiers) = max_health_modifiers {
+            max_health_modifiers.base = preset.max_health;
+        }
+        match health_system {
+            Some(mut health_system) if !has_max_health_modifiers => {
+                health_system.set_health_max_with_policy(
+                    preset.max_health,
+                    MaxHealthChangePolicy::ScaleProportionally,
+                );
+            }
+            Some(_) => {}
+            None => {
+                entity.insert(HealthSystem::new(preset.max_health));
+            }
+        }
+
+        match preset.armor.clone() {
+            Some(armor) => entity.insert(armor),
+            None => entity.remove::<Armor>(),
+        };
+        if preset.resistances.is_empty() {
+            entity.remove::<Resistances>();
+        } else {
+            entity.insert(preset.resistances());
+        }
+        match (preset.regen, regen) {
+            (Some(preset_regen), Some(mut regen)) => {
+                regen.rate_per_second = preset_regen.rate_per_second;
+                regen.delay_after_damage =
+                    Duration::from_secs_f32(preset_regen.delay_after_damage.max(0.0));
+            }
+            (Some(preset_regen), None) => {
+                entity.insert(HealthRegen::new(
+                    preset_regen.rate_per_second,
+                    Duration::from_secs_f32(preset_regen.delay_after_damage.max(0.0)),
+                ));
+            }
+            (None, _) => {
+                entity.remove::<HealthRegen>();
+            }
+        }
+    }
+}
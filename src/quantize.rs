@@ -0,0 +1,147 @@
+//! Health packed into a few bits, eg: to sync the health of many entities over the network.
+use bevy::prelude::*;
+use bevy::reflect::TypePath;
+
+use crate::HealthSystem;
+
+/// An unsigned integer health is packed into by [`encode_health`], as a fraction of max health.
+pub trait HealthQuantum:
+    Copy + Default + PartialEq + Reflect + TypePath + FromReflect + Send + Sync + 'static
+{
+    /// The encoded value of full health.
+    const STEPS: u32;
+
+    /// Convert from a step, at most [`HealthQuantum::STEPS`].
+    fn from_step(step: u32) -> Self;
+
+    /// Convert to a step.
+    fn step(self) -> u32;
+}
+
+impl HealthQuantum for u8 {
+    const STEPS: u32 = u8::MAX as u32;
+
+    fn from_step(step: u32) -> Self {
+        step.min(Self::STEPS) as u8
+    }
+
+    fn step(self) -> u32 {
+        self as u32
+    }
+}
+
+impl HealthQuantum for u16 {
+    const STEPS: u32 = u16::MAX as u32;
+
+    fn from_step(step: u32) -> Self {
+        step.min(Self::STEPS) as u16
+    }
+
+    fn step(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Pack the health as a fraction of max health, rounded to the nearest step.
+/// Any positive health encodes to at least one step, so a living entity never decodes as dead.
+/// # Arguments
+/// * `health`: The health to encode.
+/// * `max_health`: The max health, the receiver decodes with the same max health.
+/// # Examples
+/// ```
+/// use bevy_health_system::{decode_health, encode_health};
+///
+/// let encoded: u8 = encode_health(50.0, 100.0);
+/// assert_eq!(128, encoded);
+/// assert!((decode_health(encoded, 100.0) - 50.0).abs() < 0.5);
+///
+/// // A sliver of health stays alive.
+/// assert_eq!(1, encode_health::<u16>(0.0001, 100.0));
+/// ```
+pub fn encode_health<Q: HealthQuantum>(health: f32, max_health: f32) -> Q {
+    if health <= 0.0 || max_health <= 0.0 {
+        return Q::from_step(0);
+    }
+    let fraction = (health / max_health).min(1.0);
+    let step = (fraction * Q::STEPS as f32).round() as u32;
+    Q::from_step(step.max(1))
+}
+
+/// Unpack a health encoded by [`encode_health`].
+/// # Arguments
+/// * `encoded`: The encoded health.
+/// * `max_health`: The max health it was encoded with.
+pub fn decode_health<Q: HealthQuantum>(encoded: Q, max_health: f32) -> f32 {
+    encoded.step() as f32 / Q::STEPS as f32 * max_health
+}
+
+/// The encoded health of this entity, marked dirty when its [`HealthSystem`] changes to a new encoded value.
+/// Kept up to date by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) for `u8` and `u16`,
+/// send the entities whose sync [`HealthSync::take`] returns a value.
+/// Changes smaller than a step are not sent.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, HealthSync, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app.world.spawn((HealthSystem::new(100.0), HealthSync::<u8>::default())).id();
+/// app.update();
+/// assert_eq!(Some(255), app.world.get_mut::<HealthSync>(entity).unwrap().take());
+///
+/// // Too small to change the encoded health.
+/// app.world.send_event(DamageEvent::new(entity, 0.1));
+/// app.update();
+/// assert_eq!(None, app.world.get_mut::<HealthSync>(entity).unwrap().take());
+///
+/// app.world.send_event(DamageEvent::new(entity, 50.0));
+/// app.update();
+/// assert_eq!(Some(127), app.world.get_mut::<HealthSync>(entity).unwrap().take());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct HealthSync<Q: HealthQuantum = u8> {
+    encoded: Option<Q>,
+    dirty: bool,
+}
+
+impl<Q: HealthQuantum> HealthSync<Q> {
+    /// Returns the last encoded health, if any.
+    pub fn encoded(&self) -> Option<Q> {
+        self.encoded
+    }
+
+    /// Returns true if the encoded health changed since it was last taken.
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Returns the encoded health if it changed since it was last taken, and clear the dirty flag.
+    pub fn take(&mut self) -> Option<Q> {
+        if !self.dirty {
+            return None;
+        }
+        self.dirty = false;
+        self.encoded
+    }
+
+    /// Send the encoded health again, eg: to a client that just joined.
+    pub fn mark_dirty(&mut self) {
+        self.dirty = self.encoded.is_some();
+    }
+}
+
+pub(crate) fn sync_encoded_health<Q: HealthQuantum>(
+    mut query: Query<(&HealthSystem, &mut HealthSync<Q>), Changed<HealthSystem>>,
+) {
+    for (health_system, mut sync) in &mut query {
+        let encoded =
+            encode_health::<Q>(health_system.get_health(), health_system.get_health_max());
+        if sync.encoded != Some(encoded) {
+            sync.encoded = Some(encoded);
+            sync.dirty = true;
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! A system parameter to find entities by their health, eg: for the target selection of a healer AI.
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::{Fraction, HealthSystem, Team};
+
+/// Find entities with a [`HealthSystem`] in one [`SystemParam`], only reading them.
+/// Dead and downed entities are skipped by every helper.
+/// # Examples
+/// ```
+/// use bevy::ecs::system::SystemState;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{Fraction, HealthQuery, HealthSystem, Team};
+///
+/// let mut world = World::new();
+/// let mut wounded = HealthSystem::new(100.0);
+/// wounded.deal_damage(80.0, false);
+/// let wounded = world.spawn((wounded, Team(1), GlobalTransform::from_xyz(5.0, 0.0, 0.0))).id();
+/// let healthy = world.spawn((HealthSystem::new(100.0), Team(1), GlobalTransform::IDENTITY)).id();
+/// let mut enemy = HealthSystem::new(100.0);
+/// enemy.deal_damage(90.0, false);
+/// world.spawn((enemy, Team(2), GlobalTransform::from_xyz(50.0, 0.0, 0.0)));
+///
+/// let mut state = SystemState::<HealthQuery>::new(&mut world);
+/// let health = state.get(&world);
+/// assert_eq!(Some(wounded), health.lowest_health_in(Team(1)));
+/// assert_eq!(2, health.all_below_fraction(Fraction::new(0.3)).count());
+///
+/// let mut nearby: Vec<_> = health.alive_within_radius(Vec3::ZERO, 10.0).collect();
+/// nearby.sort();
+/// let mut allies = vec![wounded, healthy];
+/// allies.sort();
+/// assert_eq!(allies, nearby);
+/// ```
+#[allow(clippy::type_complexity)]
+#[derive(SystemParam)]
+pub struct HealthQuery<'w, 's> {
+    entities: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static HealthSystem,
+            Option<&'static Team>,
+            Option<&'static GlobalTransform>,
+        ),
+    >,
+}
+
+impl HealthQuery<'_, '_> {
+    /// Returns the living member of a [`Team`] with the lowest fraction of max health, if any.
+    pub fn lowest_health_in(&self, team: Team) -> Option<Entity> {
+        self.alive()
+            .filter(|(_, _, other, _)| *other == Some(&team))
+            .min_by(|(_, a, ..), (_, b, ..)| {
+                a.get_health_normalized()
+                    .total_cmp(&b.get_health_normalized())
+            })
+            .map(|(entity, ..)| entity)
+    }
+
+    /// Returns the living entities below a fraction of their max health.
+    /// # Arguments
+    /// * `fraction`: The fraction of max health entities are below, eg: `Fraction::new(0.3)`.
+    pub fn all_below_fraction(&self, fraction: Fraction) -> impl Iterator<Item = Entity> + '_ {
+        self.alive()
+            .filter(move |(_, health_system, ..)| health_system.get_health_fraction() < fraction)
+            .map(|(entity, ..)| entity)
+    }
+
+    /// Returns the living entities with a [`GlobalTransform`] in a sphere.
+    /// # Arguments
+    /// * `center`: The center of the sphere, in world space.
+    /// * `radius`: The radius of the sphere.
+    pub fn alive_within_radius(
+        &self,
+        center: Vec3,
+        radius: f32,
+    ) -> impl Iterator<Item = Entity> + '_ {
+        self.alive()
+            .filter(move |(_, _, _, transform)| {
+                transform.is_some_and(|transform| {
+                    transform.translation().distance_squared(center) <= radius * radius
+                })
+            })
+            .map(|(entity, ..)| entity)
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn alive(
+        &self,
+    ) -> impl Iterator<
+        Item = (
+            Entity,
+            &HealthSystem,
+            Option<&Team>,
+            Option<&GlobalTransform>,
+        ),
+    > + '_ {
+        self.entities
+            .iter()
+            .filter(|(_, health_system, ..)| health_system.is_alive())
+    }
+}
@@ -0,0 +1,107 @@
+//! Deterministic resolution of damage and heal requests in [`FixedUpdate`].
+use bevy::ecs::system::SystemState;
+use bevy::prelude::*;
+
+use crate::damage::apply_damage;
+use crate::heal::HealPipeline;
+use crate::{DamageEvent, DamagePipeline, HealEvent};
+
+/// When the [`HealthSystemPlugin`](crate::HealthSystemPlugin) applies [`DamageEvent`]s and [`HealEvent`]s.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, DamageResolution, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(DamageResolution::FixedUpdate);
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_millis(20)));
+///
+/// let entity = app.world.spawn(HealthSystem::new(100.0)).id();
+/// app.world.send_event(DamageEvent::new(entity, 10.0));
+/// app.update();
+/// assert_eq!(100.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+///
+/// app.update();
+/// assert_eq!(90.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone, Resource)]
+pub enum DamageResolution {
+    /// __Default__. Apply requests in [`Update`], in the order they were sent.
+    #[default]
+    Immediate,
+    /// Queue requests in the [`HealthRequestQueue`] and resolve them once per [`FixedUpdate`],
+    /// sorted by target, then source, then the order they were sent, damage before heals.
+    /// Lockstep and rollback games get the same result for simultaneous hits on every peer.
+    FixedUpdate,
+    /// Queue requests like [`DamageResolution::FixedUpdate`], but resolve them in the `GgrsSchedule` of `bevy_ggrs`,
+    /// see [`HealthRollbackPlugin`](crate::HealthRollbackPlugin).
+    #[cfg(feature = "ggrs")]
+    Rollback,
+}
+
+/// The requests waiting for the next [`FixedUpdate`] under [`DamageResolution::FixedUpdate`].
+#[derive(Debug, Default, Resource)]
+pub struct HealthRequestQueue {
+    damage: Vec<(u64, DamageEvent)>,
+    heal: Vec<(u64, HealEvent)>,
+    sequence: u64,
+}
+
+impl HealthRequestQueue {
+    /// Returns the number of damage requests waiting.
+    pub fn damage_len(&self) -> usize {
+        self.damage.len()
+    }
+
+    /// Returns the number of heal requests waiting.
+    pub fn heal_len(&self) -> usize {
+        self.heal.len()
+    }
+
+    /// Returns true if no request is waiting.
+    pub fn is_empty(&self) -> bool {
+        self.damage.is_empty() && self.heal.is_empty()
+    }
+
+    fn next_sequence(&mut self) -> u64 {
+        self.sequence += 1;
+        self.sequence
+    }
+}
+
+pub(crate) fn queue_health_requests(
+    mut damage: EventReader<DamageEvent>,
+    mut heal: EventReader<HealEvent>,
+    mut queue: ResMut<HealthRequestQueue>,
+) {
+    for event in damage.iter() {
+        let sequence = queue.next_sequence();
+        queue.damage.push((sequence, event.clone()));
+    }
+    for event in heal.iter() {
+        let sequence = queue.next_sequence();
+        queue.heal.push((sequence, event.clone()));
+    }
+}
+
+pub(crate) fn resolve_health_requests(
+    world: &mut World,
+    heal_pipeline: &mut SystemState<HealPipeline>,
+) {
+    let mut damage = std::mem::take(&mut world.resource_mut::<HealthRequestQueue>().damage);
+    damage.sort_by_key(|(sequence, event)| (event.target, event.source, *sequence));
+    world.resource_scope(|world, pipeline: Mut<DamagePipeline>| {
+        for (_, event) in &damage {
+            apply_damage(world, &pipeline, event);
+        }
+    });
+
+    let mut heal = std::mem::take(&mut world.resource_mut::<HealthRequestQueue>().heal);
+    heal.sort_by_key(|(sequence, event)| (event.target, *sequence));
+    let mut pipeline = heal_pipeline.get_mut(world);
+    for (_, event) in &heal {
+        pipeline.apply(event);
+    }
+}
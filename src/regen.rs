@@ -0,0 +1,183 @@
+//! Health regeneration over time.
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    CombatRule, DamageAppliedEvent, GlobalDamageSettings, HealingModifier, HealthSystem, InCombat,
+    IntegerHealth,
+};
+
+/// Regenerate the health of an entity over time.
+/// Regeneration pauses for `delay_after_damage` every time the entity takes damage from a [`DamageEvent`](crate::DamageEvent).
+/// It is scaled like a [`HealEvent`](crate::HealEvent), by the [`HealingModifier`] and [`GlobalDamageSettings::healing`].
+/// With [`IntegerHealth`], the fractions regenerated every frame add up and are healed once rounded to whole health.
+/// # Fields
+/// * `rate_per_second`: Health regenerated every second.
+/// * `delay_after_damage`: How long regeneration waits after the entity was damaged.
+/// * `combat`: Whether regeneration runs in or out of combat, see [`InCombat`].
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{HealthRegen, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// // Let every update last exactly one second.
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+///
+/// let mut health_system = HealthSystem::new(100.0);
+/// health_system.deal_damage(50.0, false);
+/// let entity = app
+///     .world
+///     .spawn((health_system, HealthRegen::new(5.0, Duration::from_secs(3))))
+///     .id();
+///
+/// app.update();
+/// app.update();
+/// assert!(app.world.get::<HealthSystem>(entity).unwrap().get_health() > 50.0);
+/// ```
+/// Halving every heal halves regeneration too, and whole health regenerates whole points.
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     GlobalDamageSettings, HealthRegen, HealthSystem, HealthSystemPlugin, IntegerHealth,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+/// app.world.resource_mut::<GlobalDamageSettings>().healing = 0.5;
+///
+/// let mut health_system = HealthSystem::new(100.0);
+/// health_system.deal_damage(50.0, false);
+/// let halved = app
+///     .world
+///     .spawn((health_system.clone(), HealthRegen::new(10.0, Duration::ZERO)))
+///     .id();
+/// let whole = app
+///     .world
+///     .spawn((health_system, HealthRegen::new(3.0, Duration::ZERO), IntegerHealth::default()))
+///     .id();
+///
+/// app.update();
+/// app.update();
+/// assert_eq!(55.0, app.world.get::<HealthSystem>(halved).unwrap().get_health());
+/// assert_eq!(51.0, app.world.get::<HealthSystem>(whole).unwrap().get_health());
+///
+/// app.update();
+/// assert_eq!(60.0, app.world.get::<HealthSystem>(halved).unwrap().get_health());
+/// assert_eq!(53.0, app.world.get::<HealthSystem>(whole).unwrap().get_health());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct HealthRegen {
+    pub rate_per_second: f32,
+    pub delay_after_damage: Duration,
+    pub combat: CombatRule,
+    since_damage: Option<Duration>,
+    carry: f32,
+}
+
+impl HealthRegen {
+    /// Create a new health regeneration.
+    /// # Arguments
+    /// * `rate_per_second`: Health regenerated every second.
+    /// * `delay_after_damage`: How long regeneration waits after the entity was damaged.
+    pub fn new(rate_per_second: f32, delay_after_damage: Duration) -> Self {
+        Self {
+            rate_per_second,
+            delay_after_damage,
+            combat: CombatRule::default(),
+            since_damage: None,
+            carry: 0.0,
+        }
+    }
+
+    /// Set whether regeneration runs in or out of combat.
+    pub fn with_combat(mut self, combat: CombatRule) -> Self {
+        self.combat = combat;
+        self
+    }
+
+    /// Returns true if the entity is regenerating, false while waiting for the delay after damage.
+    pub fn is_regenerating(&self) -> bool {
+        self.since_damage
+            .is_none_or(|since_damage| since_damage >= self.delay_after_damage)
+    }
+
+    fn on_damaged(&mut self) {
+        self.since_damage = Some(Duration::ZERO);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn regenerate_health(
+    time: Res<Time>,
+    settings: Res<GlobalDamageSettings>,
+    mut damaged: EventReader<DamageAppliedEvent>,
+    mut query: Query<(
+        &mut HealthRegen,
+        &mut HealthSystem,
+        Option<&HealingModifier>,
+        Option<&InCombat>,
+        Option<&IntegerHealth>,
+    )>,
+) {
+    for (mut regen, ..) in &mut query {
+        if let Some(since_damage) = regen.since_damage.as_mut() {
+            *since_damage += time.delta();
+        }
+    }
+
+    for event in damaged.iter() {
+        if let Ok((mut regen, ..)) = query.get_mut(event.target) {
+            regen.on_damaged();
+        }
+    }
+
+    for (mut regen, mut health_system, healing_modifier, in_combat, integer_health) in &mut query {
+        if !regen.is_regenerating()
+            || !regen.combat.allows(in_combat.is_some())
+            || !health_system.is_alive()
+            || health_system.get_health() >= health_system.get_health_max()
+        {
+            regen.carry = 0.0;
+            continue;
+        }
+
+        let amount = regen.rate_per_second * time.delta_seconds();
+        let amount = regenerated(
+            amount,
+            &settings,
+            healing_modifier,
+            integer_health,
+            &mut regen.carry,
+        );
+        health_system.heal(amount);
+    }
+}
+
+/// The health regenerating `amount` heals, scaled like a [`HealEvent`](crate::HealEvent).
+/// With [`IntegerHealth`], the fractions add up in `carry` and are healed once rounded to whole health.
+pub(crate) fn regenerated(
+    amount: f32,
+    settings: &GlobalDamageSettings,
+    healing_modifier: Option<&HealingModifier>,
+    integer_health: Option<&IntegerHealth>,
+    carry: &mut f32,
+) -> f32 {
+    let amount = amount * settings.healing;
+    let amount = healing_modifier.map_or(amount, |modifier| modifier.apply(amount));
+    match integer_health {
+        Some(integer_health) => {
+            *carry += amount;
+            let whole = integer_health.healing.round(*carry);
+            *carry -= whole;
+            whole
+        }
+        None => amount,
+    }
+}
@@ -0,0 +1,164 @@
+//! `bevy_replicon` replication of health, with damage requested by clients and applied by the server.
+use bevy::prelude::*;
+use bevy_replicon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::queue::queue_health_requests;
+use crate::{
+    DamageEvent, DamageOverTimeEffects, DamageType, HealOverTimeEffects, HealthSet,
+    HealthStatusEffects, HealthSystem, Lives, MaxHealthModifiers, Shield, TemporaryHealth,
+};
+
+/// Add this plugin after the `ReplicationPlugins` of `bevy_replicon` to replicate health from the server to its clients.
+/// The [`HealthSystem`], [`Shield`], [`TemporaryHealth`], [`MaxHealthModifiers`], [`Lives`],
+/// [`DamageOverTimeEffects`], [`HealOverTimeEffects`] and [`HealthStatusEffects`] of entities with `Replication` are replicated.
+/// The server is authoritative: clients send [`DamageRequest`]s, the server validates them against
+/// the [`DamageRequestPolicy`] and applies them as [`DamageEvent`]s, the new health then replicates back.
+/// It is not part of the [`HealthSystemPlugin`](crate::HealthSystemPlugin), add both.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageRequest, HealthReplicationPlugin, HealthSystem, HealthSystemPlugin};
+/// use bevy_replicon::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, ReplicationPlugins, HealthSystemPlugin, HealthReplicationPlugin));
+///
+/// let entity = app.world.spawn((HealthSystem::new(100.0), Replication)).id();
+/// // Without client, the app is the server: requests are resent locally from the `SERVER_ID` client.
+/// app.world.send_event(DamageRequest::new(entity, 30.0));
+/// app.update();
+/// app.update();
+/// assert_eq!(70.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// ```
+#[derive(Debug, Default)]
+pub struct HealthReplicationPlugin;
+
+impl Plugin for HealthReplicationPlugin {
+    fn build(&self, app: &mut App) {
+        app.replicate::<HealthSystem>()
+            .replicate::<Shield>()
+            .replicate::<TemporaryHealth>()
+            .replicate::<MaxHealthModifiers>()
+            .replicate::<Lives>()
+            .replicate::<DamageOverTimeEffects>()
+            .replicate::<HealOverTimeEffects>()
+            .replicate::<HealthStatusEffects>()
+            .add_mapped_client_event::<DamageRequest>(EventType::Ordered)
+            .init_resource::<DamageRequestPolicy>()
+            .add_event::<DamageRequestRejected>()
+            .add_systems(
+                Update,
+                apply_damage_requests
+                    .run_if(has_authority())
+                    .in_set(HealthSet::Accumulate)
+                    .before(queue_health_requests),
+            );
+    }
+}
+
+/// Sent by a client to ask the server to damage an entity, see [`HealthReplicationPlugin`].
+/// The target is mapped to the entity of the server before sending.
+/// Accepted requests are applied without source, with the `"client"` [`DamageCause`](crate::DamageCause).
+/// # Fields
+/// * `target`: The entity to damage, it must have `Replication`.
+/// * `amount`: The amount of damage.
+/// * `damage_type`: The [`DamageType`] of the damage.
+#[derive(Debug, Clone, Event, Serialize, Deserialize)]
+pub struct DamageRequest {
+    pub target: Entity,
+    pub amount: f32,
+    pub damage_type: DamageType,
+}
+
+impl DamageRequest {
+    /// Create a new request of [`DamageType::PHYSICAL`] damage.
+    /// # Arguments
+    /// * `target`: The entity to damage.
+    /// * `amount`: The amount of damage.
+    pub fn new(target: Entity, amount: f32) -> Self {
+        Self {
+            target,
+            amount,
+            damage_type: DamageType::PHYSICAL,
+        }
+    }
+
+    /// Set the type of the damage.
+    pub fn with_type(mut self, damage_type: DamageType) -> Self {
+        self.damage_type = damage_type;
+        self
+    }
+}
+
+impl MapNetworkEntities for DamageRequest {
+    fn map_entities<T: Mapper>(&mut self, mapper: &mut T) {
+        self.target = mapper.map(self.target);
+    }
+}
+
+/// How the server validates the [`DamageRequest`]s of its clients.
+/// Requests are always rejected if the amount is not a finite positive number,
+/// or if the target does not have both `Replication` and a [`HealthSystem`].
+/// # Fields
+/// * `max_amount`: The largest amount of damage a single request may deal, unbounded by default.
+/// * `validate`: An extra check of the requests, given the id of the client, eg: an allow list of damage types.
+#[derive(Debug, Clone, Resource)]
+pub struct DamageRequestPolicy {
+    pub max_amount: f32,
+    pub validate: Option<fn(u64, &DamageRequest) -> bool>,
+}
+
+impl Default for DamageRequestPolicy {
+    fn default() -> Self {
+        Self {
+            max_amount: f32::INFINITY,
+            validate: None,
+        }
+    }
+}
+
+impl DamageRequestPolicy {
+    /// Returns true if the request passes the amount checks and the extra check of the policy.
+    pub fn allows(&self, client_id: u64, request: &DamageRequest) -> bool {
+        request.amount.is_finite()
+            && request.amount > 0.0
+            && request.amount <= self.max_amount
+            && self
+                .validate
+                .is_none_or(|validate| validate(client_id, request))
+    }
+}
+
+/// Sent on the server when a [`DamageRequest`] is rejected by the [`DamageRequestPolicy`], eg: to detect cheaters.
+/// # Fields
+/// * `client_id`: The client that sent the request.
+/// * `request`: The rejected request.
+#[derive(Debug, Clone, Event)]
+pub struct DamageRequestRejected {
+    pub client_id: u64,
+    pub request: DamageRequest,
+}
+
+pub(crate) fn apply_damage_requests(
+    mut requests: EventReader<FromClient<DamageRequest>>,
+    policy: Res<DamageRequestPolicy>,
+    targets: Query<(), (With<HealthSystem>, With<Replication>)>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut rejected: EventWriter<DamageRequestRejected>,
+) {
+    for FromClient { client_id, event } in requests.iter() {
+        if !targets.contains(event.target) || !policy.allows(*client_id, event) {
+            rejected.send(DamageRequestRejected {
+                client_id: *client_id,
+                request: event.clone(),
+            });
+            continue;
+        }
+        damage_events.send(
+            DamageEvent::new(event.target, event.amount)
+                .with_type(event.damage_type)
+                .with_cause("client"),
+        );
+    }
+}
@@ -0,0 +1,37 @@
+//! Components that only work on an entity with a [`HealthSystem`], which is inserted if missing.
+use bevy::prelude::*;
+
+use crate::{
+    Armor, DamageCap, DamageImmunities, HealthDecay, HealthRegen, HealthSystem, HealthThresholds,
+    HitInvulnerability, MaxHealthModifiers, Resistances, SharedHealthMember, Shield,
+    TemporaryHealth,
+};
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn require_health_system(
+    mut commands: Commands,
+    query: Query<
+        Entity,
+        (
+            Without<HealthSystem>,
+            Without<SharedHealthMember>,
+            Or<(
+                Added<Shield>,
+                Added<TemporaryHealth>,
+                Added<Armor>,
+                Added<Resistances>,
+                Added<DamageImmunities>,
+                Added<DamageCap>,
+                Added<HealthRegen>,
+                Added<HealthDecay>,
+                Added<MaxHealthModifiers>,
+                Added<HealthThresholds>,
+                Added<HitInvulnerability>,
+            )>,
+        ),
+    >,
+) {
+    for entity in &query {
+        commands.entity(entity).insert(HealthSystem::default());
+    }
+}
@@ -0,0 +1,231 @@
+//! Lives and respawning of dead entities.
+use std::time::Duration;
+
+use bevy::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{DeathEvent, HealthSystem, HealthSystemReviveHealType, ReviveEvent};
+
+/// How many lives an entity has left, one is lost on every death.
+/// Once the last one is lost, [`OutOfLives`] is sent and the entity is not respawned anymore.
+/// Entities without this component have infinite lives.
+/// # Fields
+/// * `remaining`: The lives left, including the current one.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageEvent, HealthSystem, HealthSystemPlugin, HealthSystemReviveHealType, Lives,
+///     OutOfLives, RespawnTimer,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app
+///     .world
+///     .spawn((
+///         HealthSystem::new(100.0),
+///         Lives::new(1),
+///         RespawnTimer::new(Duration::ZERO, HealthSystemReviveHealType::HealFull),
+///     ))
+///     .id();
+///
+/// app.world.send_event(DamageEvent::new(entity, 100.0));
+/// app.update();
+///
+/// assert_eq!(0, app.world.get::<Lives>(entity).unwrap().remaining);
+/// let events = app.world.resource::<Events<OutOfLives>>();
+/// assert_eq!(entity, events.iter_current_update_events().next().unwrap().entity);
+///
+/// app.update();
+/// assert!(app.world.get::<HealthSystem>(entity).unwrap().is_dead());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Lives {
+    pub remaining: u32,
+}
+
+impl Lives {
+    /// Give an entity a number of lives.
+    /// # Arguments
+    /// * `remaining`: The lives left, including the current one.
+    pub fn new(remaining: u32) -> Self {
+        Self { remaining }
+    }
+}
+
+/// Respawn an entity some time after it died, teleporting it to its [`RespawnPoint`] if it has one.
+/// The entity is revived with a [`ReviveEvent`], reviving it manually before that cancels the respawn.
+/// # Fields
+/// * `delay`: How long to wait after death before respawning.
+/// * `heal_type`: How the entity is healed when respawned.
+/// * `invulnerability`: How long the entity is [`Invulnerability`](crate::Invulnerability) after respawning, if at all.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageEvent, Fraction, HealthSystem, HealthSystemPlugin, HealthSystemReviveHealType,
+///     RespawnPoint, RespawnTimer,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+///
+/// let entity = app
+///     .world
+///     .spawn((
+///         HealthSystem::new(100.0),
+///         Transform::from_xyz(10.0, 0.0, 0.0),
+///         RespawnTimer::new(Duration::from_secs(2), HealthSystemReviveHealType::HealFraction(Fraction::HALF)),
+///         RespawnPoint::new(Transform::IDENTITY),
+///     ))
+///     .id();
+///
+/// app.world.send_event(DamageEvent::new(entity, 100.0));
+/// app.update();
+/// assert!(app.world.get::<RespawnTimer>(entity).unwrap().is_pending());
+///
+/// for _ in 0..3 {
+///     app.update();
+/// }
+/// assert_eq!(50.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// assert_eq!(Vec3::ZERO, app.world.get::<Transform>(entity).unwrap().translation);
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct RespawnTimer {
+    pub delay: Duration,
+    pub heal_type: HealthSystemReviveHealType,
+    pub invulnerability: Option<Duration>,
+    pending: Option<Timer>,
+}
+
+impl RespawnTimer {
+    /// Respawn an entity some time after it died.
+    /// # Arguments
+    /// * `delay`: How long to wait after death before respawning.
+    /// * `heal_type`: How the entity is healed when respawned.
+    pub fn new(delay: Duration, heal_type: HealthSystemReviveHealType) -> Self {
+        Self {
+            delay,
+            heal_type,
+            invulnerability: None,
+            pending: None,
+        }
+    }
+
+    /// Make the entity invulnerable for `duration` after respawning.
+    pub fn with_invulnerability(mut self, duration: Duration) -> Self {
+        self.invulnerability = Some(duration);
+        self
+    }
+
+    /// Whether the entity is waiting to respawn.
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// The time left before the entity respawns, if it is waiting to.
+    pub fn remaining(&self) -> Option<Duration> {
+        self.pending.as_ref().map(Timer::remaining)
+    }
+}
+
+/// Where an entity with a [`RespawnTimer`] is teleported to when respawning.
+/// # Fields
+/// * `transform`: The [`Transform`] the entity is given when respawning.
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct RespawnPoint {
+    pub transform: Transform,
+}
+
+impl RespawnPoint {
+    /// Set where an entity respawns.
+    /// # Arguments
+    /// * `transform`: The [`Transform`] the entity is given when respawning.
+    pub fn new(transform: Transform) -> Self {
+        Self { transform }
+    }
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when an entity lost its last [`Lives`].
+/// # Fields
+/// * `entity`: The entity out of lives.
+#[derive(Debug, Clone, Event)]
+pub struct OutOfLives {
+    pub entity: Entity,
+}
+
+pub(crate) fn start_respawns(
+    mut deaths: EventReader<DeathEvent>,
+    mut query: Query<(&HealthSystem, Option<&mut Lives>, Option<&mut RespawnTimer>)>,
+    mut out_of_lives: EventWriter<OutOfLives>,
+) {
+    for event in deaths.iter() {
+        let Ok((health_system, lives, respawn)) = query.get_mut(event.entity) else {
+            continue;
+        };
+        if !health_system.is_dead() {
+            continue;
+        }
+
+        if let Some(mut lives) = lives {
+            lives.remaining = lives.remaining.saturating_sub(1);
+            if lives.remaining == 0 {
+                out_of_lives.send(OutOfLives {
+                    entity: event.entity,
+                });
+                continue;
+            }
+        }
+
+        if let Some(mut respawn) = respawn {
+            respawn.pending = Some(Timer::new(respawn.delay, TimerMode::Once));
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn tick_respawns(
+    time: Res<Time>,
+    mut query: Query<(
+        Entity,
+        &HealthSystem,
+        &mut RespawnTimer,
+        Option<&RespawnPoint>,
+        Option<&mut Transform>,
+    )>,
+    mut revives: EventWriter<ReviveEvent>,
+) {
+    for (entity, health_system, mut respawn, point, transform) in &mut query {
+        if !health_system.is_dead() {
+            respawn.pending = None;
+            continue;
+        }
+        let Some(timer) = respawn.pending.as_mut() else {
+            continue;
+        };
+        if !timer.tick(time.delta()).finished() {
+            continue;
+        }
+        respawn.pending = None;
+
+        if let (Some(point), Some(mut transform)) = (point, transform) {
+            *transform = point.transform;
+        }
+
+        let mut revive = ReviveEvent::new(entity, respawn.heal_type);
+        if let Some(duration) = respawn.invulnerability {
+            revive = revive.with_invulnerability(duration);
+        }
+        revives.send(revive);
+    }
+}
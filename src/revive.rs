@@ -0,0 +1,138 @@
+//! Revive requests and the systems applying them.
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{HealthSystem, HealthSystemReviveHealType};
+
+/// Request to revive a dead or downed entity's [`HealthSystem`].
+/// Requests targeting alive entities are ignored.
+/// # Fields
+/// * `target`: The entity to revive.
+/// * `heal_type`: How much health the target is revived with.
+/// * `reviver`: The entity reviving the target, if any.
+/// * `invulnerability`: How long the target is [`Invulnerability`](crate::Invulnerability) after revival, if at all.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{HealthSystem, HealthSystemPlugin, HealthSystemReviveHealType, ReviveEvent};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let mut health_system = HealthSystem::new(100.0);
+/// health_system.kill_system(true);
+/// let entity = app.world.spawn(health_system).id();
+///
+/// app.world.send_event(ReviveEvent::new(entity, HealthSystemReviveHealType::HealFull));
+/// app.update();
+///
+/// assert_eq!(100.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// ```
+#[derive(Debug, Clone, Event)]
+pub struct ReviveEvent {
+    pub target: Entity,
+    pub heal_type: HealthSystemReviveHealType,
+    pub reviver: Option<Entity>,
+    pub invulnerability: Option<Duration>,
+}
+
+impl ReviveEvent {
+    /// Create a new revive request.
+    /// # Arguments
+    /// * `target`: The entity to revive.
+    /// * `heal_type`: How much health the target is revived with.
+    pub fn new(target: Entity, heal_type: HealthSystemReviveHealType) -> Self {
+        Self {
+            target,
+            heal_type,
+            reviver: None,
+            invulnerability: None,
+        }
+    }
+
+    /// Set the entity reviving the target.
+    pub fn with_reviver(mut self, reviver: Entity) -> Self {
+        self.reviver = Some(reviver);
+        self
+    }
+
+    /// Make the target invulnerable for `duration` after revival, so it doesn't get killed again right away.
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use bevy::prelude::*;
+    /// use bevy_health_system::{
+    ///     HealthSystem, HealthSystemPlugin, HealthSystemReviveHealType, Invulnerability, ReviveEvent,
+    /// };
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+    ///
+    /// let mut health_system = HealthSystem::new(100.0);
+    /// health_system.kill_system(true);
+    /// let entity = app.world.spawn(health_system).id();
+    ///
+    /// app.world.send_event(
+    ///     ReviveEvent::new(entity, HealthSystemReviveHealType::HealFull)
+    ///         .with_invulnerability(Duration::from_secs(3)),
+    /// );
+    /// app.update();
+    ///
+    /// assert!(app.world.get::<Invulnerability>(entity).unwrap().is_active());
+    /// ```
+    pub fn with_invulnerability(mut self, duration: Duration) -> Self {
+        self.invulnerability = Some(duration);
+        self
+    }
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) after a [`ReviveEvent`] revived its target.
+/// # Fields
+/// * `entity`: The revived entity.
+/// * `reviver`: The entity that revived it, if any.
+/// * `was_downed`: The entity was downed rather than dead.
+#[derive(Debug, Clone, Event)]
+pub struct RevivedEvent {
+    pub entity: Entity,
+    pub reviver: Option<Entity>,
+    pub was_downed: bool,
+}
+
+pub(crate) fn apply_revive_events(
+    mut commands: Commands,
+    mut events: EventReader<ReviveEvent>,
+    mut targets: Query<&mut HealthSystem>,
+    mut revived: EventWriter<RevivedEvent>,
+) {
+    for event in events.iter() {
+        let Ok(mut health_system) = targets.get_mut(event.target) else {
+            continue;
+        };
+        if health_system.is_alive() {
+            continue;
+        }
+
+        let was_downed = health_system.is_downed();
+        match event.invulnerability {
+            Some(duration) => {
+                let Ok((_, invulnerability)) =
+                    health_system.revive_system_invulnerable(event.heal_type, duration, false)
+                else {
+                    continue;
+                };
+                commands.entity(event.target).insert(invulnerability);
+            }
+            None => {
+                if health_system.revive_system(event.heal_type, false).is_err() {
+                    continue;
+                }
+            }
+        }
+        revived.send(RevivedEvent {
+            entity: event.target,
+            reviver: event.reviver,
+            was_downed,
+        });
+    }
+}
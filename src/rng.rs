@@ -0,0 +1,65 @@
+//! Seedable random numbers used by the damage pipeline.
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+
+use bevy::prelude::*;
+
+/// The random number generator of the [`HealthSystemPlugin`](crate::HealthSystemPlugin), eg: to roll [`CritStats`](crate::CritStats).
+/// It is seeded randomly by default, insert one with a fixed seed to keep replays or networked games deterministic.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{HealthRng, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(HealthRng::new(42));
+///
+/// let mut rng = HealthRng::new(42);
+/// assert_eq!(rng.next_u64(), app.world.resource_mut::<HealthRng>().next_u64());
+/// ```
+#[derive(Debug, Clone, Resource, Reflect)]
+#[reflect(Resource)]
+pub struct HealthRng {
+    state: u64,
+}
+
+impl HealthRng {
+    /// Create a new generator, the same seed always gives the same numbers.
+    /// # Arguments
+    /// * `seed`: The seed of the generator.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns a random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        // SplitMix64
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random `f32` from `0.0` included to `1.0` excluded.
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    /// Returns a random `f32` from `min` included to `max` excluded.
+    pub fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+
+    /// Returns `true` with a probability of `chance`, from `0.0` to `1.0`.
+    pub fn chance(&mut self, chance: f32) -> bool {
+        self.next_f32() < chance
+    }
+}
+
+impl Default for HealthRng {
+    fn default() -> Self {
+        Self::new(RandomState::new().build_hasher().finish())
+    }
+}
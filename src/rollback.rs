@@ -0,0 +1,143 @@
+//! `bevy_ggrs` rollback of health, with damage and heal requests resolved in the rollback schedule.
+use bevy::prelude::*;
+use bevy_ggrs::ggrs::Config;
+use bevy_ggrs::{GgrsPlugin, GgrsSchedule};
+
+use crate::queue::{queue_health_requests, resolve_health_requests};
+use crate::{
+    DamageOverTimeEffects, DamageResolution, Dead, HealOverTimeEffects, HealthRng,
+    HealthStatusEffects, HealthSystem, Invulnerability, Lives, MaxHealthModifiers, Shield,
+    TemporaryHealth,
+};
+
+/// Add this plugin after the `GgrsPlugin` of `bevy_ggrs` to resolve [`DamageEvent`](crate::DamageEvent)s
+/// and [`HealEvent`](crate::HealEvent)s in the `GgrsSchedule`, under [`DamageResolution::Rollback`].
+/// Requests sent by the systems of the `GgrsSchedule` are sent again when a frame is simulated again,
+/// so they are resolved again from the restored health, in the same order on every peer.
+/// Order these systems before the [`HealthRollbackSet`], the `GgrsSchedule` rejects ambiguous systems.
+/// Time based effects, eg: [`HealthRegen`](crate::HealthRegen), still run in `Update`, outside of the rollback.
+/// The [`DamageAppliedEvent`](crate::DamageAppliedEvent)s, [`DeathEvent`](crate::DeathEvent)s and [`Kill`](crate::Kill)s
+/// of a frame are sent again each time it is simulated again, and the bookkeeping reading them in `Update`,
+/// [`CombatStats`](crate::CombatStats), [`DpsMeter`](crate::DpsMeter), the [`CombatLog`](crate::CombatLog)
+/// and kill feeds, counts them every time. Drive it outside of the rollback schedule: leave these off
+/// rollback entities and the log disabled, and keep your own from the frames confirmed by the session.
+/// Register the components to roll back with [`HealthRollbackExt::register_health_rollback`].
+/// It is not part of the [`HealthSystemPlugin`](crate::HealthSystemPlugin), add both.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_ggrs::ggrs::{Config, PlayerHandle, PlayerType, SessionBuilder};
+/// use bevy_ggrs::{AddRollbackCommandExtension, GgrsAppExtension, GgrsPlugin, GgrsSchedule, Session};
+/// use bevy_health_system::{
+///     DamageEvent, HealthRollbackExt, HealthRollbackPlugin, HealthRollbackSet, HealthSystem,
+///     HealthSystemPlugin,
+/// };
+///
+/// struct GgrsConfig;
+/// impl Config for GgrsConfig {
+///     type Input = u8;
+///     type State = u8;
+///     type Address = usize;
+/// }
+///
+/// #[derive(Debug, Default, Resource, Reflect)]
+/// #[reflect(Resource)]
+/// struct Frame(u32);
+///
+/// fn count_frames(mut frame: ResMut<Frame>) {
+///     frame.0 += 1;
+/// }
+///
+/// fn hit_on_third_frame(
+///     frame: Res<Frame>,
+///     query: Query<Entity, With<HealthSystem>>,
+///     mut events: EventWriter<DamageEvent>,
+/// ) {
+///     if frame.0 == 3 {
+///         events.send_batch(query.iter().map(|entity| DamageEvent::new(entity, 10.0)));
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin))
+///     .init_resource::<Frame>()
+///     .add_systems(Startup, |mut commands: Commands| {
+///         commands.spawn(HealthSystem::new(100.0)).add_rollback();
+///     })
+///     // A sync test simulates every frame twice, and panics if the checksums differ.
+///     .insert_resource(Session::SyncTest(
+///         SessionBuilder::<GgrsConfig>::new()
+///             .with_num_players(1)
+///             .with_check_distance(2)
+///             .add_player(PlayerType::Local, 0)
+///             .unwrap()
+///             .start_synctest_session()
+///             .unwrap(),
+///     ))
+///     .add_ggrs_plugin(
+///         GgrsPlugin::<GgrsConfig>::new()
+///             .with_input_system(|_: In<PlayerHandle>| 0u8)
+///             .register_rollback_resource::<Frame>()
+///             .register_health_rollback(),
+///     )
+///     .add_plugins(HealthRollbackPlugin)
+///     .add_systems(
+///         GgrsSchedule,
+///         (count_frames, hit_on_third_frame).chain().before(HealthRollbackSet),
+///     );
+///
+/// for _ in 0..100 {
+///     if app.world.resource::<Frame>().0 >= 6 {
+///         break;
+///     }
+///     std::thread::sleep(Duration::from_secs_f32(1.0 / 60.0));
+///     app.update();
+/// }
+/// let health = app.world.query::<&HealthSystem>().single(&app.world).get_health();
+/// assert_eq!(90.0, health);
+/// ```
+#[derive(Debug, Default)]
+pub struct HealthRollbackPlugin;
+
+impl Plugin for HealthRollbackPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(DamageResolution::Rollback).add_systems(
+            GgrsSchedule,
+            (queue_health_requests, resolve_health_requests)
+                .chain()
+                .in_set(HealthRollbackSet)
+                .run_if(resource_equals(DamageResolution::Rollback)),
+        );
+    }
+}
+
+/// The systems of the [`HealthRollbackPlugin`] resolving requests in the `GgrsSchedule`.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, SystemSet)]
+pub struct HealthRollbackSet;
+
+/// Register the health of this crate for rollback on the `GgrsPlugin` of `bevy_ggrs`.
+pub trait HealthRollbackExt {
+    /// Register [`HealthSystem`], [`Shield`], [`TemporaryHealth`], [`MaxHealthModifiers`], [`Lives`], [`Dead`],
+    /// [`Invulnerability`], [`DamageOverTimeEffects`], [`HealOverTimeEffects`] and [`HealthStatusEffects`]
+    /// as rollback components, and the [`HealthRng`] as a rollback resource, so rerolled crits match.
+    /// The [`HealthSystem`] is part of the checksum of each frame.
+    /// Combat bookkeeping is not rolled back, see the [`HealthRollbackPlugin`].
+    fn register_health_rollback(self) -> Self;
+}
+
+impl<T: Config + Send + Sync> HealthRollbackExt for GgrsPlugin<T> {
+    fn register_health_rollback(self) -> Self {
+        self.register_rollback_component::<HealthSystem>()
+            .register_rollback_component::<Shield>()
+            .register_rollback_component::<TemporaryHealth>()
+            .register_rollback_component::<MaxHealthModifiers>()
+            .register_rollback_component::<Lives>()
+            .register_rollback_component::<Dead>()
+            .register_rollback_component::<Invulnerability>()
+            .register_rollback_component::<DamageOverTimeEffects>()
+            .register_rollback_component::<HealOverTimeEffects>()
+            .register_rollback_component::<HealthStatusEffects>()
+            .register_rollback_resource::<HealthRng>()
+    }
+}
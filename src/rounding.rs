@@ -0,0 +1,106 @@
+//! Whole number health, rounding the damage and healing of an entity.
+use bevy::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::DamageContext;
+
+/// How an amount of health is rounded to a whole number.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Copy, Clone, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Rounding {
+    /// Round to the next whole number, eg: `2.1` to `3.0`.
+    Up,
+    /// Round to the previous whole number, eg: `2.9` to `2.0`.
+    Down,
+    /// __Default__. Round to the closest whole number, halves away from zero.
+    #[default]
+    Nearest,
+}
+
+impl Rounding {
+    /// Round a number to a whole number.
+    pub fn round(self, value: f32) -> f32 {
+        match self {
+            Self::Up => value.ceil(),
+            Self::Down => value.floor(),
+            Self::Nearest => value.round(),
+        }
+    }
+}
+
+/// Keep the health of an entity a whole number: the damage dealt by [`DamageEvent`](crate::DamageEvent)s
+/// and the healing of [`HealEvent`](crate::HealEvent)s is rounded before it is applied.
+/// Damage is rounded once every [`DamageStage`](crate::DamageStage) built-in ran, along the other post mitigation stages.
+/// Give the entity a whole max health, [`HealthRegen`](crate::HealthRegen) heals once its regeneration adds up to whole health.
+/// # Fields
+/// * `damage`: How damage is rounded, [`Rounding::Up`] by default.
+/// * `healing`: How healing is rounded, [`Rounding::Down`] by default.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageEvent, HealEvent, HealthSystem, HealthSystemPlugin, IntegerHealth, Rounding,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), IntegerHealth::default()))
+///     .id();
+/// app.world.send_event(DamageEvent::new(entity, 10.2));
+/// app.update();
+/// assert_eq!(89.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+///
+/// app.world.send_event(HealEvent::new(entity, 5.7));
+/// app.update();
+/// assert_eq!(94.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+///
+/// // Generous rounding instead.
+/// app.world.entity_mut(entity).insert(
+///     IntegerHealth::default()
+///         .with_damage_rounding(Rounding::Down)
+///         .with_healing_rounding(Rounding::Up),
+/// );
+/// app.world.send_event(DamageEvent::new(entity, 10.2));
+/// app.update();
+/// assert_eq!(84.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// ```
+#[derive(Debug, Copy, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct IntegerHealth {
+    pub damage: Rounding,
+    pub healing: Rounding,
+}
+
+/// Damage rounds up and healing rounds down, so fractions never favor the entity.
+impl Default for IntegerHealth {
+    fn default() -> Self {
+        Self {
+            damage: Rounding::Up,
+            healing: Rounding::Down,
+        }
+    }
+}
+
+impl IntegerHealth {
+    /// Set how damage is rounded.
+    pub fn with_damage_rounding(mut self, damage: Rounding) -> Self {
+        self.damage = damage;
+        self
+    }
+
+    /// Set how healing is rounded.
+    pub fn with_healing_rounding(mut self, healing: Rounding) -> Self {
+        self.healing = healing;
+        self
+    }
+}
+
+pub(crate) fn integer_health_stage(context: &mut DamageContext, world: &mut World) {
+    if let Some(integer_health) = world.get::<IntegerHealth>(context.target) {
+        context.amount = integer_health.damage.round(context.amount).max(0.0);
+    }
+}
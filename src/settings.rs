@@ -0,0 +1,138 @@
+//! Global damage and healing settings, eg: for difficulty levels and debug cheats.
+use bevy::prelude::*;
+
+use crate::DamageContext;
+
+/// Marker for entities controlled by a player, see [`GlobalDamageSettings`].
+#[derive(Debug, Default, Copy, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Player;
+
+/// Scale every damage and heal request resolved by the [`HealthSystemPlugin`](crate::HealthSystemPlugin).
+/// Damage multipliers apply before the damage is mitigated, the healing multiplier applies to every [`HealEvent`](crate::HealEvent).
+/// # Fields
+/// * `player_damage_taken`: Multiplier of the damage taken by [`Player`]s.
+/// * `enemy_damage_taken`: Multiplier of the damage taken by every other entity.
+/// * `healing`: Multiplier of the heals.
+/// * `god_mode`: [`Player`]s take no damage at all, forced damage included. The hit is dropped before any stage
+///   of the [`DamagePipeline`](crate::DamagePipeline) runs, so it is never dodged, blocked nor breaks [`Poise`](crate::Poise).
+/// * `invalid_amounts`: What happens to damage and heals whose amount is NaN or infinite.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageEvent, GlobalDamageSettings, HealthSystem, HealthSystemPlugin, Player, Poise,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(GlobalDamageSettings {
+///     player_damage_taken: 0.5,
+///     enemy_damage_taken: 2.0,
+///     ..default()
+/// });
+///
+/// let player = app.world.spawn((HealthSystem::new(100.0), Player, Poise::new(100.0, 0.0))).id();
+/// let enemy = app.world.spawn(HealthSystem::new(100.0)).id();
+/// app.world.send_event(DamageEvent::new(player, 10.0));
+/// app.world.send_event(DamageEvent::new(enemy, 10.0));
+/// app.update();
+/// assert_eq!(95.0, app.world.get::<HealthSystem>(player).unwrap().get_health());
+/// assert_eq!(80.0, app.world.get::<HealthSystem>(enemy).unwrap().get_health());
+///
+/// app.world.resource_mut::<GlobalDamageSettings>().god_mode = true;
+/// app.world.send_event(DamageEvent::new(player, 10.0).forced());
+/// app.update();
+/// assert_eq!(95.0, app.world.get::<HealthSystem>(player).unwrap().get_health());
+/// assert_eq!(90.0, app.world.get::<Poise>(player).unwrap().current);
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone, Resource)]
+pub struct GlobalDamageSettings {
+    pub player_damage_taken: f32,
+    pub enemy_damage_taken: f32,
+    pub healing: f32,
+    pub god_mode: bool,
+    pub invalid_amounts: InvalidAmountPolicy,
+}
+
+impl Default for GlobalDamageSettings {
+    fn default() -> Self {
+        Self {
+            player_damage_taken: 1.0,
+            enemy_damage_taken: 1.0,
+            healing: 1.0,
+            god_mode: false,
+            invalid_amounts: InvalidAmountPolicy::default(),
+        }
+    }
+}
+
+/// What the [`HealthSystemPlugin`](crate::HealthSystemPlugin) does with a damage or heal whose amount is NaN or infinite,
+/// checked before and after the [`DamagePipeline`](crate::DamagePipeline), and after the healing multipliers.
+/// Methods of [`HealthSystem`](crate::HealthSystem) called directly are not checked, use its `try_` methods instead,
+/// eg: [`HealthSystem::try_deal_damage`](crate::HealthSystem::try_deal_damage).
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageEvent, GlobalDamageSettings, HealthSystem, HealthSystemPlugin, InvalidAmountPolicy,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app.world.spawn(HealthSystem::new(100.0)).id();
+/// app.world.send_event(DamageEvent::new(entity, f32::NAN));
+/// app.update();
+/// assert_eq!(100.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+///
+/// app.world.resource_mut::<GlobalDamageSettings>().invalid_amounts = InvalidAmountPolicy::Clamp;
+/// app.world.send_event(DamageEvent::new(entity, f32::INFINITY));
+/// app.update();
+/// assert!(app.world.get::<HealthSystem>(entity).unwrap().is_dead());
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone)]
+pub enum InvalidAmountPolicy {
+    /// __Default__. Drop the request and log a warning.
+    #[default]
+    Reject,
+    /// Apply the request and log a warning, NaN becomes `0.0` and infinities the largest finite amount.
+    Clamp,
+    /// Panic, eg: to catch the bug in development.
+    Panic,
+}
+
+impl InvalidAmountPolicy {
+    /// Returns the amount to apply, `None` if the request is dropped.
+    /// # Panics
+    /// With [`InvalidAmountPolicy::Panic`], if the amount is NaN or infinite.
+    pub fn check(self, amount: f32) -> Option<f32> {
+        if amount.is_finite() {
+            return Some(amount);
+        }
+        match self {
+            Self::Reject => {
+                warn!("Dropped a health request whose amount is {amount}.");
+                None
+            }
+            Self::Clamp => {
+                warn!("Clamped a health request whose amount is {amount}.");
+                Some(if amount.is_nan() {
+                    0.0
+                } else {
+                    amount.clamp(f32::MIN, f32::MAX)
+                })
+            }
+            Self::Panic => panic!("a health request amount is {amount}"),
+        }
+    }
+}
+
+pub(crate) fn global_damage_stage(context: &mut DamageContext, world: &mut World) {
+    let settings = *world.resource::<GlobalDamageSettings>();
+    if world.get::<Player>(context.hit).is_some() {
+        context.amount *= settings.player_damage_taken;
+    } else {
+        context.amount *= settings.enemy_damage_taken;
+    }
+}
@@ -0,0 +1,115 @@
+//! Health pools shared by multiple entities.
+use bevy::ecs::entity::{EntityMapper, MapEntities};
+use bevy::ecs::event::ManualEventReader;
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::prelude::*;
+
+use crate::{Corpse, Dead, DeathEvent, HealthSystem, RevivedEvent};
+
+/// Marker for an entity whose [`HealthSystem`] is shared by [`SharedHealthMember`]s, eg: a squad, a multi part boss, linked players,...
+/// Damage and heals sent to any member are applied to the pool, using the mitigation components of the pool entity.
+/// When the pool dies, a [`DeathEvent`] is sent for every member and their own [`HealthSystem`], if any, is killed.
+/// Members without one are marked [`Dead`] until the pool is revived.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageEvent, Dead, HealthSystem, HealthSystemPlugin, SharedHealthMember, SharedHealthPool,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let pool = app.world.spawn((HealthSystem::new(100.0), SharedHealthPool)).id();
+/// let left = app.world.spawn(SharedHealthMember::new(pool)).id();
+/// let right = app.world.spawn(SharedHealthMember::new(pool)).id();
+///
+/// app.world.send_event(DamageEvent::new(left, 60.0));
+/// app.update();
+/// assert_eq!(40.0, app.world.get::<HealthSystem>(pool).unwrap().get_health());
+///
+/// app.world.send_event(DamageEvent::new(right, 60.0));
+/// app.update();
+/// assert!(app.world.get::<HealthSystem>(pool).unwrap().is_dead());
+/// assert!(app.world.get::<Dead>(left).is_some());
+/// assert!(app.world.get::<Dead>(right).is_some());
+/// ```
+#[derive(Debug, Default, Copy, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct SharedHealthPool;
+
+/// Draw health from the [`SharedHealthPool`] of another entity instead of a [`HealthSystem`] of its own.
+/// # Fields
+/// * `pool`: The entity holding the shared [`HealthSystem`].
+#[derive(Debug, Copy, Clone, Component, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct SharedHealthMember {
+    pub pool: Entity,
+}
+
+/// Keep the member in the same pool when spawned from a scene.
+impl MapEntities for SharedHealthMember {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        self.pool = entity_mapper.get_or_reserve(self.pool);
+    }
+}
+
+/// A member without pool, used when a member is built through reflection, eg: by a scene.
+impl Default for SharedHealthMember {
+    fn default() -> Self {
+        Self::new(Entity::PLACEHOLDER)
+    }
+}
+
+impl SharedHealthMember {
+    /// Draw health from a pool.
+    /// # Arguments
+    /// * `pool`: The entity holding the shared [`HealthSystem`].
+    pub fn new(pool: Entity) -> Self {
+        Self { pool }
+    }
+}
+
+pub(crate) fn propagate_shared_deaths(
+    mut deaths: ResMut<Events<DeathEvent>>,
+    mut reader: Local<ManualEventReader<DeathEvent>>,
+    pools: Query<(), With<SharedHealthPool>>,
+    mut members: Query<(Entity, &SharedHealthMember, Option<&mut HealthSystem>)>,
+) {
+    let pool_deaths: Vec<_> = reader
+        .iter(&deaths)
+        .filter(|event| pools.contains(event.entity))
+        .cloned()
+        .collect();
+
+    for pool_death in pool_deaths {
+        for (entity, member, health_system) in &mut members {
+            if member.pool != pool_death.entity {
+                continue;
+            }
+            if let Some(mut health_system) = health_system {
+                health_system.kill_system(true);
+            }
+            deaths.send(DeathEvent {
+                entity,
+                ..pool_death.clone()
+            });
+        }
+    }
+    // Skip the events sent above.
+    reader.iter(&deaths).for_each(drop);
+}
+
+pub(crate) fn revive_shared_members(
+    mut commands: Commands,
+    mut revived: EventReader<RevivedEvent>,
+    members: Query<(Entity, &SharedHealthMember), With<Dead>>,
+) {
+    for event in revived.iter() {
+        for (entity, member) in &members {
+            if member.pool == event.entity {
+                commands.entity(entity).remove::<(Dead, Corpse)>();
+            }
+        }
+    }
+}
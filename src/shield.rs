@@ -0,0 +1,263 @@
+//! Shields absorbing damage before health.
+use std::time::Duration;
+
+use bevy::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{CombatRule, DamageAppliedEvent, DamageContext, InCombat};
+
+/// A shield absorbing any damage from a [`DamageEvent`](crate::DamageEvent) before the [`HealthSystem`](crate::HealthSystem) of the entity.
+/// Only damage that could not be absorbed spills into health.
+/// # Fields
+/// * `current`: The current shield value.
+/// * `max`: The max shield value.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, HealthSystem, HealthSystemPlugin, Shield};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app.world.spawn((HealthSystem::new(100.0), Shield::new(25.0))).id();
+/// app.world.send_event(DamageEvent::new(entity, 40.0));
+/// app.update();
+///
+/// assert_eq!(0.0, app.world.get::<Shield>(entity).unwrap().current);
+/// assert_eq!(85.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// ```
+/// The shield is only drained once the damage is applied, a cancelled hit leaves it untouched.
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageContext, DamageEvent, DamagePipelineAppExt, DamageStage, HealthSystem,
+///     HealthSystemPlugin, Shield, ShieldBroken,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.add_damage_modifier(
+///     DamageStage::PostMitigation,
+///     |context: &mut DamageContext, _: &mut World| context.cancel(),
+/// );
+///
+/// let entity = app.world.spawn((HealthSystem::new(100.0), Shield::new(25.0))).id();
+/// app.world.send_event(DamageEvent::new(entity, 40.0));
+/// app.update();
+/// assert_eq!(25.0, app.world.get::<Shield>(entity).unwrap().current);
+/// assert!(app.world.resource::<Events<ShieldBroken>>().is_empty());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Shield {
+    pub current: f32,
+    pub max: f32,
+}
+
+impl Shield {
+    /// Create a new, full, shield.
+    /// # Arguments
+    /// * `max`: The max shield value.
+    pub fn new(max: f32) -> Self {
+        Self { current: max, max }
+    }
+
+    /// - Absorb damage with this shield.
+    /// - Returns the damage that could not be absorbed.
+    /// # Arguments
+    /// * `amount`: The amount of damage to absorb.
+    /// # Example
+    /// ```
+    /// use bevy_health_system::Shield;
+    ///
+    /// let mut shield = Shield::new(25.0);
+    /// assert_eq!(0.0, shield.absorb(10.0));
+    /// assert_eq!(15.0, shield.current);
+    ///
+    /// assert_eq!(5.0, shield.absorb(20.0));
+    /// assert!(shield.is_broken());
+    /// ```
+    pub fn absorb(&mut self, amount: f32) -> f32 {
+        let absorbed = amount.clamp(0.0, self.current.max(0.0));
+        self.current -= absorbed;
+
+        amount - absorbed
+    }
+
+    /// Returns true if the shield has nothing left to absorb damage with.
+    pub fn is_broken(&self) -> bool {
+        self.current <= 0.0
+    }
+
+    /// Returns the normalized value of the shield.
+    /// this means: `current/max`
+    pub fn get_normalized(&self) -> f32 {
+        self.current / self.max
+    }
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when damage breaks a [`Shield`].
+/// # Fields
+/// * `entity`: The entity whose shield broke.
+#[derive(Debug, Clone, Event)]
+pub struct ShieldBroken {
+    pub entity: Entity,
+}
+
+/// Recharge the [`Shield`] of an entity after it has not been damaged for a while.
+/// # Fields
+/// * `delay`: How long the shield waits after the entity was damaged before recharging.
+/// * `rate_per_second`: Shield value recharged every second.
+/// * `combat`: Whether the shield recharges in or out of combat, see [`InCombat`](crate::InCombat).
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{HealthSystem, HealthSystemPlugin, Shield, ShieldRecharge};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+///
+/// let mut shield = Shield::new(50.0);
+/// shield.absorb(50.0);
+/// let entity = app
+///     .world
+///     .spawn((
+///         HealthSystem::new(100.0),
+///         shield,
+///         ShieldRecharge::new(Duration::ZERO, 100.0),
+///     ))
+///     .id();
+///
+/// app.update();
+/// app.update();
+/// assert_eq!(50.0, app.world.get::<Shield>(entity).unwrap().current);
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct ShieldRecharge {
+    pub delay: Duration,
+    pub rate_per_second: f32,
+    pub combat: CombatRule,
+    since_damage: Option<Duration>,
+    recharging: bool,
+}
+
+impl ShieldRecharge {
+    /// Create a new shield recharge.
+    /// # Arguments
+    /// * `delay`: How long the shield waits after the entity was damaged before recharging.
+    /// * `rate_per_second`: Shield value recharged every second.
+    pub fn new(delay: Duration, rate_per_second: f32) -> Self {
+        Self {
+            delay,
+            rate_per_second,
+            combat: CombatRule::default(),
+            since_damage: None,
+            recharging: false,
+        }
+    }
+
+    /// Set whether the shield recharges in or out of combat.
+    pub fn with_combat(mut self, combat: CombatRule) -> Self {
+        self.combat = combat;
+        self
+    }
+
+    /// Returns true while the shield is recharging.
+    pub fn is_recharging(&self) -> bool {
+        self.recharging
+    }
+
+    fn delay_finished(&self) -> bool {
+        self.since_damage
+            .is_none_or(|since_damage| since_damage >= self.delay)
+    }
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when a [`Shield`] starts recharging.
+/// # Fields
+/// * `entity`: The entity whose shield is recharging.
+#[derive(Debug, Clone, Event)]
+pub struct ShieldRechargeStarted {
+    pub entity: Entity,
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when a [`Shield`] is fully recharged.
+/// # Fields
+/// * `entity`: The entity whose shield is full again.
+#[derive(Debug, Clone, Event)]
+pub struct ShieldRecharged {
+    pub entity: Entity,
+}
+
+pub(crate) fn recharge_shields(
+    time: Res<Time>,
+    mut damaged: EventReader<DamageAppliedEvent>,
+    mut query: Query<(Entity, &mut ShieldRecharge, &mut Shield, Option<&InCombat>)>,
+    mut started: EventWriter<ShieldRechargeStarted>,
+    mut recharged: EventWriter<ShieldRecharged>,
+) {
+    for (_, mut recharge, ..) in &mut query {
+        if let Some(since_damage) = recharge.since_damage.as_mut() {
+            *since_damage += time.delta();
+        }
+    }
+
+    for event in damaged.iter() {
+        if let Ok((_, mut recharge, ..)) = query.get_mut(event.target) {
+            recharge.since_damage = Some(Duration::ZERO);
+            recharge.recharging = false;
+        }
+    }
+
+    for (entity, mut recharge, mut shield, in_combat) in &mut query {
+        if shield.current >= shield.max
+            || !recharge.delay_finished()
+            || !recharge.combat.allows(in_combat.is_some())
+        {
+            continue;
+        }
+
+        if !recharge.recharging {
+            recharge.recharging = true;
+            started.send(ShieldRechargeStarted { entity });
+        }
+
+        shield.current =
+            (shield.current + recharge.rate_per_second * time.delta_seconds()).min(shield.max);
+
+        if shield.current >= shield.max {
+            recharge.recharging = false;
+            recharged.send(ShieldRecharged { entity });
+        }
+    }
+}
+
+pub(crate) fn shield_stage(context: &mut DamageContext, world: &mut World) {
+    let Some(mut shield) = world.get::<Shield>(context.target).cloned() else {
+        return;
+    };
+
+    let remaining = shield.absorb(context.amount);
+    context.shield_absorbed = context.amount - remaining;
+    context.amount = remaining;
+}
+
+/// Drain the [`Shield`] of the target by the damage it absorbed from an applied hit.
+pub(crate) fn apply_shield_absorbed(context: &DamageContext, world: &mut World) {
+    let Some(mut shield) = world.get_mut::<Shield>(context.target) else {
+        return;
+    };
+
+    let was_broken = shield.is_broken();
+    shield.absorb(context.shield_absorbed);
+    if !was_broken && shield.is_broken() {
+        world.send_event(ShieldBroken {
+            entity: context.target,
+        });
+    }
+}
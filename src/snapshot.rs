@@ -0,0 +1,308 @@
+//! Versioned snapshots of the health of a world, to save and load combat state.
+use std::fmt;
+
+use bevy::ecs::world::EntityMut;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    DamageCause, DamageOverTimeEffects, HealOverTimeEffects, HealthStatusEffects, HealthSystem,
+    HealthSystemModifiers, HealthSystemState, Lives, MaxHealthModifiers, Shield, TemporaryHealth,
+};
+
+/// A stable id of your own for an entity, matching it to its [`HealthSnapshot`] across sessions.
+/// Entity ids change from one session to another, [`SaveData::restore`] only writes to an existing entity
+/// with the same id, eg: the player or a boss spawned by the level, and spawns the others.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Copy, Clone, Component, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SaveId(pub u64);
+
+/// The health related components of an entity, captured by [`SaveData::capture`].
+/// Components the entity does not have are `None`, with the `serde` feature they also default to `None`
+/// when reading a save written before they were added.
+/// # Fields
+/// * `entity`: The entity the snapshot was captured from, only valid in the session it was captured in.
+/// * `save_id`: Its [`SaveId`].
+/// * `health`: The current health of its [`HealthSystem`].
+/// * `max_health`: The max health of its [`HealthSystem`].
+/// * `state`: The state of its [`HealthSystem`].
+/// * `modifiers`: The modifiers of its [`HealthSystem`].
+/// * `death_cause`: Why its [`HealthSystem`] died, see [`HealthSystem::get_death_cause`].
+/// * `shield`: Its [`Shield`].
+/// * `temporary_health`: Its [`TemporaryHealth`].
+/// * `max_health_modifiers`: Its [`MaxHealthModifiers`].
+/// * `damage_over_time`: Its [`DamageOverTimeEffects`], with the time left of each effect.
+/// * `heal_over_time`: Its [`HealOverTimeEffects`], with the time left of each effect.
+/// * `status_effects`: Its [`HealthStatusEffects`], with the time left of each effect.
+/// * `lives`: Its [`Lives`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HealthSnapshot {
+    pub entity: Entity,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub save_id: Option<SaveId>,
+    pub health: f32,
+    pub max_health: f32,
+    pub state: HealthSystemState,
+    pub modifiers: HealthSystemModifiers,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub death_cause: Option<DamageCause>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub shield: Option<Shield>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub temporary_health: Option<TemporaryHealth>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_health_modifiers: Option<MaxHealthModifiers>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub damage_over_time: Option<DamageOverTimeEffects>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub heal_over_time: Option<HealOverTimeEffects>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub status_effects: Option<HealthStatusEffects>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub lives: Option<Lives>,
+}
+
+impl HealthSnapshot {
+    /// Capture the components of an entity, returns `None` without [`HealthSystem`].
+    pub fn capture(entity: EntityRef) -> Option<Self> {
+        let health_system = entity.get::<HealthSystem>()?;
+        Some(Self {
+            entity: entity.id(),
+            save_id: entity.get::<SaveId>().copied(),
+            health: health_system.get_health(),
+            max_health: health_system.get_health_max(),
+            state: health_system.get_state(),
+            modifiers: health_system.get_modifiers(),
+            death_cause: health_system.get_death_cause().cloned(),
+            shield: entity.get::<Shield>().cloned(),
+            temporary_health: entity.get::<TemporaryHealth>().cloned(),
+            max_health_modifiers: entity.get::<MaxHealthModifiers>().cloned(),
+            damage_over_time: entity.get::<DamageOverTimeEffects>().cloned(),
+            heal_over_time: entity.get::<HealOverTimeEffects>().cloned(),
+            status_effects: entity.get::<HealthStatusEffects>().cloned(),
+            lives: entity.get::<Lives>().cloned(),
+        })
+    }
+
+    /// Write the snapshot to an entity, components missing from the snapshot are removed, its [`SaveId`] is kept.
+    pub fn apply(self, entity: &mut EntityMut) {
+        entity.insert(
+            HealthSystem::from_parts(self.health, self.max_health, self.state, self.modifiers)
+                .with_death_cause(self.death_cause),
+        );
+        if let Some(save_id) = self.save_id {
+            entity.insert(save_id);
+        }
+        insert_or_remove(entity, self.shield);
+        insert_or_remove(entity, self.temporary_health);
+        insert_or_remove(entity, self.max_health_modifiers);
+        insert_or_remove(entity, self.damage_over_time);
+        insert_or_remove(entity, self.heal_over_time);
+        insert_or_remove(entity, self.status_effects);
+        insert_or_remove(entity, self.lives);
+    }
+}
+
+fn insert_or_remove<T: Component>(entity: &mut EntityMut, component: Option<T>) {
+    match component {
+        Some(component) => {
+            entity.insert(component);
+        }
+        None => {
+            entity.remove::<T>();
+        }
+    }
+}
+
+/// The health of every entity of a world, with the version of its format.
+/// Save it with the `serde` feature, then [`SaveData::restore`] it in a later session.
+/// Snapshots are written to the entity with the same [`SaveId`], the others are spawned,
+/// restore returns where each captured entity went.
+/// # Fields
+/// * `version`: The version of the format, [`SaveData::VERSION`] when captured.
+/// * `entities`: The snapshot of every entity with a [`HealthSystem`].
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, HealthSystem, HealthSystemPlugin, SaveData, SaveId, Shield};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let mut health_system = HealthSystem::new(100.0);
+/// health_system.set_health(40.0);
+/// let entity = app.world.spawn((health_system, Shield::new(25.0), SaveId(1))).id();
+/// let save = SaveData::capture(&app.world);
+/// assert_eq!(SaveData::VERSION, save.version);
+///
+/// app.world.send_event(DamageEvent::new(entity, 50.0));
+/// app.update();
+/// assert!(app.world.get::<HealthSystem>(entity).unwrap().get_health() < 40.0);
+/// app.world.entity_mut(entity).remove::<Shield>();
+///
+/// let restored = save.restore(&mut app.world).unwrap();
+/// assert_eq!(Some(&entity), restored.get(&entity));
+/// assert_eq!(40.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// assert_eq!(25.0, app.world.get::<Shield>(entity).unwrap().current);
+/// ```
+/// Restoring in a new session never overwrites an entity that happens to reuse a saved entity id.
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{HealthSystem, SaveData, SaveId};
+///
+/// let mut session = World::new();
+/// let mut wounded = HealthSystem::new(100.0);
+/// wounded.set_health(30.0);
+/// let player = session.spawn((wounded.clone(), SaveId(1))).id();
+/// let goblin = session.spawn(wounded).id();
+/// let save = SaveData::capture(&session);
+///
+/// let mut world = World::new();
+/// let chest = world.spawn(HealthSystem::new(10.0)).id();
+/// let barrel = world.spawn(HealthSystem::new(10.0)).id();
+/// let new_player = world.spawn((HealthSystem::new(100.0), SaveId(1))).id();
+/// let restored = save.restore(&mut world).unwrap();
+///
+/// assert_eq!(Some(&new_player), restored.get(&player));
+/// assert_eq!(30.0, world.get::<HealthSystem>(new_player).unwrap().get_health());
+/// let new_goblin = restored[&goblin];
+/// assert!(![chest, barrel, new_player].contains(&new_goblin));
+/// assert_eq!(30.0, world.get::<HealthSystem>(new_goblin).unwrap().get_health());
+/// assert_eq!(10.0, world.get::<HealthSystem>(chest).unwrap().get_health());
+/// assert_eq!(10.0, world.get::<HealthSystem>(barrel).unwrap().get_health());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SaveData {
+    pub version: u32,
+    pub entities: Vec<HealthSnapshot>,
+}
+
+impl SaveData {
+    /// The version of the format written by this release, bumped whenever the meaning of a field changes.
+    pub const VERSION: u32 = 2;
+
+    /// Capture every entity with a [`HealthSystem`].
+    pub fn capture(world: &World) -> Self {
+        Self {
+            version: Self::VERSION,
+            entities: world
+                .iter_entities()
+                .filter_map(HealthSnapshot::capture)
+                .collect(),
+        }
+    }
+
+    /// Migrate the save to [`SaveData::VERSION`] with the [`HealthSaveMigrations`] of the world, if any,
+    /// then write every snapshot to the entity with its [`SaveId`], or to a new entity.
+    /// Returns the entity each captured entity was written to.
+    /// # Errors
+    /// [`RestoreError::NewerVersion`] if the save was written by a newer release, nothing is written.
+    pub fn restore(mut self, world: &mut World) -> Result<HashMap<Entity, Entity>, RestoreError> {
+        if self.version > Self::VERSION {
+            return Err(RestoreError::NewerVersion {
+                version: self.version,
+                supported: Self::VERSION,
+            });
+        }
+        if let Some(migrations) = world.get_resource::<HealthSaveMigrations>() {
+            migrations.migrate(&mut self);
+        }
+        self.version = Self::VERSION;
+
+        let mut by_id: HashMap<SaveId, Entity> = world
+            .query::<(Entity, &SaveId)>()
+            .iter(world)
+            .map(|(entity, save_id)| (*save_id, entity))
+            .collect();
+        let mut entities = HashMap::new();
+        for snapshot in self.entities {
+            let saved = snapshot.entity;
+            let mut entity = match snapshot.save_id.and_then(|save_id| by_id.remove(&save_id)) {
+                Some(entity) => world.entity_mut(entity),
+                None => world.spawn_empty(),
+            };
+            entities.insert(saved, entity.id());
+            snapshot.apply(&mut entity);
+        }
+        Ok(entities)
+    }
+}
+
+/// Migrations run by [`SaveData::restore`], upgrading older saves one version at a time.
+/// Fields added by a new version default to `None`, migrations fix fields whose meaning changed.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{HealthSaveMigrations, HealthSystem, SaveData, SaveId};
+///
+/// let mut world = World::new();
+/// let entity = world.spawn((HealthSystem::new(100.0), SaveId(1))).id();
+/// let mut save = SaveData::capture(&world);
+///
+/// // Version 0 stored the health as a fraction of max health.
+/// save.version = 0;
+/// save.entities[0].health = 0.25;
+/// world.insert_resource(HealthSaveMigrations::default().with_migration(0, |save| {
+///     for snapshot in &mut save.entities {
+///         snapshot.health *= snapshot.max_health;
+///     }
+/// }));
+///
+/// save.restore(&mut world).unwrap();
+/// assert_eq!(25.0, world.get::<HealthSystem>(entity).unwrap().get_health());
+/// ```
+#[derive(Debug, Default, Clone, Resource)]
+pub struct HealthSaveMigrations {
+    migrations: HashMap<u32, fn(&mut SaveData)>,
+}
+
+impl HealthSaveMigrations {
+    /// Add a migration upgrading saves from a version to the next one, replacing the previous one of this version.
+    /// # Arguments
+    /// * `from_version`: The version of the saves this migration reads.
+    /// * `migration`: Update the save to the next version.
+    pub fn with_migration(mut self, from_version: u32, migration: fn(&mut SaveData)) -> Self {
+        self.migrations.insert(from_version, migration);
+        self
+    }
+
+    /// Run the migrations from the version of the save up to [`SaveData::VERSION`], in order.
+    pub fn migrate(&self, save: &mut SaveData) {
+        while save.version < SaveData::VERSION {
+            if let Some(migration) = self.migrations.get(&save.version) {
+                migration(save);
+            }
+            save.version += 1;
+        }
+    }
+}
+
+/// Why a [`SaveData`] could not be restored.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum RestoreError {
+    /// The save was written by a newer release.
+    NewerVersion {
+        /// The version of the save.
+        version: u32,
+        /// The latest version this release reads, [`SaveData::VERSION`].
+        supported: u32,
+    },
+}
+
+impl fmt::Display for RestoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NewerVersion { version, supported } => write!(
+                f,
+                "save version {version} is newer than the supported version {supported}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RestoreError {}
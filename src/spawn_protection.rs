@@ -0,0 +1,81 @@
+//! Protection of freshly spawned entities.
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+/// Make an entity ignore any non forced [`DamageEvent`](crate::DamageEvent) for some time after it is inserted.
+/// The component is removed once it runs out, and [`SpawnProtectionExpired`] is sent.
+/// # Fields
+/// * `remaining`: The time left before the entity can be damaged.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageEvent, HealthSystem, HealthSystemPlugin, SpawnProtection, SpawnProtectionExpired,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+///
+/// let entity = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), SpawnProtection::new(Duration::from_secs(1))))
+///     .id();
+/// app.world.send_event(DamageEvent::new(entity, 10.0));
+/// app.update();
+/// assert_eq!(100.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+///
+/// app.update();
+/// assert!(app.world.get::<SpawnProtection>(entity).is_none());
+/// let events = app.world.resource::<Events<SpawnProtectionExpired>>();
+/// assert_eq!(entity, events.iter_current_update_events().next().unwrap().entity);
+///
+/// app.world.send_event(DamageEvent::new(entity, 10.0));
+/// app.update();
+/// assert_eq!(90.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct SpawnProtection {
+    pub remaining: Timer,
+}
+
+impl SpawnProtection {
+    /// Protect an entity for some time.
+    /// # Arguments
+    /// * `duration`: How long the entity stays protected.
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            remaining: Timer::new(duration, TimerMode::Once),
+        }
+    }
+
+    /// Returns true while the entity should not take any non forced damage.
+    pub fn is_active(&self) -> bool {
+        !self.remaining.finished()
+    }
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when the [`SpawnProtection`] of an entity runs out.
+/// # Fields
+/// * `entity`: The entity no longer protected.
+#[derive(Debug, Clone, Event)]
+pub struct SpawnProtectionExpired {
+    pub entity: Entity,
+}
+
+pub(crate) fn tick_spawn_protection(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut SpawnProtection)>,
+    mut expired: EventWriter<SpawnProtectionExpired>,
+) {
+    for (entity, mut protection) in &mut query {
+        if protection.remaining.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<SpawnProtection>();
+            expired.send(SpawnProtectionExpired { entity });
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! Health spent as a cost, eg: blood magic, instead of being lost to damage.
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::{HealthSystem, NotEnoughHealth, SharedHealthMember};
+
+/// Sent by a [`HealthSpender`] when an entity spent health.
+/// Spending is not damage, no [`DamageAppliedEvent`](crate::DamageAppliedEvent) is sent for it.
+/// # Fields
+/// * `entity`: The entity whose health was spent, the pool when spent by a [`SharedHealthMember`].
+/// * `amount`: The health spent.
+/// * `remaining`: The health left after spending.
+#[derive(Debug, Clone, Event)]
+pub struct HealthSpent {
+    pub entity: Entity,
+    pub amount: f32,
+    pub remaining: f32,
+}
+
+/// Spend the health of entities in one [`SystemParam`], checked and deducted at once, see [`HealthSystem::spend_health`].
+/// Nothing mitigates the cost: no [`Armor`](crate::Armor), no [`Shield`](crate::Shield),
+/// no [`HitInvulnerability`](crate::HitInvulnerability) frames and no [`DamageEvent`](crate::DamageEvent).
+/// # Examples
+/// ```
+/// use bevy::ecs::system::SystemState;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{HealthSpender, HealthSpent, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// let warlock = app.world.spawn(HealthSystem::new(100.0)).id();
+///
+/// let mut state = SystemState::<HealthSpender>::new(&mut app.world);
+/// let mut spender = state.get_mut(&mut app.world);
+/// assert!(spender.spend(warlock, 40.0, 1.0).is_ok());
+/// assert!(spender.spend(warlock, 60.0, 1.0).is_err());
+/// state.apply(&mut app.world);
+///
+/// assert_eq!(60.0, app.world.get::<HealthSystem>(warlock).unwrap().get_health());
+/// let events = app.world.resource::<Events<HealthSpent>>();
+/// let spent: Vec<_> = events.iter_current_update_events().map(|event| event.amount).collect();
+/// assert_eq!(vec![40.0], spent);
+/// ```
+#[derive(SystemParam)]
+pub struct HealthSpender<'w, 's> {
+    members: Query<'w, 's, &'static SharedHealthMember>,
+    targets: Query<'w, 's, &'static mut HealthSystem>,
+    spent: EventWriter<'w, HealthSpent>,
+}
+
+impl HealthSpender<'_, '_> {
+    /// Spend health of an entity, or of its pool if it is a [`SharedHealthMember`].
+    /// # Arguments
+    /// * `entity`: The entity spending health.
+    /// * `amount`: The health spent.
+    /// * `min_remaining`: The lowest health the entity can be left with.
+    /// # Errors
+    /// [`NotEnoughHealth`] if the entity can't afford the cost, or has no [`HealthSystem`]. Nothing is spent.
+    pub fn spend(
+        &mut self,
+        entity: Entity,
+        amount: f32,
+        min_remaining: f32,
+    ) -> Result<(), NotEnoughHealth> {
+        let entity = self
+            .members
+            .get(entity)
+            .map_or(entity, |member| member.pool);
+        let Ok(mut health_system) = self.targets.get_mut(entity) else {
+            return Err(NotEnoughHealth {
+                available: 0.0,
+                cost: amount,
+            });
+        };
+
+        health_system.spend_health(amount, min_remaining)?;
+        self.spent.send(HealthSpent {
+            entity,
+            amount: amount.max(0.0),
+            remaining: health_system.get_health(),
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,217 @@
+//! Combat statistics of entities, eg: for scoreboards and DPS meters.
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{DamageAppliedEvent, Kill};
+
+/// Totals of the fights of an entity, kept up to date by the [`HealthSystemPlugin`](crate::HealthSystemPlugin).
+/// Damage counts the health lost from [`DamageEvent`](crate::DamageEvent)s, healing counts the health restored by [`HealEvent`](crate::HealEvent)s.
+/// # Fields
+/// * `damage_taken`: The total damage taken.
+/// * `damage_dealt`: The total damage dealt as a source.
+/// * `healing_received`: The total health healed.
+/// * `kills`: The number of killing blows dealt, see [`Kill`].
+/// * `deaths`: The number of deaths.
+/// * `largest_hit`: The largest damage dealt in a single hit.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{CombatStats, DamageEvent, HealEvent, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let attacker = app.world.spawn(CombatStats::default()).id();
+/// let entity = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), CombatStats::default()))
+///     .id();
+///
+/// app.world.send_event(DamageEvent::new(entity, 30.0).with_source(attacker));
+/// app.update();
+/// app.world.send_event(HealEvent::new(entity, 10.0));
+/// app.update();
+/// app.world.send_event(DamageEvent::new(entity, 200.0).with_source(attacker));
+/// app.update();
+///
+/// let stats = app.world.get::<CombatStats>(entity).unwrap();
+/// assert_eq!((110.0, 10.0, 1), (stats.damage_taken, stats.healing_received, stats.deaths));
+/// let stats = app.world.get::<CombatStats>(attacker).unwrap();
+/// assert_eq!((110.0, 80.0, 1), (stats.damage_dealt, stats.largest_hit, stats.kills));
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct CombatStats {
+    pub damage_taken: f32,
+    pub damage_dealt: f32,
+    pub healing_received: f32,
+    pub kills: u32,
+    pub deaths: u32,
+    pub largest_hit: f32,
+}
+
+pub(crate) fn update_combat_stats(
+    mut damaged: EventReader<DamageAppliedEvent>,
+    mut kills: EventReader<Kill>,
+    mut query: Query<&mut CombatStats>,
+) {
+    for event in damaged.iter() {
+        let amount = event.breakdown.applied;
+        if let Ok(mut stats) = query.get_mut(event.target) {
+            stats.damage_taken += amount;
+        }
+        if let Some(Ok(mut stats)) = event.source.map(|source| query.get_mut(source)) {
+            stats.damage_dealt += amount;
+            stats.largest_hit = stats.largest_hit.max(amount);
+        }
+    }
+
+    for kill in kills.iter() {
+        if let Ok(mut stats) = query.get_mut(kill.victim) {
+            stats.deaths += 1;
+        }
+        if let Some(Ok(mut stats)) = kill.killer.map(|killer| query.get_mut(killer)) {
+            stats.kills += 1;
+        }
+    }
+}
+
+/// What a [`DpsMeter`] measures.
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone, Reflect)]
+pub enum DpsSource {
+    /// __Default__. The damage dealt by the entity as a source, eg: for a player balance overlay.
+    #[default]
+    Dealt,
+    /// The damage taken by the entity, eg: for a training dummy.
+    Taken,
+}
+
+/// A rolling damage per second meter, kept up to date by the [`HealthSystemPlugin`](crate::HealthSystemPlugin).
+/// The damage counted within the last `window` is averaged over it.
+/// # Fields
+/// * `window`: How far back damage counts.
+/// * `source`: Whether the damage dealt or taken is measured.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, DpsMeter, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+///
+/// let dummy = app
+///     .world
+///     .spawn((HealthSystem::new(1000.0), DpsMeter::taken(Duration::from_secs(2))))
+///     .id();
+///
+/// app.update();
+/// for _ in 0..2 {
+///     app.world.send_event(DamageEvent::new(dummy, 30.0));
+///     app.update();
+/// }
+/// assert_eq!(30.0, app.world.get::<DpsMeter>(dummy).unwrap().dps());
+///
+/// app.update();
+/// app.update();
+/// assert_eq!(0.0, app.world.get::<DpsMeter>(dummy).unwrap().dps());
+/// ```
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct DpsMeter {
+    pub window: Duration,
+    pub source: DpsSource,
+    samples: VecDeque<(f64, f32)>,
+    dps: f32,
+}
+
+/// Measure the damage taken over 5 seconds.
+impl Default for DpsMeter {
+    fn default() -> Self {
+        Self::taken(Duration::from_secs(5))
+    }
+}
+
+impl DpsMeter {
+    /// Measure the damage dealt by the entity.
+    /// # Arguments
+    /// * `window`: How far back damage counts.
+    pub fn dealt(window: Duration) -> Self {
+        Self {
+            window,
+            source: DpsSource::Dealt,
+            samples: VecDeque::new(),
+            dps: 0.0,
+        }
+    }
+
+    /// Measure the damage taken by the entity.
+    /// # Arguments
+    /// * `window`: How far back damage counts.
+    pub fn taken(window: Duration) -> Self {
+        Self {
+            source: DpsSource::Taken,
+            ..Self::dealt(window)
+        }
+    }
+
+    /// The damage per second within the window.
+    pub fn dps(&self) -> f32 {
+        self.dps
+    }
+
+    /// The damage counted within the window.
+    pub fn total(&self) -> f32 {
+        self.samples.iter().map(|(_, amount)| amount).sum()
+    }
+
+    /// Forget every damage counted.
+    pub fn reset(&mut self) {
+        self.samples.clear();
+        self.dps = 0.0;
+    }
+}
+
+pub(crate) fn update_dps_meters(
+    time: Res<Time>,
+    mut damaged: EventReader<DamageAppliedEvent>,
+    mut query: Query<&mut DpsMeter>,
+) {
+    let now = time.elapsed_seconds_f64();
+    for event in damaged.iter() {
+        let amount = event.breakdown.applied;
+        if let Ok(mut meter) = query.get_mut(event.target) {
+            if meter.source == DpsSource::Taken {
+                meter.samples.push_back((now, amount));
+            }
+        }
+        if let Some(Ok(mut meter)) = event.source.map(|source| query.get_mut(source)) {
+            if meter.source == DpsSource::Dealt {
+                meter.samples.push_back((now, amount));
+            }
+        }
+    }
+
+    for mut meter in &mut query {
+        let window = meter.window.as_secs_f64();
+        while meter
+            .samples
+            .front()
+            .is_some_and(|&(time, _)| time <= now - window)
+        {
+            meter.samples.pop_front();
+        }
+
+        let dps = if window > 0.0 {
+            meter.total() / window as f32
+        } else {
+            0.0
+        };
+        if meter.dps != dps {
+            meter.dps = dps;
+        }
+    }
+}
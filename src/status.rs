@@ -0,0 +1,443 @@
+//! Temporary buffs and debuffs changing how an entity's health behaves.
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::regen::regenerated;
+use crate::{
+    DamageContext, GlobalDamageSettings, HealingModifier, HealthSystem, HealthSystemModifier,
+    IntegerHealth, MaxHealthBonus, MaxHealthModifiers,
+};
+
+/// The id of the [`MaxHealthBonus`] status effects add to [`MaxHealthModifiers`].
+const STATUS_BONUS_ID: &str = "health_status";
+
+/// What a [`HealthStatusEffect`] does, scaled by its `magnitude`.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     ApplyHealthStatusEffect, GlobalDamageSettings, HealthStatusEffect, HealthStatusKind,
+///     HealthSystem, HealthSystemPlugin,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+/// app.world.resource_mut::<GlobalDamageSettings>().healing = 0.5;
+///
+/// let mut health_system = HealthSystem::new(100.0);
+/// health_system.deal_damage(50.0, false);
+/// let entity = app.world.spawn(health_system).id();
+/// let regen = HealthStatusEffect::new(HealthStatusKind::Regen, 10.0, Duration::from_secs(5));
+/// app.world.send_event(ApplyHealthStatusEffect::new(entity, regen));
+///
+/// app.update();
+/// app.update();
+/// assert_eq!(55.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// ```
+/// A heal block the system had before a [`HealthStatusKind::HealBlocked`] effect outlives it.
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     ApplyHealthStatusEffect, HealthStatusEffect, HealthStatusKind, HealthSystem,
+///     HealthSystemModifier, HealthSystemPlugin,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+///
+/// let mut health_system = HealthSystem::new(100.0);
+/// health_system.add_modifier(HealthSystemModifier::HEAL_BLOCKED);
+/// let entity = app.world.spawn(health_system).id();
+/// let block = HealthStatusEffect::new(HealthStatusKind::HealBlocked, 0.0, Duration::from_secs(1));
+/// app.world.send_event(ApplyHealthStatusEffect::new(entity, block));
+///
+/// for _ in 0..4 {
+///     app.update();
+/// }
+/// let health_system = app.world.get::<HealthSystem>(entity).unwrap();
+/// assert!(health_system.has_modifier(HealthSystemModifier::HEAL_BLOCKED));
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HealthStatusKind {
+    /// Add `magnitude` to the max health, negative lowers it, through [`MaxHealthModifiers`].
+    MaxHealth,
+    /// Heal `magnitude` health every second, scaled and rounded like [`HealthRegen`](crate::HealthRegen).
+    Regen,
+    /// Multiply the damage taken by `magnitude`, eg: `1.5` takes 50% more damage.
+    DamageTaken,
+    /// Block healing, add [`HealthSystemModifier::HEAL_BLOCKED`] while active, `magnitude` is unused.
+    /// A heal block the system already had is kept once the effect expires.
+    HealBlocked,
+    /// Lower the max health by `magnitude`, see [`HealthStatusEffect::curse`].
+    Curse {
+        /// Give back the health lost to the lowered max health once the curse expires.
+        restore_health: bool,
+    },
+}
+
+/// A buff or debuff lasting `duration`. Apply it to an entity with [`ApplyHealthStatusEffect`],
+/// an entity can have many of them at once, effects of the same kind stack.
+/// # Fields
+/// * `kind`: What the effect does.
+/// * `magnitude`: How strong the effect is, see [`HealthStatusKind`].
+/// * `duration`: How long the effect lasts.
+#[derive(Debug, Clone, Reflect)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HealthStatusEffect {
+    pub kind: HealthStatusKind,
+    pub magnitude: f32,
+    pub duration: Duration,
+    elapsed: Duration,
+    taken: Option<f32>,
+}
+
+impl HealthStatusEffect {
+    /// Create a new status effect.
+    /// # Arguments
+    /// * `kind`: What the effect does.
+    /// * `magnitude`: How strong the effect is.
+    /// * `duration`: How long the effect lasts.
+    pub fn new(kind: HealthStatusKind, magnitude: f32, duration: Duration) -> Self {
+        Self {
+            kind,
+            magnitude,
+            duration,
+            elapsed: Duration::ZERO,
+            taken: None,
+        }
+    }
+
+    /// Create a curse lowering the max health by `amount` for `duration`, the max health is restored once it expires.
+    /// The current health above the lowered max health is lost, it is given back on expiry if `restore_health` is true.
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use bevy::prelude::*;
+    /// use bevy_health_system::{
+    ///     ApplyHealthStatusEffect, HealthStatusEffect, HealthSystem, HealthSystemPlugin,
+    /// };
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+    /// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+    ///
+    /// let entity = app.world.spawn(HealthSystem::new(100.0)).id();
+    /// let curse = HealthStatusEffect::curse(40.0, Duration::from_secs(2), true);
+    /// app.world.send_event(ApplyHealthStatusEffect::new(entity, curse));
+    ///
+    /// for _ in 0..3 {
+    ///     app.update();
+    /// }
+    /// let health_system = app.world.get::<HealthSystem>(entity).unwrap();
+    /// assert_eq!((60.0, 60.0), (health_system.get_health(), health_system.get_health_max()));
+    ///
+    /// app.update();
+    /// let health_system = app.world.get::<HealthSystem>(entity).unwrap();
+    /// assert_eq!((100.0, 100.0), (health_system.get_health(), health_system.get_health_max()));
+    /// ```
+    pub fn curse(amount: f32, duration: Duration, restore_health: bool) -> Self {
+        Self::new(HealthStatusKind::Curse { restore_health }, amount, duration)
+    }
+
+    /// The time left before the effect expires.
+    pub fn remaining(&self) -> Duration {
+        self.duration.saturating_sub(self.elapsed)
+    }
+
+    /// Returns true once the effect lasted its whole duration.
+    pub fn is_expired(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+/// Every [`HealthStatusEffect`] currently affecting an entity.
+/// Expired effects are removed by the [`HealthSystemPlugin`](crate::HealthSystemPlugin), which keeps the values derived
+/// from them up to date: max health, [`HealthSystemModifier::HEAL_BLOCKED`],...
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     ApplyHealthStatusEffect, HealthStatusEffect, HealthStatusExpired, HealthStatusKind,
+///     HealthSystem, HealthSystemPlugin,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_secs(1)));
+///
+/// let entity = app.world.spawn(HealthSystem::new(100.0)).id();
+/// let buff = HealthStatusEffect::new(HealthStatusKind::MaxHealth, 50.0, Duration::from_secs(2));
+/// app.world.send_event(ApplyHealthStatusEffect::new(entity, buff));
+///
+/// for _ in 0..3 {
+///     app.update();
+/// }
+/// assert_eq!(150.0, app.world.get::<HealthSystem>(entity).unwrap().get_health_max());
+/// let events = app.world.resource::<Events<HealthStatusExpired>>();
+/// assert_eq!(1, events.iter_current_update_events().count());
+///
+/// app.update();
+/// assert_eq!(100.0, app.world.get::<HealthSystem>(entity).unwrap().get_health_max());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HealthStatusEffects {
+    effects: Vec<HealthStatusEffect>,
+    blocking_heal: bool,
+    was_heal_blocked: bool,
+    restoring: f32,
+    regen_carry: f32,
+}
+
+impl HealthStatusEffects {
+    /// Add a new effect, stacking with the others.
+    pub fn add(&mut self, effect: HealthStatusEffect) {
+        self.effects.push(effect);
+    }
+
+    /// Returns every active effect.
+    pub fn iter(&self) -> impl Iterator<Item = &HealthStatusEffect> {
+        self.effects.iter()
+    }
+
+    /// Returns the number of active effects.
+    pub fn len(&self) -> usize {
+        self.effects.len()
+    }
+
+    /// Returns true if there is no active effect.
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Remove every effect.
+    pub fn clear(&mut self) {
+        self.effects.clear();
+    }
+
+    /// Returns true if an effect of this kind is active.
+    pub fn has(&self, kind: HealthStatusKind) -> bool {
+        self.effects.iter().any(|effect| effect.kind == kind)
+    }
+
+    /// The summed magnitude of the active effects of this kind.
+    pub fn total(&self, kind: HealthStatusKind) -> f32 {
+        self.effects
+            .iter()
+            .filter(|effect| effect.kind == kind)
+            .map(|effect| effect.magnitude)
+            .sum()
+    }
+
+    /// The flat bonus to the max health, [`HealthStatusKind::MaxHealth`] effects minus curses.
+    pub fn max_health_bonus(&self) -> f32 {
+        self.effects
+            .iter()
+            .map(|effect| match effect.kind {
+                HealthStatusKind::MaxHealth => effect.magnitude,
+                HealthStatusKind::Curse { .. } => -effect.magnitude,
+                _ => 0.0,
+            })
+            .sum()
+    }
+
+    /// The multiplier of the damage taken, the product of the active [`HealthStatusKind::DamageTaken`] effects.
+    pub fn damage_taken_multiplier(&self) -> f32 {
+        self.effects
+            .iter()
+            .filter(|effect| effect.kind == HealthStatusKind::DamageTaken)
+            .map(|effect| effect.magnitude)
+            .product()
+    }
+}
+
+/// Request to apply a [`HealthStatusEffect`] to an entity with a [`HealthSystem`].
+/// # Fields
+/// * `target`: The entity receiving the effect.
+/// * `effect`: The effect to apply.
+#[derive(Debug, Clone, Event)]
+pub struct ApplyHealthStatusEffect {
+    pub target: Entity,
+    pub effect: HealthStatusEffect,
+}
+
+impl ApplyHealthStatusEffect {
+    /// Create a new request to apply a status effect.
+    pub fn new(target: Entity, effect: HealthStatusEffect) -> Self {
+        Self { target, effect }
+    }
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when a [`HealthStatusEffect`] is applied.
+/// # Fields
+/// * `target`: The entity receiving the effect.
+/// * `kind`: What the effect does.
+#[derive(Debug, Clone, Event)]
+pub struct HealthStatusApplied {
+    pub target: Entity,
+    pub kind: HealthStatusKind,
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when a [`HealthStatusEffect`] expires.
+/// # Fields
+/// * `target`: The entity the effect expired on.
+/// * `kind`: What the effect did.
+#[derive(Debug, Clone, Event)]
+pub struct HealthStatusExpired {
+    pub target: Entity,
+    pub kind: HealthStatusKind,
+}
+
+pub(crate) fn apply_health_status_effects(
+    mut commands: Commands,
+    mut events: EventReader<ApplyHealthStatusEffect>,
+    mut targets: Query<Option<&mut HealthStatusEffects>, With<HealthSystem>>,
+    mut applied: EventWriter<HealthStatusApplied>,
+) {
+    let mut inserted: HashMap<Entity, HealthStatusEffects> = HashMap::default();
+
+    for event in events.iter() {
+        let Ok(effects) = targets.get_mut(event.target) else {
+            continue;
+        };
+
+        match effects {
+            Some(mut effects) => effects.add(event.effect.clone()),
+            None => inserted
+                .entry(event.target)
+                .or_default()
+                .add(event.effect.clone()),
+        }
+
+        applied.send(HealthStatusApplied {
+            target: event.target,
+            kind: event.effect.kind,
+        });
+    }
+
+    for (entity, effects) in inserted {
+        commands.entity(entity).insert(effects);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn tick_health_status_effects(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<GlobalDamageSettings>,
+    mut query: Query<(
+        Entity,
+        &mut HealthStatusEffects,
+        &mut HealthSystem,
+        Option<&mut MaxHealthModifiers>,
+        Option<&HealingModifier>,
+        Option<&IntegerHealth>,
+    )>,
+    mut expired: EventWriter<HealthStatusExpired>,
+) {
+    for (entity, mut effects, mut health_system, max_health, healing_modifier, integer_health) in
+        &mut query
+    {
+        // Health taken by expired curses, given back once the max health is restored.
+        if effects.restoring > 0.0 {
+            if health_system.is_alive() {
+                let health = health_system.get_health();
+                health_system.set_health(health + effects.restoring);
+            }
+            effects.restoring = 0.0;
+        }
+
+        let (health, health_max) = (health_system.get_health(), health_system.get_health_max());
+        let mut regen = 0.0;
+        for effect in effects.effects.iter_mut() {
+            if matches!(effect.kind, HealthStatusKind::Curse { .. }) && effect.taken.is_none() {
+                let taken = health - (health_max - effect.magnitude);
+                effect.taken = Some(taken.clamp(0.0, effect.magnitude));
+            }
+
+            let delta = time.delta().min(effect.remaining());
+            effect.elapsed += delta;
+
+            if effect.kind == HealthStatusKind::Regen {
+                regen += effect.magnitude * delta.as_secs_f32();
+            }
+        }
+        if regen > 0.0 && health_system.is_alive() && health < health_max {
+            let amount = regenerated(
+                regen,
+                &settings,
+                healing_modifier,
+                integer_health,
+                &mut effects.regen_carry,
+            );
+            health_system.heal(amount);
+        } else {
+            effects.regen_carry = 0.0;
+        }
+
+        let mut restoring = 0.0;
+        effects.effects.retain(|effect| {
+            if effect.is_expired() {
+                if let HealthStatusKind::Curse {
+                    restore_health: true,
+                } = effect.kind
+                {
+                    restoring += effect.taken.unwrap_or_default();
+                }
+                expired.send(HealthStatusExpired {
+                    target: entity,
+                    kind: effect.kind,
+                });
+            }
+            !effect.is_expired()
+        });
+        effects.restoring += restoring;
+
+        let bonus = effects.max_health_bonus();
+        let wanted = (bonus != 0.0).then_some(MaxHealthBonus::Flat(bonus));
+        if let Some(mut max_health) = max_health {
+            // Only touch the stack on change, any mutation recomputes the max health.
+            if max_health.get(STATUS_BONUS_ID) != wanted {
+                match wanted {
+                    Some(bonus) => max_health.insert(STATUS_BONUS_ID, bonus),
+                    None => {
+                        max_health.remove(STATUS_BONUS_ID);
+                    }
+                }
+            }
+        } else if let Some(bonus) = wanted {
+            let mut max_health = MaxHealthModifiers::new(health_system.get_health_max());
+            max_health.insert(STATUS_BONUS_ID, bonus);
+            commands.entity(entity).insert(max_health);
+        }
+
+        let heal_blocked = effects.has(HealthStatusKind::HealBlocked);
+        if heal_blocked != effects.blocking_heal {
+            effects.blocking_heal = heal_blocked;
+            if heal_blocked {
+                effects.was_heal_blocked =
+                    health_system.has_modifier(HealthSystemModifier::HEAL_BLOCKED);
+                health_system.add_modifier(HealthSystemModifier::HEAL_BLOCKED);
+            } else if !effects.was_heal_blocked {
+                health_system.remove_modifier(HealthSystemModifier::HEAL_BLOCKED);
+            }
+        }
+    }
+}
+
+pub(crate) fn damage_taken_stage(context: &mut DamageContext, world: &mut World) {
+    if let Some(effects) = world.get::<HealthStatusEffects>(context.target) {
+        context.amount *= effects.damage_taken_multiplier();
+    }
+}
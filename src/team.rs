@@ -0,0 +1,141 @@
+//! Teams and friendly fire between their members.
+use bevy::prelude::*;
+
+use crate::DamageContext;
+
+/// The team, or faction, of an entity. Damage between members of the same team follows the [`FriendlyFirePolicy`].
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, FriendlyFireBlocked, HealthSystem, HealthSystemPlugin, Team};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let ally = app.world.spawn(Team(1)).id();
+/// let enemy = app.world.spawn(Team(2)).id();
+/// let entity = app.world.spawn((HealthSystem::new(100.0), Team(1))).id();
+///
+/// app.world.send_event(DamageEvent::new(entity, 10.0).with_source(ally));
+/// app.world.send_event(DamageEvent::new(entity, 10.0).with_source(enemy));
+/// app.update();
+///
+/// assert_eq!(90.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// let events = app.world.resource::<Events<FriendlyFireBlocked>>();
+/// assert_eq!(Some(ally), events.iter_current_update_events().next().map(|event| event.source));
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Copy, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Team(pub u8);
+
+/// Which entities an effect, eg: a [`HealingAura`](crate::HealingAura), applies to, relative to the [`Team`] of its owner.
+/// # Examples
+/// ```
+/// use bevy_health_system::{Team, TeamFilter};
+///
+/// assert!(TeamFilter::Allies.matches(Some(&Team(1)), Some(&Team(1))));
+/// assert!(!TeamFilter::Allies.matches(Some(&Team(1)), None));
+/// assert!(TeamFilter::Enemies.matches(Some(&Team(1)), None));
+/// assert!(TeamFilter::Team(Team(2)).matches(None, Some(&Team(2))));
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone, Reflect)]
+pub enum TeamFilter {
+    /// __Default__. Entities in the same team as the owner.
+    #[default]
+    Allies,
+    /// Entities not in the same team as the owner.
+    Enemies,
+    /// Entities in this team.
+    Team(Team),
+    /// Every entity.
+    All,
+}
+
+impl TeamFilter {
+    /// Returns true if an entity of team `other` passes this filter for an owner of team `owner`.
+    /// Entities without a team are nobody's allies.
+    pub fn matches(&self, owner: Option<&Team>, other: Option<&Team>) -> bool {
+        let allies = owner.is_some() && owner == other;
+        match self {
+            TeamFilter::Allies => allies,
+            TeamFilter::Enemies => !allies,
+            TeamFilter::Team(team) => other == Some(team),
+            TeamFilter::All => true,
+        }
+    }
+}
+
+/// How damage between members of the same [`Team`] is handled. Damage an entity deals to itself is never friendly fire.
+/// Forced damage ignores this policy.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageAppliedEvent, DamageEvent, FriendlyFirePolicy, HealthSystem, HealthSystemPlugin, Team,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(FriendlyFirePolicy::Scale(0.5));
+///
+/// let ally = app.world.spawn(Team(1)).id();
+/// let entity = app.world.spawn((HealthSystem::new(100.0), Team(1))).id();
+///
+/// app.world.send_event(DamageEvent::new(entity, 10.0).with_source(ally));
+/// app.update();
+///
+/// assert_eq!(95.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// let events = app.world.resource::<Events<DamageAppliedEvent>>();
+/// assert!(events.iter_current_update_events().next().unwrap().friendly_fire);
+/// ```
+#[derive(Debug, Default, PartialEq, Copy, Clone, Resource)]
+pub enum FriendlyFirePolicy {
+    /// Deal friendly fire like any other damage.
+    Allow,
+    /// __Default__. Reject friendly fire, sending [`FriendlyFireBlocked`].
+    #[default]
+    Block,
+    /// Multiply friendly fire by this value.
+    Scale(f32),
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when a [`DamageEvent`](crate::DamageEvent) is rejected by the [`FriendlyFirePolicy`].
+/// # Fields
+/// * `target`: The entity that would have taken the damage.
+/// * `source`: The teammate that would have dealt it.
+#[derive(Debug, Clone, Event)]
+pub struct FriendlyFireBlocked {
+    pub target: Entity,
+    pub source: Entity,
+}
+
+pub(crate) fn friendly_fire_stage(context: &mut DamageContext, world: &mut World) {
+    let Some(source) = context.event.source.filter(|&source| source != context.hit) else {
+        return;
+    };
+    let (Some(team), Some(other)) = (world.get::<Team>(source), world.get::<Team>(context.hit))
+    else {
+        return;
+    };
+    if team != other {
+        return;
+    }
+
+    context.friendly_fire = true;
+    if context.event.force {
+        return;
+    }
+    match *world.resource::<FriendlyFirePolicy>() {
+        FriendlyFirePolicy::Allow => {}
+        FriendlyFirePolicy::Block => {
+            context.cancel();
+            if !context.is_preview() {
+                world.send_event(FriendlyFireBlocked {
+                    target: context.hit,
+                    source,
+                });
+            }
+        }
+        FriendlyFirePolicy::Scale(multiplier) => context.amount *= multiplier,
+    }
+}
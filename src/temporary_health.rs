@@ -0,0 +1,122 @@
+//! Temporary health above max health, also known as overheal.
+use bevy::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::DamageContext;
+
+/// Health above max health, consumed by damage from a [`DamageEvent`](crate::DamageEvent)
+/// after any [`Shield`](crate::Shield) and before the [`HealthSystem`](crate::HealthSystem).
+/// Fill it with [`HealEvent::with_overheal`](crate::HealEvent::with_overheal) or by hand, it decays over time.
+/// # Fields
+/// * `amount`: The current temporary health.
+/// * `decay_per_second`: Temporary health lost every second.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, HealthSystem, HealthSystemPlugin, TemporaryHealth};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), TemporaryHealth::new(20.0, 0.0)))
+///     .id();
+/// app.world.send_event(DamageEvent::new(entity, 30.0));
+/// app.update();
+///
+/// assert_eq!(0.0, app.world.get::<TemporaryHealth>(entity).unwrap().amount);
+/// assert_eq!(90.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// ```
+/// Temporary health is only consumed once the damage is applied, a cancelled hit leaves it untouched.
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageContext, DamageEvent, DamagePipelineAppExt, DamageStage, HealthSystem,
+///     HealthSystemPlugin, TemporaryHealth,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.add_damage_modifier(
+///     DamageStage::PostMitigation,
+///     |context: &mut DamageContext, _: &mut World| context.cancel(),
+/// );
+///
+/// let entity = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), TemporaryHealth::new(20.0, 0.0)))
+///     .id();
+/// app.world.send_event(DamageEvent::new(entity, 30.0));
+/// app.update();
+/// assert_eq!(20.0, app.world.get::<TemporaryHealth>(entity).unwrap().amount);
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TemporaryHealth {
+    pub amount: f32,
+    pub decay_per_second: f32,
+}
+
+impl TemporaryHealth {
+    /// Create new temporary health.
+    /// # Arguments
+    /// * `amount`: The starting temporary health.
+    /// * `decay_per_second`: Temporary health lost every second.
+    pub fn new(amount: f32, decay_per_second: f32) -> Self {
+        Self {
+            amount,
+            decay_per_second,
+        }
+    }
+
+    /// - Absorb damage with this temporary health.
+    /// - Returns the damage that could not be absorbed.
+    /// # Arguments
+    /// * `amount`: The amount of damage to absorb.
+    /// # Example
+    /// ```
+    /// use bevy_health_system::TemporaryHealth;
+    ///
+    /// let mut temporary_health = TemporaryHealth::new(20.0, 1.0);
+    /// assert_eq!(5.0, temporary_health.absorb(25.0));
+    /// assert_eq!(0.0, temporary_health.amount);
+    /// ```
+    pub fn absorb(&mut self, amount: f32) -> f32 {
+        let absorbed = amount.clamp(0.0, self.amount.max(0.0));
+        self.amount -= absorbed;
+
+        amount - absorbed
+    }
+}
+
+pub(crate) fn decay_temporary_health(time: Res<Time>, mut query: Query<&mut TemporaryHealth>) {
+    for mut temporary_health in &mut query {
+        if temporary_health.amount <= 0.0 {
+            continue;
+        }
+
+        temporary_health.amount = (temporary_health.amount
+            - temporary_health.decay_per_second * time.delta_seconds())
+        .max(0.0);
+    }
+}
+
+pub(crate) fn temporary_health_stage(context: &mut DamageContext, world: &mut World) {
+    let Some(mut temporary_health) = world.get::<TemporaryHealth>(context.target).cloned() else {
+        return;
+    };
+
+    let remaining = temporary_health.absorb(context.amount);
+    context.temporary_absorbed = context.amount - remaining;
+    context.amount = remaining;
+}
+
+/// Consume the [`TemporaryHealth`] of the target by the damage it absorbed from an applied hit.
+pub(crate) fn apply_temporary_absorbed(context: &DamageContext, world: &mut World) {
+    if let Some(mut temporary_health) = world.get_mut::<TemporaryHealth>(context.target) {
+        temporary_health.absorb(context.temporary_absorbed);
+    }
+}
@@ -0,0 +1,257 @@
+//! Health bar themes, loaded from RON assets and hot reloaded.
+use bevy::asset::{AssetLoader, BoxedFuture, LoadContext, LoadedAsset};
+use bevy::prelude::*;
+use bevy::reflect::{TypePath, TypeUuid};
+use serde::Deserialize;
+
+use crate::{Fraction, HealthBar, HealthSystem};
+
+/// The look of a [`HealthBar`], loaded from `.healthbar.ron` files, see [`ThemedHealthBar`].
+/// Themes are hot reloaded if the `AssetPlugin` watches for changes.
+/// # Fields
+/// * `background`: The color of the empty part of the bar.
+/// * `colors`: The color of the health by fraction of health, each color applies from its fraction upward.
+/// * `gradient`: Blend between `colors` instead of switching from one to the next.
+/// * `border`: The width, in pixels, and color of the border, if any.
+/// * `segment`: Draw a tick every `segment` health, if set.
+/// * `tick_color`: The color of the ticks.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{Fraction, HealthBarTheme};
+///
+/// let theme = HealthBarTheme::from_ron(
+///     r#"(
+///         colors: [
+///             (0.0, Rgba(red: 1.0, green: 0.0, blue: 0.0, alpha: 1.0)),
+///             (1.0, Rgba(red: 0.0, green: 1.0, blue: 0.0, alpha: 1.0)),
+///         ],
+///         gradient: true,
+///         segment: Some(25.0),
+///     )"#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(Color::rgba(0.5, 0.5, 0.0, 1.0), theme.color_at(Fraction::HALF));
+/// assert_eq!(Some(25.0), theme.segment);
+/// ```
+#[derive(Debug, Clone, Deserialize, TypeUuid, TypePath)]
+#[uuid = "5d3c2a0b-6a47-4f0e-9a43-b4f0f3c7e2d1"]
+#[serde(default)]
+pub struct HealthBarTheme {
+    pub background: Color,
+    pub colors: Vec<(f32, Color)>,
+    pub gradient: bool,
+    pub border: Option<(f32, Color)>,
+    pub segment: Option<f32>,
+    pub tick_color: Color,
+}
+
+impl Default for HealthBarTheme {
+    fn default() -> Self {
+        Self {
+            background: Color::DARK_GRAY,
+            colors: vec![(0.0, Color::RED)],
+            gradient: false,
+            border: None,
+            segment: None,
+            tick_color: Color::BLACK,
+        }
+    }
+}
+
+impl HealthBarTheme {
+    /// Parse a theme from RON.
+    pub fn from_ron(ron: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(ron)
+    }
+
+    /// The color of the health at this fraction of health, from `0.0` to `1.0`.
+    pub fn color_at(&self, fraction: Fraction) -> Color {
+        let fraction = fraction.get();
+        let mut colors = self.colors.clone();
+        colors.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        let Some(&(first_fraction, first)) = colors.first() else {
+            return Color::WHITE;
+        };
+        if fraction <= first_fraction {
+            return first;
+        }
+
+        for pair in colors.windows(2) {
+            let ((from_fraction, from), (to_fraction, to)) = (pair[0], pair[1]);
+            if fraction >= to_fraction {
+                continue;
+            }
+            if !self.gradient || to_fraction <= from_fraction {
+                return from;
+            }
+
+            let t = (fraction - from_fraction) / (to_fraction - from_fraction);
+            let (from, to) = (from.as_rgba_f32(), to.as_rgba_f32());
+            let [r, g, b, a] = std::array::from_fn(|i| from[i] + (to[i] - from[i]) * t);
+            return Color::rgba(r, g, b, a);
+        }
+
+        colors.last().map_or(first, |&(_, color)| color)
+    }
+}
+
+/// Loads [`HealthBarTheme`]s from `.healthbar.ron` files.
+#[derive(Debug, Default)]
+pub struct HealthBarThemeLoader;
+
+impl AssetLoader for HealthBarThemeLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let theme = ron::de::from_bytes::<HealthBarTheme>(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(theme));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["healthbar.ron"]
+    }
+}
+
+/// Style a [`HealthBar`] with a [`HealthBarTheme`], it overrides the colors of the bar.
+/// Themes are registered by the [`HealthBarUiPlugin`](crate::HealthBarUiPlugin) if the `AssetPlugin` is added before it,
+/// load them with the `AssetServer`, eg: `asset_server.load("ui/player.healthbar.ron")`.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     HealthBar, HealthBarTheme, HealthBarUiPlugin, HealthSystem, HealthSystemPlugin, ThemedHealthBar,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, AssetPlugin::default(), HealthSystemPlugin, HealthBarUiPlugin));
+///
+/// let theme = app.world.resource_mut::<Assets<HealthBarTheme>>().add(HealthBarTheme {
+///     colors: vec![(0.0, Color::RED), (0.5, Color::GREEN)],
+///     segment: Some(25.0),
+///     ..default()
+/// });
+/// let mut health_system = HealthSystem::new(100.0);
+/// health_system.deal_damage(25.0, false);
+/// let player = app.world.spawn(health_system).id();
+/// let bar = app
+///     .world
+///     .spawn((HealthBar::new(player), ThemedHealthBar::new(theme)))
+///     .id();
+/// app.update();
+///
+/// let fill = app.world.get::<HealthBar>(bar).unwrap().fill_node().unwrap();
+/// assert_eq!(Color::GREEN, app.world.get::<BackgroundColor>(fill).unwrap().0);
+/// // A fill and 3 ticks, at 25, 50 and 75 health.
+/// assert_eq!(4, app.world.get::<Children>(bar).unwrap().len());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct ThemedHealthBar {
+    pub theme: Handle<HealthBarTheme>,
+    #[reflect(ignore)]
+    ticks: Vec<Entity>,
+    #[reflect(ignore)]
+    ticks_for: Option<(f32, Option<f32>)>,
+}
+
+impl ThemedHealthBar {
+    /// Style a bar with this theme.
+    pub fn new(theme: Handle<HealthBarTheme>) -> Self {
+        Self {
+            theme,
+            ticks: Vec::new(),
+            ticks_for: None,
+        }
+    }
+}
+
+#[derive(Component)]
+struct HealthBarTick;
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn apply_health_bar_themes(
+    mut commands: Commands,
+    themes: Res<Assets<HealthBarTheme>>,
+    mut theme_events: EventReader<AssetEvent<HealthBarTheme>>,
+    mut bars: Query<(Entity, Ref<HealthBar>, &mut ThemedHealthBar)>,
+    targets: Query<Ref<HealthSystem>>,
+    mut colors: Query<&mut BackgroundColor>,
+    mut styles: Query<&mut Style>,
+) {
+    let reloaded: Vec<_> = theme_events
+        .iter()
+        .filter_map(|event| match event {
+            AssetEvent::Created { handle } | AssetEvent::Modified { handle } => Some(handle.id()),
+            AssetEvent::Removed { .. } => None,
+        })
+        .collect();
+
+    for (entity, bar, mut themed) in &mut bars {
+        let Some(theme) = themes.get(&themed.theme) else {
+            continue;
+        };
+        let Ok(health_system) = targets.get(bar.target) else {
+            continue;
+        };
+        let theme_changed = themed.is_added() || reloaded.contains(&themed.theme.id());
+        if !theme_changed && !bar.is_changed() && !health_system.is_changed() {
+            continue;
+        }
+
+        let fraction = health_system.get_health_fraction().clamped();
+        if let Some(mut color) = bar.fill_node().and_then(|fill| colors.get_mut(fill).ok()) {
+            color.0 = theme.color_at(fraction);
+        }
+        if let Ok(mut color) = colors.get_mut(entity) {
+            color.0 = theme.background;
+        }
+        let (border_width, border_color) = theme.border.unwrap_or((0.0, Color::NONE));
+        if let Ok(mut style) = styles.get_mut(entity) {
+            style.border = UiRect::all(Val::Px(border_width));
+        }
+        commands.entity(entity).insert(BorderColor(border_color));
+
+        let max = health_system.get_health_max();
+        if !theme_changed && themed.ticks_for == Some((max, theme.segment)) {
+            continue;
+        }
+        for tick in themed.ticks.drain(..) {
+            commands.entity(tick).despawn_recursive();
+        }
+        themed.ticks_for = Some((max, theme.segment));
+        let Some(segment) = theme.segment.filter(|&segment| segment > 0.0 && max > 0.0) else {
+            continue;
+        };
+
+        let mut health = segment;
+        while health < max {
+            let tick = commands
+                .spawn((
+                    HealthBarTick,
+                    NodeBundle {
+                        style: Style {
+                            position_type: PositionType::Absolute,
+                            left: Val::Percent(health / max * 100.0),
+                            width: Val::Px(1.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        background_color: theme.tick_color.into(),
+                        ..default()
+                    },
+                ))
+                .id();
+            commands.entity(entity).add_child(tick);
+            themed.ticks.push(tick);
+            health += segment;
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Thorns, reflecting damage back to the attacker.
+use bevy::prelude::*;
+
+use crate::{DamageAppliedEvent, DamageEvent};
+
+/// Reflect damage taken by this entity back to its source.
+/// Reflected damage is a new [`DamageEvent`], marked as [`DamageEvent::reflected`] so it is never reflected again,
+/// which keeps two entities with thorns from damaging each other forever.
+/// # Fields
+/// * `flat`: Damage reflected on every hit.
+/// * `percent`: Portion of the health lost reflected, eg: `0.5` reflects half of it.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, HealthSystem, HealthSystemPlugin, Thorns};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let cactus = app.world.spawn((HealthSystem::new(100.0), Thorns::new(1.0, 0.5))).id();
+/// let attacker = app.world.spawn((HealthSystem::new(100.0), Thorns::new(1.0, 0.5))).id();
+///
+/// app.world.send_event(DamageEvent::new(cactus, 20.0).with_source(attacker));
+/// for _ in 0..3 {
+///     app.update();
+/// }
+///
+/// assert_eq!(80.0, app.world.get::<HealthSystem>(cactus).unwrap().get_health());
+/// assert_eq!(89.0, app.world.get::<HealthSystem>(attacker).unwrap().get_health());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct Thorns {
+    pub flat: f32,
+    pub percent: f32,
+}
+
+impl Thorns {
+    /// Create new thorns.
+    /// # Arguments
+    /// * `flat`: Damage reflected on every hit.
+    /// * `percent`: Portion of the health lost reflected.
+    pub fn new(flat: f32, percent: f32) -> Self {
+        Self { flat, percent }
+    }
+
+    /// Returns the damage reflected when the owner loses `amount` health.
+    pub fn reflect(&self, amount: f32) -> f32 {
+        self.flat + amount * self.percent
+    }
+}
+
+pub(crate) fn reflect_damage(
+    mut damaged: EventReader<DamageAppliedEvent>,
+    thorns: Query<&Thorns>,
+    mut damage: EventWriter<DamageEvent>,
+) {
+    for event in damaged.iter() {
+        if event.reflected {
+            continue;
+        }
+        let Some(source) = event.source.filter(|source| *source != event.target) else {
+            continue;
+        };
+        let Ok(thorns) = thorns.get(event.target) else {
+            continue;
+        };
+
+        let amount = thorns.reflect(event.amount);
+        if amount > 0.0 {
+            let mut reflected = DamageEvent::new(source, amount).with_source(event.target);
+            reflected.reflected = true;
+            damage.send(reflected);
+        }
+    }
+}
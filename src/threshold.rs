@@ -0,0 +1,134 @@
+//! Events sent when health passes given fractions of max health.
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::{Fraction, HealthSystem};
+
+/// Send a [`ThresholdCrossed`] event each time the health of an entity passes one of these fractions of its max health.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageEvent, Fraction, HealthSystem, HealthSystemPlugin, HealthThresholds,
+///     ThresholdCrossed, ThresholdDirection,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let entity = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), HealthThresholds::new([25.0, 50.0, 75.0].map(Fraction::from_percent))))
+///     .id();
+/// app.update();
+///
+/// app.world.send_event(DamageEvent::new(entity, 60.0));
+/// app.update();
+///
+/// let events = app.world.resource::<Events<ThresholdCrossed>>();
+/// let crossed: Vec<_> = events
+///     .iter_current_update_events()
+///     .map(|event| (event.threshold, event.direction))
+///     .collect();
+/// assert_eq!(
+///     vec![(0.75, ThresholdDirection::Falling), (0.5, ThresholdDirection::Falling)],
+///     crossed
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct HealthThresholds(pub Vec<f32>);
+
+impl HealthThresholds {
+    /// Send events at these fractions of max health, eg: `[Fraction::from_percent(25.0)]`.
+    pub fn new(thresholds: impl IntoIterator<Item = Fraction>) -> Self {
+        Self(
+            thresholds
+                .into_iter()
+                .map(|threshold| threshold.get())
+                .collect(),
+        )
+    }
+}
+
+/// Which way the health went when passing a threshold.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ThresholdDirection {
+    /// The health fell to or below the threshold.
+    Falling,
+    /// The health rose above the threshold.
+    Rising,
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) when the health of an entity passes one of its [`HealthThresholds`].
+/// Thresholds passed in the same frame are sent in the order the health passed them.
+/// # Fields
+/// * `entity`: The entity whose health passed the threshold.
+/// * `threshold`: The threshold passed, as a fraction of max health.
+/// * `direction`: Which way the health went.
+#[derive(Debug, Clone, Event)]
+pub struct ThresholdCrossed {
+    pub entity: Entity,
+    pub threshold: f32,
+    pub direction: ThresholdDirection,
+}
+
+impl ThresholdCrossed {
+    /// Returns the threshold passed as a [`Fraction`].
+    pub fn fraction(&self) -> Fraction {
+        Fraction::new(self.threshold)
+    }
+}
+
+pub(crate) fn detect_threshold_crossings(
+    mut previous: Local<HashMap<Entity, f32>>,
+    query: Query<(Entity, &HealthSystem, &HealthThresholds), Changed<HealthSystem>>,
+    added: Query<(Entity, &HealthSystem), Added<HealthThresholds>>,
+    mut removed: RemovedComponents<HealthThresholds>,
+    mut crossings: EventWriter<ThresholdCrossed>,
+) {
+    for entity in removed.iter() {
+        previous.remove(&entity);
+    }
+
+    for (entity, health_system) in &added {
+        previous.insert(entity, health_system.get_health_normalized());
+    }
+
+    for (entity, health_system, thresholds) in &query {
+        let current = health_system.get_health_normalized();
+        let Some(last) = previous.insert(entity, current) else {
+            continue;
+        };
+
+        let mut crossed: Vec<_> = if current < last {
+            thresholds
+                .0
+                .iter()
+                .copied()
+                .filter(|&threshold| last > threshold && current <= threshold)
+                .map(|threshold| (threshold, ThresholdDirection::Falling))
+                .collect()
+        } else {
+            thresholds
+                .0
+                .iter()
+                .copied()
+                .filter(|&threshold| last <= threshold && current > threshold)
+                .map(|threshold| (threshold, ThresholdDirection::Rising))
+                .collect()
+        };
+        crossed.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        if current < last {
+            crossed.reverse();
+        }
+
+        for (threshold, direction) in crossed {
+            crossings.send(ThresholdCrossed {
+                entity,
+                threshold,
+                direction,
+            });
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Health transfers, draining an entity to heal another one.
+use bevy::prelude::*;
+
+use crate::heal::HealPipeline;
+
+/// Request to move health from an entity to another one, eg: a vampiric tether or a "share life" spell.
+/// Send it with [`HealthWriter::transfer_health`](crate::HealthWriter::transfer_health), the
+/// [`HealthSystemPlugin`](crate::HealthSystemPlugin) applies it at once, both entities must be alive.
+/// The drain bypasses the [`DamagePipeline`](crate::DamagePipeline) but not invincibility,
+/// the heal is scaled by the [`HealingModifier`](crate::HealingModifier) of the receiver
+/// and clamped to its max health, health it can't receive is lost.
+/// # Fields
+/// * `from`: The entity drained.
+/// * `to`: The entity healed.
+/// * `amount`: The health drained, at most the health of `from`.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{HealthSystem, HealthSystemPlugin, HealthTransferEvent, HealthTransferred};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+///
+/// let victim = app.world.spawn(HealthSystem::new(100.0)).id();
+/// let mut health_system = HealthSystem::new(100.0);
+/// health_system.deal_damage(80.0, false);
+/// let vampire = app.world.spawn(health_system).id();
+///
+/// app.world.send_event(HealthTransferEvent::new(victim, vampire, 30.0));
+/// app.update();
+///
+/// assert_eq!(70.0, app.world.get::<HealthSystem>(victim).unwrap().get_health());
+/// assert_eq!(50.0, app.world.get::<HealthSystem>(vampire).unwrap().get_health());
+/// let events = app.world.resource::<Events<HealthTransferred>>();
+/// let transferred = events.iter_current_update_events().next().unwrap();
+/// assert_eq!((30.0, 30.0), (transferred.drained, transferred.healed));
+/// ```
+#[derive(Debug, Clone, Event)]
+pub struct HealthTransferEvent {
+    pub from: Entity,
+    pub to: Entity,
+    pub amount: f32,
+}
+
+impl HealthTransferEvent {
+    /// Create a new transfer request.
+    /// # Arguments
+    /// * `from`: The entity drained.
+    /// * `to`: The entity healed.
+    /// * `amount`: The health drained.
+    pub fn new(from: Entity, to: Entity, amount: f32) -> Self {
+        Self { from, to, amount }
+    }
+}
+
+/// Sent by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) after a [`HealthTransferEvent`] was applied.
+/// # Fields
+/// * `from`: The entity drained.
+/// * `to`: The entity healed.
+/// * `drained`: The health `from` lost.
+/// * `healed`: The health `to` gained, after its healing modifiers and max health.
+#[derive(Debug, Clone, Event)]
+pub struct HealthTransferred {
+    pub from: Entity,
+    pub to: Entity,
+    pub drained: f32,
+    pub healed: f32,
+}
+
+pub(crate) fn apply_health_transfers(
+    mut events: EventReader<HealthTransferEvent>,
+    mut pipeline: HealPipeline,
+    mut transferred: EventWriter<HealthTransferred>,
+) {
+    for event in events.iter() {
+        if event.amount <= 0.0
+            || pipeline.resolve(event.from) == pipeline.resolve(event.to)
+            || !pipeline.is_alive(event.from)
+            || !pipeline.is_alive(event.to)
+        {
+            continue;
+        }
+
+        let drained = pipeline.drain(event.from, event.amount);
+        if drained <= 0.0 {
+            continue;
+        }
+        let healed = pipeline.heal(event.to, drained, false, Some(event.from));
+        transferred.send(HealthTransferred {
+            from: event.from,
+            to: event.to,
+            drained,
+            healed,
+        });
+    }
+}
@@ -0,0 +1,648 @@
+//! `bevy_ui` health bars bound to the [`HealthSystem`] of an entity.
+use bevy::ecs::entity::{EntityMapper, MapEntities};
+use bevy::ecs::reflect::ReflectMapEntities;
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use bevy::utils::HashSet;
+
+use crate::{HealthPhases, HealthSystem};
+
+/// Add this plugin to build and update the [`HealthBar`]s, [`BossBar`]s and [`HealthText`]s of your UI.
+/// It is not part of the [`HealthSystemPlugin`](crate::HealthSystemPlugin), add both.
+#[derive(Debug, Default)]
+pub struct HealthBarUiPlugin;
+
+impl Plugin for HealthBarUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (
+                spawn_health_bars,
+                spawn_boss_bars,
+                apply_deferred,
+                update_health_bars,
+                update_health_texts,
+                update_boss_bars,
+                despawn_health_bars,
+                despawn_boss_bars,
+            )
+                .chain()
+                .before(UiSystem::Layout),
+        );
+
+        // Themes are assets, they need the `AssetPlugin` added before this plugin.
+        #[cfg(feature = "theme")]
+        if app.world.contains_resource::<AssetServer>() {
+            app.add_asset::<crate::HealthBarTheme>()
+                .init_asset_loader::<crate::HealthBarThemeLoader>()
+                .register_type::<crate::ThemedHealthBar>()
+                .add_systems(
+                    PostUpdate,
+                    crate::theme::apply_health_bar_themes
+                        .after(update_health_bars)
+                        .before(UiSystem::Layout),
+                );
+        }
+    }
+}
+
+/// A `bevy_ui` bar showing the health of the `target` entity, built by the [`HealthBarUiPlugin`].
+/// Spawn it like any UI node, its `Style` is kept, only its size is set. The bar is updated when the health changes,
+/// and despawned with its children once the target loses its [`HealthSystem`] or is despawned.
+/// # Fields
+/// * `target`: The entity whose [`HealthSystem`] is shown.
+/// * `size`: The size of the bar, in pixels.
+/// * `background`: The color of the empty part of the bar.
+/// * `fill`: The color of the health.
+/// * `text`: The font size and color of the "75/100" text, hidden if `None`.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{HealthBar, HealthBarUiPlugin, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin, HealthBarUiPlugin));
+///
+/// let mut health_system = HealthSystem::new(100.0);
+/// health_system.deal_damage(25.0, false);
+/// let player = app.world.spawn(health_system).id();
+/// let bar = app
+///     .world
+///     .spawn(HealthBar::new(player).with_text(16.0, Color::WHITE))
+///     .id();
+/// app.update();
+///
+/// assert_eq!(Some("75/100"), app.world.get::<HealthBar>(bar).unwrap().text(&app.world));
+///
+/// app.world.despawn(player);
+/// app.update();
+/// assert!(app.world.get_entity(bar).is_none());
+/// ```
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct HealthBar {
+    pub target: Entity,
+    pub size: Vec2,
+    pub background: Color,
+    pub fill: Color,
+    pub text: Option<(f32, Color)>,
+    #[reflect(ignore)]
+    fill_node: Option<Entity>,
+    #[reflect(ignore)]
+    text_node: Option<Entity>,
+}
+
+/// Keep the bar showing the same entity when spawned from a scene.
+impl MapEntities for HealthBar {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        self.target = entity_mapper.get_or_reserve(self.target);
+    }
+}
+
+/// A bar without target, used when a bar is built through reflection, eg: by a scene.
+impl Default for HealthBar {
+    fn default() -> Self {
+        Self::new(Entity::PLACEHOLDER)
+    }
+}
+
+impl HealthBar {
+    /// Create a 200x20 red bar without text.
+    /// # Arguments
+    /// * `target`: The entity whose [`HealthSystem`] is shown.
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            size: Vec2::new(200.0, 20.0),
+            background: Color::DARK_GRAY,
+            fill: Color::RED,
+            text: None,
+            fill_node: None,
+            text_node: None,
+        }
+    }
+
+    /// Set the size of the bar, in pixels.
+    pub fn with_size(mut self, size: Vec2) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the colors of the empty part of the bar and of the health.
+    pub fn with_colors(mut self, background: Color, fill: Color) -> Self {
+        self.background = background;
+        self.fill = fill;
+        self
+    }
+
+    /// Show the health as text over the bar, eg: "75/100".
+    pub fn with_text(mut self, font_size: f32, color: Color) -> Self {
+        self.text = Some((font_size, color));
+        self
+    }
+
+    /// Returns the node of the health, child of the bar, once built.
+    pub fn fill_node(&self) -> Option<Entity> {
+        self.fill_node
+    }
+
+    /// Returns the text currently shown, if any.
+    pub fn text<'w>(&self, world: &'w World) -> Option<&'w str> {
+        let text = world.get::<Text>(self.text_node?)?;
+        text.sections.first().map(|section| section.value.as_str())
+    }
+}
+
+#[derive(Component)]
+struct HealthBarFill;
+
+#[derive(Component)]
+struct HealthBarText;
+
+fn spawn_health_bars(
+    mut commands: Commands,
+    mut bars: Query<(Entity, &mut HealthBar, Option<&Style>), Added<HealthBar>>,
+) {
+    for (entity, mut bar, style) in &mut bars {
+        let mut style = style.cloned().unwrap_or_default();
+        style.width = Val::Px(bar.size.x);
+        style.height = Val::Px(bar.size.y);
+        style.justify_content = JustifyContent::Center;
+        style.align_items = AlignItems::Center;
+
+        let fill = commands
+            .spawn((
+                HealthBarFill,
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(0.0),
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    background_color: bar.fill.into(),
+                    ..default()
+                },
+            ))
+            .id();
+        bar.fill_node = Some(fill);
+        bar.text_node = bar.text.map(|(font_size, color)| {
+            let text_style = TextStyle {
+                font_size,
+                color,
+                ..default()
+            };
+            commands
+                .spawn((HealthBarText, TextBundle::from_section("", text_style)))
+                .id()
+        });
+
+        commands
+            .entity(entity)
+            .insert(NodeBundle {
+                style,
+                background_color: bar.background.into(),
+                ..default()
+            })
+            .push_children(&[fill])
+            .push_children(bar.text_node.as_slice());
+    }
+}
+
+fn update_health_bars(
+    bars: Query<Ref<HealthBar>>,
+    targets: Query<Ref<HealthSystem>>,
+    mut fills: Query<&mut Style, With<HealthBarFill>>,
+    mut texts: Query<&mut Text, With<HealthBarText>>,
+) {
+    for bar in &bars {
+        let Ok(health_system) = targets.get(bar.target) else {
+            continue;
+        };
+        if !health_system.is_changed() && !bar.is_changed() {
+            continue;
+        }
+
+        if let Some(mut style) = bar.fill_node.and_then(|fill| fills.get_mut(fill).ok()) {
+            style.width = Val::Percent(health_system.get_health_fraction().clamped().to_percent());
+        }
+        if let Some(mut text) = bar.text_node.and_then(|text| texts.get_mut(text).ok()) {
+            text.sections[0].value = format!(
+                "{:.0}/{:.0}",
+                health_system.get_health().max(0.0),
+                health_system.get_health_max()
+            );
+        }
+    }
+}
+
+/// How a [`HealthText`] shows the health.
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone, Reflect)]
+pub enum HealthTextFormat {
+    /// __Default__. The health out of the max health, eg: "75 / 100".
+    #[default]
+    CurrentOfMax,
+    /// The health alone, eg: "75".
+    Current,
+    /// The health as a percentage of max health, eg: "75.0%" with a precision of `1`.
+    Percent {
+        /// The number of decimals shown.
+        precision: usize,
+    },
+}
+
+impl HealthTextFormat {
+    /// Format the health of a system, health and max health are rounded to whole numbers.
+    pub fn format(self, health_system: &HealthSystem) -> String {
+        match self {
+            Self::CurrentOfMax => format!("{health_system:.0}"),
+            Self::Current => format!("{:.0}", health_system.get_health().max(0.0)),
+            Self::Percent { precision } => health_system.format_percent(precision),
+        }
+    }
+}
+
+/// The first section of the `Text` of this entity shows the health of the `target` entity,
+/// kept in sync by the [`HealthBarUiPlugin`], eg: for HUD labels.
+/// # Fields
+/// * `target`: The entity whose [`HealthSystem`] is shown.
+/// * `format`: How the health is shown.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     DamageEvent, HealthBarUiPlugin, HealthSystem, HealthSystemPlugin, HealthText,
+///     HealthTextFormat,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin, HealthBarUiPlugin));
+///
+/// let player = app.world.spawn(HealthSystem::new(100.0)).id();
+/// let label = app
+///     .world
+///     .spawn((TextBundle::from_section("", TextStyle::default()), HealthText::new(player)))
+///     .id();
+/// let percent = app
+///     .world
+///     .spawn((
+///         TextBundle::from_section("", TextStyle::default()),
+///         HealthText::new(player).with_format(HealthTextFormat::Percent { precision: 1 }),
+///     ))
+///     .id();
+/// app.world.send_event(DamageEvent::new(player, 25.0));
+/// app.update();
+///
+/// let text = |entity| app.world.get::<Text>(entity).unwrap().sections[0].value.clone();
+/// assert_eq!("75 / 100", text(label));
+/// assert_eq!("75.0%", text(percent));
+/// ```
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component, MapEntities)]
+pub struct HealthText {
+    pub target: Entity,
+    pub format: HealthTextFormat,
+}
+
+/// Keep the text showing the same entity when spawned from a scene.
+impl MapEntities for HealthText {
+    fn map_entities(&mut self, entity_mapper: &mut EntityMapper) {
+        self.target = entity_mapper.get_or_reserve(self.target);
+    }
+}
+
+/// A text without target, used when a text is built through reflection, eg: by a scene.
+impl Default for HealthText {
+    fn default() -> Self {
+        Self::new(Entity::PLACEHOLDER)
+    }
+}
+
+impl HealthText {
+    /// Show the health of `target` out of its max health, eg: "75 / 100".
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            format: HealthTextFormat::default(),
+        }
+    }
+
+    /// Set how the health is shown.
+    pub fn with_format(mut self, format: HealthTextFormat) -> Self {
+        self.format = format;
+        self
+    }
+}
+
+fn update_health_texts(
+    mut texts: Query<(Ref<HealthText>, &mut Text)>,
+    targets: Query<Ref<HealthSystem>>,
+) {
+    for (health_text, mut text) in &mut texts {
+        let Ok(health_system) = targets.get(health_text.target) else {
+            continue;
+        };
+        if !health_system.is_changed() && !health_text.is_changed() {
+            continue;
+        }
+        let value = health_text.format.format(&health_system);
+        match text.sections.first_mut() {
+            Some(section) => section.value = value,
+            None => text
+                .sections
+                .push(TextSection::new(value, TextStyle::default())),
+        }
+    }
+}
+
+/// A large bar at the top of the screen showing the health of this entity, eg: a boss, built by the [`HealthBarUiPlugin`].
+/// The [`HealthPhases`] of the entity, if any, are marked on the bar. The bar is removed once the entity dies, loses its [`HealthSystem`] or is despawned.
+/// # Fields
+/// * `title`: The text shown above the bar, eg: the name of the boss.
+/// * `size`: The size of the bar, in pixels.
+/// * `background`: The color of the empty part of the bar.
+/// * `fill`: The color of the health.
+/// * `marker`: The color of the phase markers.
+/// * `text`: The font size and color of the title.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{
+///     BossBar, DamageEvent, Fraction, HealthBarUiPlugin, HealthPhases, HealthSystem,
+///     HealthSystemPlugin,
+/// };
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin, HealthBarUiPlugin));
+///
+/// let boss = app
+///     .world
+///     .spawn((
+///         HealthSystem::new(1000.0),
+///         HealthPhases::new([Fraction::HALF]),
+///         BossBar::new("Dragon"),
+///     ))
+///     .id();
+/// app.update();
+///
+/// let bar = app.world.get::<BossBar>(boss).unwrap();
+/// let root = bar.root().unwrap();
+/// // A fill and a phase marker.
+/// let bar_node = bar.bar_node().unwrap();
+/// assert_eq!(2, app.world.get::<Children>(bar_node).unwrap().len());
+///
+/// app.world.send_event(DamageEvent::new(boss, 1000.0));
+/// app.update();
+/// assert!(app.world.get_entity(root).is_none());
+/// ```
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct BossBar {
+    pub title: String,
+    pub size: Vec2,
+    pub background: Color,
+    pub fill: Color,
+    pub marker: Color,
+    pub text: (f32, Color),
+    #[reflect(ignore)]
+    root: Option<Entity>,
+    #[reflect(ignore)]
+    bar_node: Option<Entity>,
+    #[reflect(ignore)]
+    fill_node: Option<Entity>,
+}
+
+/// A bar without title.
+impl Default for BossBar {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl BossBar {
+    /// Create a 600x24 red bar with phase markers in black.
+    /// # Arguments
+    /// * `title`: The text shown above the bar, eg: the name of the boss.
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            size: Vec2::new(600.0, 24.0),
+            background: Color::DARK_GRAY,
+            fill: Color::RED,
+            marker: Color::BLACK,
+            text: (24.0, Color::WHITE),
+            root: None,
+            bar_node: None,
+            fill_node: None,
+        }
+    }
+
+    /// Set the size of the bar, in pixels.
+    pub fn with_size(mut self, size: Vec2) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the colors of the empty part of the bar and of the health.
+    pub fn with_colors(mut self, background: Color, fill: Color) -> Self {
+        self.background = background;
+        self.fill = fill;
+        self
+    }
+
+    /// Set the color of the phase markers.
+    pub fn with_marker(mut self, marker: Color) -> Self {
+        self.marker = marker;
+        self
+    }
+
+    /// Set the font size and color of the title.
+    pub fn with_text(mut self, font_size: f32, color: Color) -> Self {
+        self.text = (font_size, color);
+        self
+    }
+
+    /// Returns the root node of the widget, holding the title and the bar, while shown.
+    pub fn root(&self) -> Option<Entity> {
+        self.root
+    }
+
+    /// Returns the node of the bar, parent of the health and the phase markers, while shown.
+    pub fn bar_node(&self) -> Option<Entity> {
+        self.bar_node
+    }
+
+    /// Returns the node of the health, while shown.
+    pub fn fill_node(&self) -> Option<Entity> {
+        self.fill_node
+    }
+}
+
+/// The root of a boss bar, showing `target`.
+#[derive(Component)]
+struct BossBarRoot {
+    target: Entity,
+}
+
+fn spawn_boss_bars(
+    mut commands: Commands,
+    mut bosses: Query<(
+        Entity,
+        &mut BossBar,
+        &HealthSystem,
+        Option<Ref<HealthPhases>>,
+    )>,
+) {
+    for (target, mut bar, health_system, phases) in &mut bosses {
+        let rebuild = bar.is_changed() || phases.as_ref().is_some_and(|phases| phases.is_changed());
+        if let Some(root) = bar.root {
+            if !health_system.is_dead() && !rebuild {
+                continue;
+            }
+            commands.entity(root).despawn_recursive();
+            bar.root = None;
+            bar.bar_node = None;
+            bar.fill_node = None;
+        }
+        if health_system.is_dead() {
+            continue;
+        }
+
+        let fill = commands
+            .spawn(NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(0.0),
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                background_color: bar.fill.into(),
+                ..default()
+            })
+            .id();
+        let markers = phases
+            .iter()
+            .flat_map(|phases| phases.boundaries().to_vec());
+        let markers: Vec<_> = markers
+            .map(|boundary| {
+                commands
+                    .spawn(NodeBundle {
+                        style: Style {
+                            position_type: PositionType::Absolute,
+                            left: Val::Percent(boundary.clamp(0.0, 1.0) * 100.0),
+                            width: Val::Px(2.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        background_color: bar.marker.into(),
+                        ..default()
+                    })
+                    .id()
+            })
+            .collect();
+        let bar_node = commands
+            .spawn(NodeBundle {
+                style: Style {
+                    width: Val::Px(bar.size.x),
+                    height: Val::Px(bar.size.y),
+                    ..default()
+                },
+                background_color: bar.background.into(),
+                ..default()
+            })
+            .push_children(&[fill])
+            .push_children(&markers)
+            .id();
+
+        let (font_size, color) = bar.text;
+        let text_style = TextStyle {
+            font_size,
+            color,
+            ..default()
+        };
+        let title = commands
+            .spawn(TextBundle::from_section(bar.title.clone(), text_style))
+            .id();
+        let root = commands
+            .spawn((
+                BossBarRoot { target },
+                NodeBundle {
+                    style: Style {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(16.0),
+                        width: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(4.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+            ))
+            .push_children(&[title, bar_node])
+            .id();
+
+        // Do not trigger a rebuild next frame.
+        let bar = bar.bypass_change_detection();
+        bar.root = Some(root);
+        bar.bar_node = Some(bar_node);
+        bar.fill_node = Some(fill);
+    }
+}
+
+fn update_boss_bars(
+    bosses: Query<(Ref<BossBar>, Ref<HealthSystem>)>,
+    mut fills: Query<&mut Style>,
+) {
+    for (bar, health_system) in &bosses {
+        if !health_system.is_changed() && !bar.is_changed() {
+            continue;
+        }
+
+        if let Some(mut style) = bar.fill_node.and_then(|fill| fills.get_mut(fill).ok()) {
+            style.width = Val::Percent(health_system.get_health_fraction().clamped().to_percent());
+        }
+    }
+}
+
+fn despawn_health_bars(
+    mut commands: Commands,
+    mut removed: RemovedComponents<HealthSystem>,
+    bars: Query<(Entity, &HealthBar)>,
+) {
+    let removed: HashSet<_> = removed.iter().collect();
+    if removed.is_empty() {
+        return;
+    }
+    for (entity, bar) in &bars {
+        if removed.contains(&bar.target) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+fn despawn_boss_bars(
+    mut commands: Commands,
+    mut removed: RemovedComponents<HealthSystem>,
+    roots: Query<(Entity, &BossBarRoot)>,
+    mut bosses: Query<&mut BossBar>,
+) {
+    // Detach the bars of bosses losing their health, their roots are despawned below.
+    for target in removed.iter() {
+        if let Ok(mut bar) = bosses.get_mut(target) {
+            bar.root = None;
+            bar.bar_node = None;
+            bar.fill_node = None;
+        }
+    }
+    for (entity, root) in &roots {
+        if bosses
+            .get(root.target)
+            .map_or(true, |bar| bar.root != Some(entity))
+        {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
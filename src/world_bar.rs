@@ -0,0 +1,297 @@
+//! World space health bars floating above entities.
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+
+use crate::HealthSystem;
+
+/// Add this plugin to build and update the [`WorldHealthBar`]s of your entities.
+/// It is not part of the [`HealthSystemPlugin`](crate::HealthSystemPlugin), add both.
+#[derive(Debug, Default)]
+pub struct WorldHealthBarPlugin;
+
+impl Plugin for WorldHealthBarPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (
+                spawn_world_health_bars,
+                apply_deferred,
+                update_world_health_bars,
+                despawn_world_health_bars,
+            )
+                .chain()
+                .before(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+/// A bar floating above an entity with a [`HealthSystem`], built by the [`WorldHealthBarPlugin`].
+/// The bar is a separate entity following the entity, facing the 3D camera if any, and despawned with it
+/// or once it loses its [`HealthSystem`]. The bar is built again if a [`HealthSystem`] is inserted back.
+/// Bars are made of sprites for 2D games, or of unlit quads once [`WorldHealthBar::with_mesh`] is set for 3D games.
+/// # Fields
+/// * `offset`: The position of the bar relative to the entity.
+/// * `size`: The size of the bar, in world units.
+/// * `hide_when_full`: Hide the bar while the health is full.
+/// * `fade_delay`: Hide the bar once the health did not change for this long, if set.
+/// * `background`: The color of the empty part of the bar.
+/// * `fill`: The color of the health.
+/// * `mesh`: Build the bar out of meshes instead of sprites.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{HealthSystem, HealthSystemPlugin, WorldHealthBar, WorldHealthBarPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin, WorldHealthBarPlugin));
+///
+/// let entity = app
+///     .world
+///     .spawn((
+///         HealthSystem::new(100.0),
+///         TransformBundle::default(),
+///         WorldHealthBar::new(Vec3::Y * 2.0, Vec2::new(1.0, 0.1)),
+///     ))
+///     .id();
+/// app.update();
+///
+/// let bar = app.world.get::<WorldHealthBar>(entity).unwrap().bar().unwrap();
+/// assert_eq!(Vec3::Y * 2.0, app.world.get::<Transform>(bar).unwrap().translation);
+///
+/// app.world.entity_mut(entity).remove::<HealthSystem>();
+/// app.update();
+/// assert!(app.world.get_entity(bar).is_none());
+///
+/// app.world.entity_mut(entity).insert(HealthSystem::new(100.0));
+/// app.update();
+/// let bar = app.world.get::<WorldHealthBar>(entity).unwrap().bar().unwrap();
+///
+/// app.world.despawn(entity);
+/// app.update();
+/// assert!(app.world.get_entity(bar).is_none());
+/// ```
+#[derive(Debug, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct WorldHealthBar {
+    pub offset: Vec3,
+    pub size: Vec2,
+    pub hide_when_full: bool,
+    pub fade_delay: Option<Duration>,
+    pub background: Color,
+    pub fill: Color,
+    pub mesh: bool,
+    #[reflect(ignore)]
+    bar: Option<Entity>,
+    #[reflect(ignore)]
+    fill_node: Option<Entity>,
+    since_change: Duration,
+}
+
+/// A 1x0.1 bar, one unit above the entity.
+impl Default for WorldHealthBar {
+    fn default() -> Self {
+        Self::new(Vec3::Y, Vec2::new(1.0, 0.1))
+    }
+}
+
+impl WorldHealthBar {
+    /// Create a red bar made of sprites, always shown.
+    /// # Arguments
+    /// * `offset`: The position of the bar relative to the entity.
+    /// * `size`: The size of the bar, in world units.
+    pub fn new(offset: Vec3, size: Vec2) -> Self {
+        Self {
+            offset,
+            size,
+            hide_when_full: false,
+            fade_delay: None,
+            background: Color::DARK_GRAY,
+            fill: Color::RED,
+            mesh: false,
+            bar: None,
+            fill_node: None,
+            since_change: Duration::ZERO,
+        }
+    }
+
+    /// Hide the bar while the health is full.
+    pub fn hide_when_full(mut self) -> Self {
+        self.hide_when_full = true;
+        self
+    }
+
+    /// Hide the bar once the health did not change for `delay`.
+    pub fn with_fade_delay(mut self, delay: Duration) -> Self {
+        self.fade_delay = Some(delay);
+        self
+    }
+
+    /// Set the colors of the empty part of the bar and of the health.
+    pub fn with_colors(mut self, background: Color, fill: Color) -> Self {
+        self.background = background;
+        self.fill = fill;
+        self
+    }
+
+    /// Build the bar out of unlit quads, for 3D games. Requires the `PbrPlugin`.
+    pub fn with_mesh(mut self) -> Self {
+        self.mesh = true;
+        self
+    }
+
+    /// Returns the entity of the bar, once built.
+    pub fn bar(&self) -> Option<Entity> {
+        self.bar
+    }
+}
+
+/// The root of a bar, following `target`.
+#[derive(Component)]
+struct WorldHealthBarRoot {
+    target: Entity,
+}
+
+#[allow(clippy::type_complexity)]
+fn spawn_world_health_bars(
+    mut commands: Commands,
+    mut bars: Query<
+        (Entity, &mut WorldHealthBar),
+        (
+            With<HealthSystem>,
+            Or<(Added<WorldHealthBar>, Added<HealthSystem>)>,
+        ),
+    >,
+    mut meshes: Option<ResMut<Assets<Mesh>>>,
+    mut materials: Option<ResMut<Assets<StandardMaterial>>>,
+) {
+    for (target, mut bar) in &mut bars {
+        if bar.bar.is_some() {
+            continue;
+        }
+        let root = commands
+            .spawn((WorldHealthBarRoot { target }, SpatialBundle::default()))
+            .id();
+
+        let mut spawn_quad = |commands: &mut Commands, color: Color, z: f32| match (
+            bar.mesh,
+            meshes.as_deref_mut(),
+            materials.as_deref_mut(),
+        ) {
+            (true, Some(meshes), Some(materials)) => commands
+                .spawn(PbrBundle {
+                    mesh: meshes.add(shape::Quad::new(bar.size).into()),
+                    material: materials.add(StandardMaterial {
+                        base_color: color,
+                        unlit: true,
+                        ..default()
+                    }),
+                    transform: Transform::from_xyz(0.0, 0.0, z),
+                    ..default()
+                })
+                .id(),
+            (mesh, ..) => {
+                if mesh {
+                    warn!("`WorldHealthBar::mesh` requires the `PbrPlugin`, using sprites.");
+                }
+                commands
+                    .spawn(SpriteBundle {
+                        sprite: Sprite {
+                            color,
+                            custom_size: Some(bar.size),
+                            ..default()
+                        },
+                        transform: Transform::from_xyz(0.0, 0.0, z),
+                        ..default()
+                    })
+                    .id()
+            }
+        };
+        let background = spawn_quad(&mut commands, bar.background, 0.0);
+        let fill = spawn_quad(&mut commands, bar.fill, 0.001);
+        commands.entity(root).push_children(&[background, fill]);
+
+        bar.bar = Some(root);
+        bar.fill_node = Some(fill);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_world_health_bars(
+    time: Res<Time>,
+    mut targets: Query<(
+        &mut WorldHealthBar,
+        Ref<HealthSystem>,
+        Option<&GlobalTransform>,
+    )>,
+    cameras: Query<&GlobalTransform, With<Camera3d>>,
+    mut transforms: Query<&mut Transform, Without<WorldHealthBar>>,
+    mut visibilities: Query<&mut Visibility, With<WorldHealthBarRoot>>,
+) {
+    let facing = cameras
+        .iter()
+        .next()
+        .map_or(Quat::IDENTITY, |camera| camera.compute_transform().rotation);
+
+    for (mut bar, health_system, transform) in &mut targets {
+        let (Some(root), Some(fill)) = (bar.bar, bar.fill_node) else {
+            continue;
+        };
+
+        if let Ok(mut root_transform) = transforms.get_mut(root) {
+            let translation = transform.map_or(Vec3::ZERO, GlobalTransform::translation);
+            root_transform.translation = translation + bar.offset;
+            root_transform.rotation = facing;
+        }
+
+        if health_system.is_changed() || bar.is_added() {
+            bar.since_change = Duration::ZERO;
+            if let Ok(mut fill_transform) = transforms.get_mut(fill) {
+                let fraction = health_system.get_health_normalized().clamp(0.0, 1.0);
+                fill_transform.scale.x = fraction;
+                fill_transform.translation.x = -bar.size.x * (1.0 - fraction) / 2.0;
+            }
+        } else {
+            bar.since_change += time.delta();
+        }
+
+        let full = health_system.get_health() >= health_system.get_health_max();
+        let faded = bar
+            .fade_delay
+            .is_some_and(|delay| bar.since_change >= delay);
+        let visibility = if (bar.hide_when_full && full) || faded {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+        if let Ok(mut current) = visibilities.get_mut(root) {
+            if *current != visibility {
+                *current = visibility;
+            }
+        }
+    }
+}
+
+fn despawn_world_health_bars(
+    mut commands: Commands,
+    mut removed: RemovedComponents<HealthSystem>,
+    roots: Query<(Entity, &WorldHealthBarRoot)>,
+    mut bars: Query<&mut WorldHealthBar>,
+) {
+    // Detach the bars of entities losing their health, their roots are despawned below.
+    for target in removed.iter() {
+        if let Ok(mut bar) = bars.get_mut(target) {
+            bar.bar = None;
+            bar.fill_node = None;
+        }
+    }
+    for (entity, root) in &roots {
+        if bars
+            .get(root.target)
+            .map_or(true, |bar| bar.bar != Some(entity))
+        {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
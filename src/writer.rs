@@ -0,0 +1,146 @@
+//! A system parameter to damage and heal entities.
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+
+use crate::{DamageEvent, Falloff, HealEvent, HealthSystem, HealthTransferEvent, ReviveEvent};
+
+/// Send damage, heal, revive and transfer requests in one [`SystemParam`], with helpers for area effects.
+/// # Examples
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, HealthWriter};
+///
+/// #[derive(Component)]
+/// struct Explosion {
+///     damage: f32,
+///     radius: f32,
+/// }
+///
+/// fn explode(mut health: HealthWriter, explosions: Query<(Entity, &Explosion, &GlobalTransform)>) {
+///     for (entity, explosion, transform) in &explosions {
+///         health.apply_damage_in_radius_with(
+///             transform.translation(),
+///             explosion.radius,
+///             Default::default(),
+///             DamageEvent::new(entity, explosion.damage).with_source(entity),
+///         );
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct HealthWriter<'w, 's> {
+    damage: EventWriter<'w, DamageEvent>,
+    heal: EventWriter<'w, HealEvent>,
+    revive: EventWriter<'w, ReviveEvent>,
+    transfer: EventWriter<'w, HealthTransferEvent>,
+    targets: Query<'w, 's, (Entity, &'static GlobalTransform), With<HealthSystem>>,
+}
+
+impl<'w, 's> HealthWriter<'w, 's> {
+    /// Send a damage request.
+    pub fn damage(&mut self, event: DamageEvent) {
+        self.damage.send(event);
+    }
+
+    /// Send a heal request.
+    pub fn heal(&mut self, event: HealEvent) {
+        self.heal.send(event);
+    }
+
+    /// Send a revive request.
+    pub fn revive(&mut self, event: ReviveEvent) {
+        self.revive.send(event);
+    }
+
+    /// Send a request draining health from an entity to heal another one, see [`HealthTransferEvent`].
+    /// # Arguments
+    /// * `from`: The entity drained.
+    /// * `to`: The entity healed.
+    /// * `amount`: The health drained.
+    pub fn transfer_health(&mut self, from: Entity, to: Entity, amount: f32) {
+        self.transfer
+            .send(HealthTransferEvent::new(from, to, amount));
+    }
+
+    /// Deal damage to every entity with a [`HealthSystem`] and a [`GlobalTransform`] in a sphere,
+    /// attenuated by their distance from its center. Returns the number of entities hit.
+    /// # Arguments
+    /// * `center`: The center of the sphere, in world space.
+    /// * `radius`: The radius of the sphere.
+    /// * `amount`: The amount of damage dealt at the center.
+    /// * `falloff`: How the damage weakens with the distance from the center.
+    /// # Example
+    /// ```
+    /// use bevy::ecs::system::SystemState;
+    /// use bevy::prelude::*;
+    /// use bevy_health_system::{Falloff, HealthSystem, HealthSystemPlugin, HealthWriter};
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+    ///
+    /// let near = app
+    ///     .world
+    ///     .spawn((HealthSystem::new(100.0), GlobalTransform::from_xyz(2.0, 0.0, 0.0)))
+    ///     .id();
+    /// let far = app
+    ///     .world
+    ///     .spawn((HealthSystem::new(100.0), GlobalTransform::from_xyz(20.0, 0.0, 0.0)))
+    ///     .id();
+    ///
+    /// let mut state = SystemState::<HealthWriter>::new(&mut app.world);
+    /// let hit = state
+    ///     .get_mut(&mut app.world)
+    ///     .apply_damage_in_radius(Vec3::ZERO, 10.0, 50.0, Falloff::Linear);
+    /// app.update();
+    ///
+    /// assert_eq!(1, hit);
+    /// assert_eq!(60.0, app.world.get::<HealthSystem>(near).unwrap().get_health());
+    /// assert_eq!(100.0, app.world.get::<HealthSystem>(far).unwrap().get_health());
+    /// ```
+    pub fn apply_damage_in_radius(
+        &mut self,
+        center: Vec3,
+        radius: f32,
+        amount: f32,
+        falloff: Falloff,
+    ) -> usize {
+        self.apply_damage_in_radius_with(
+            center,
+            radius,
+            falloff,
+            DamageEvent::new(Entity::PLACEHOLDER, amount),
+        )
+    }
+
+    /// Like [`HealthWriter::apply_damage_in_radius`], sending a copy of `damage` to every entity hit.
+    /// The target and amount of the copies are replaced, everything else, eg: the source, is kept.
+    /// # Arguments
+    /// * `center`: The center of the sphere, in world space.
+    /// * `radius`: The radius of the sphere.
+    /// * `falloff`: How the damage weakens with the distance from the center.
+    /// * `damage`: The damage dealt at the center.
+    pub fn apply_damage_in_radius_with(
+        &mut self,
+        center: Vec3,
+        radius: f32,
+        falloff: Falloff,
+        damage: DamageEvent,
+    ) -> usize {
+        let mut hit = 0;
+        for (entity, transform) in &self.targets {
+            let distance = transform.translation().distance(center);
+            if distance > radius {
+                continue;
+            }
+
+            hit += 1;
+            self.damage.send(DamageEvent {
+                target: entity,
+                amount: damage.amount * falloff.multiplier(distance, radius),
+                ..damage.clone()
+            });
+        }
+
+        hit
+    }
+}
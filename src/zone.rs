@@ -0,0 +1,250 @@
+//! Volumes damaging or healing the entities inside, eg: lava, spikes, poison gas, healing springs,...
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+
+use crate::contact::Contacts;
+use crate::{DamageEvent, DamageType, HealEvent, HealthSystem};
+
+/// Damage the entities inside a volume every frame, through the damage pipeline.
+/// With a `radius`, the volume is a sphere around the [`GlobalTransform`] of the zone.
+/// Without one, the entities inside are read from the physics sensor events of the `rapier` or `avian` feature.
+/// # Fields
+/// * `amount_per_second`: The amount of damage dealt per second.
+/// * `damage_type`: The type of the damage.
+/// * `radius`: The radius of the sphere, if not using physics sensors.
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageType, DamageZone, HealthSystem, HealthSystemPlugin};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_millis(500)));
+///
+/// app.world.spawn((
+///     DamageZone::new(10.0).with_type(DamageType::FIRE).with_radius(5.0),
+///     GlobalTransform::IDENTITY,
+/// ));
+/// let entity = app
+///     .world
+///     .spawn((HealthSystem::new(100.0), GlobalTransform::from_xyz(1.0, 0.0, 0.0)))
+///     .id();
+///
+/// for _ in 0..3 {
+///     app.update();
+/// }
+/// assert_eq!(90.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct DamageZone {
+    pub amount_per_second: f32,
+    pub damage_type: DamageType,
+    pub radius: Option<f32>,
+}
+
+impl DamageZone {
+    /// Damage the entities inside a volume.
+    /// # Arguments
+    /// * `amount_per_second`: The amount of damage dealt per second.
+    pub fn new(amount_per_second: f32) -> Self {
+        Self {
+            amount_per_second,
+            damage_type: DamageType::default(),
+            radius: None,
+        }
+    }
+
+    /// Set the type of the damage.
+    pub fn with_type(mut self, damage_type: DamageType) -> Self {
+        self.damage_type = damage_type;
+        self
+    }
+
+    /// Use a sphere of `radius` instead of physics sensors.
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = Some(radius);
+        self
+    }
+}
+
+/// Heal the entities inside a volume every frame, like a [`DamageZone`].
+/// # Fields
+/// * `amount_per_second`: The amount of health healed per second.
+/// * `radius`: The radius of the sphere, if not using physics sensors.
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct HealZone {
+    pub amount_per_second: f32,
+    pub radius: Option<f32>,
+}
+
+impl HealZone {
+    /// Heal the entities inside a volume.
+    /// # Arguments
+    /// * `amount_per_second`: The amount of health healed per second.
+    pub fn new(amount_per_second: f32) -> Self {
+        Self {
+            amount_per_second,
+            radius: None,
+        }
+    }
+
+    /// Use a sphere of `radius` instead of physics sensors.
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = Some(radius);
+        self
+    }
+}
+
+/// Suppress any non forced damage dealt to the entities inside a volume, eg: hub areas, spawn rooms,...
+/// Entities inside are marked [`InSafeZone`], and optionally regenerate.
+/// # Fields
+/// * `regen_per_second`: The amount of health the entities inside heal per second.
+/// * `radius`: The radius of the sphere, if not using physics sensors, see [`DamageZone`].
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use bevy::prelude::*;
+/// use bevy_health_system::{DamageEvent, HealthSystem, HealthSystemPlugin, InSafeZone, SafeZone};
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, HealthSystemPlugin));
+/// app.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(Duration::from_millis(500)));
+///
+/// app.world.spawn((
+///     SafeZone::new().with_regen(10.0).with_radius(5.0),
+///     GlobalTransform::IDENTITY,
+/// ));
+/// let mut health_system = HealthSystem::new(100.0);
+/// health_system.set_health(50.0);
+/// let entity = app
+///     .world
+///     .spawn((health_system, GlobalTransform::from_xyz(1.0, 0.0, 0.0)))
+///     .id();
+/// app.update();
+/// assert!(app.world.get::<InSafeZone>(entity).is_some());
+///
+/// app.world.send_event(DamageEvent::new(entity, 10.0));
+/// app.update();
+/// assert_eq!(55.0, app.world.get::<HealthSystem>(entity).unwrap().get_health());
+/// ```
+#[derive(Debug, Default, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct SafeZone {
+    pub regen_per_second: f32,
+    pub radius: Option<f32>,
+}
+
+impl SafeZone {
+    /// Suppress damage inside a volume.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Heal the entities inside by `regen_per_second`.
+    pub fn with_regen(mut self, regen_per_second: f32) -> Self {
+        self.regen_per_second = regen_per_second;
+        self
+    }
+
+    /// Use a sphere of `radius` instead of physics sensors.
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = Some(radius);
+        self
+    }
+}
+
+/// Inserted by the [`HealthSystemPlugin`](crate::HealthSystemPlugin) on entities inside a [`SafeZone`],
+/// and removed once they leave it.
+#[derive(Debug, Default, Copy, Clone, Component, Reflect)]
+#[reflect(Component)]
+pub struct InSafeZone;
+
+/// Returns the entities with a [`HealthSystem`] inside a zone.
+fn occupants<'a>(
+    zone: Entity,
+    transform: Option<&'a GlobalTransform>,
+    radius: Option<f32>,
+    contacts: &'a Contacts,
+    targets: &'a Query<(Entity, &GlobalTransform), With<HealthSystem>>,
+) -> Vec<Entity> {
+    match (radius, transform) {
+        (Some(radius), Some(transform)) => {
+            let center = transform.translation();
+            targets
+                .iter()
+                .filter(|(entity, target)| {
+                    *entity != zone && target.translation().distance(center) <= radius
+                })
+                .map(|(entity, _)| entity)
+                .collect()
+        }
+        (Some(_), None) => Vec::new(),
+        (None, _) => contacts.touching(zone).collect(),
+    }
+}
+
+pub(crate) fn apply_zones(
+    time: Res<Time>,
+    contacts: Res<Contacts>,
+    damage_zones: Query<(Entity, &DamageZone, Option<&GlobalTransform>)>,
+    heal_zones: Query<(Entity, &HealZone, Option<&GlobalTransform>)>,
+    targets: Query<(Entity, &GlobalTransform), With<HealthSystem>>,
+    mut damage: EventWriter<DamageEvent>,
+    mut heal: EventWriter<HealEvent>,
+) {
+    let delta = time.delta_seconds();
+    if delta <= 0.0 {
+        return;
+    }
+
+    for (zone, damage_zone, transform) in &damage_zones {
+        let amount = damage_zone.amount_per_second * delta;
+        for target in occupants(zone, transform, damage_zone.radius, &contacts, &targets) {
+            damage.send(
+                DamageEvent::new(target, amount)
+                    .with_type(damage_zone.damage_type)
+                    .with_source(zone),
+            );
+        }
+    }
+
+    for (zone, heal_zone, transform) in &heal_zones {
+        let amount = heal_zone.amount_per_second * delta;
+        for target in occupants(zone, transform, heal_zone.radius, &contacts, &targets) {
+            heal.send(HealEvent::new(target, amount));
+        }
+    }
+}
+
+pub(crate) fn update_safe_zones(
+    mut commands: Commands,
+    time: Res<Time>,
+    contacts: Res<Contacts>,
+    safe_zones: Query<(Entity, &SafeZone, Option<&GlobalTransform>)>,
+    targets: Query<(Entity, &GlobalTransform), With<HealthSystem>>,
+    sheltered: Query<Entity, With<InSafeZone>>,
+    mut heal: EventWriter<HealEvent>,
+) {
+    let mut inside = HashSet::new();
+    for (zone, safe_zone, transform) in &safe_zones {
+        let amount = safe_zone.regen_per_second * time.delta_seconds();
+        for target in occupants(zone, transform, safe_zone.radius, &contacts, &targets) {
+            if amount > 0.0 {
+                heal.send(HealEvent::new(target, amount));
+            }
+            inside.insert(target);
+        }
+    }
+
+    for entity in &sheltered {
+        if !inside.remove(&entity) {
+            commands.entity(entity).remove::<InSafeZone>();
+        }
+    }
+    for entity in inside {
+        commands.entity(entity).insert(InSafeZone);
+    }
+}